@@ -233,6 +233,29 @@ impl AdminManager {
         env.crypto().sha256(&payload_bytes).into()
     }
 
+    /// The project a proposal decides on, when its signers must not have a
+    /// conflict of interest in it.
+    fn conflict_project(payload: &ProposalPayload) -> Option<u64> {
+        match payload {
+            ProposalPayload::ApproveVerification(project_id)
+            | ProposalPayload::RejectVerification(project_id) => Some(*project_id),
+            _ => None,
+        }
+    }
+
+    fn require_no_conflict(
+        env: &Env,
+        payload: &ProposalPayload,
+        signer: &Address,
+    ) -> Result<(), ContractError> {
+        match Self::conflict_project(payload) {
+            Some(project_id) => crate::conflict_registry::ConflictRegistry::require_no_conflict(
+                env, project_id, signer,
+            ),
+            None => Ok(()),
+        }
+    }
+
     pub fn create_proposal(
         env: &Env,
         proposer: Address,
@@ -240,6 +263,7 @@ impl AdminManager {
     ) -> Result<u64, ContractError> {
         proposer.require_auth();
         Self::require_admin(env, &proposer)?;
+        Self::require_no_conflict(env, &payload, &proposer)?;
 
         let mut id: u64 = env
             .storage()
@@ -250,11 +274,15 @@ impl AdminManager {
         let action_type = match &payload {
             ProposalPayload::AddAdmin(_) => AdminActionType::AdminAdded,
             ProposalPayload::RemoveAdmin(_) => AdminActionType::AdminRemoved,
-            ProposalPayload::SetFee(_, _, _, _) => AdminActionType::FeeChanged,
+            ProposalPayload::SetFee(_, _, _, _) | ProposalPayload::SetTreasurySplits(_, _) => {
+                AdminActionType::FeeChanged
+            }
             ProposalPayload::SetThreshold(_) => AdminActionType::ThresholdChanged,
             ProposalPayload::ApproveVerification(_) => AdminActionType::VerificationApproved,
             ProposalPayload::RejectVerification(_) => AdminActionType::VerificationRejected,
             ProposalPayload::RevokeVerification(_, _) => AdminActionType::VerificationRevoked,
+            ProposalPayload::SetReviewPolicy(_, _)
+            | ProposalPayload::ClearProjectReviewPolicy(_) => AdminActionType::ReviewPolicyChanged,
         };
 
         let payload_hash = Self::compute_payload_hash(env, &payload);
@@ -328,6 +356,7 @@ impl AdminManager {
                 return Err(ContractError::Unauthorized);
             }
         }
+        Self::require_no_conflict(env, &proposal.payload, &admin)?;
 
         proposal.approvals.push_back(admin);
 
@@ -377,6 +406,10 @@ impl AdminManager {
         if proposal.approvals.len() < threshold {
             return Err(ContractError::Unauthorized);
         }
+        // A signer may have acquired a conflict since signing.
+        for signer in proposal.approvals.iter() {
+            Self::require_no_conflict(env, &proposal.payload, &signer)?;
+        }
 
         match proposal.payload.clone() {
             ProposalPayload::AddAdmin(new_admin) => {
@@ -453,12 +486,24 @@ impl AdminManager {
                     project.verification_status,
                     VerificationStatus::Verified,
                 )?;
+                crate::verification_registry::VerificationChecklistRegistry::require_complete(
+                    env,
+                    &project.category,
+                    &record,
+                )?;
                 let now = env.ledger().timestamp();
+                crate::verification_registry::VerificationQueue::dequeue(env, &record);
                 record.status = VerificationStatus::Verified;
                 record.decided_at = now;
-                record.expires_at = now.saturating_add(
+                let untiered_duration =
                     crate::verification_registry::VerificationRegistry::get_verification_duration(
                         env,
+                    );
+                record.expires_at = now.saturating_add(
+                    crate::verification_registry::VerificationTierRegistry::duration_for(
+                        env,
+                        record.tier_id,
+                        untiered_duration,
                     ),
                 );
                 env.storage()
@@ -467,6 +512,12 @@ impl AdminManager {
                 env.storage()
                     .persistent()
                     .set(&StorageKey::VerificationRecord(record.request_id), &record);
+                crate::verification_registry::VerificationRegistry::grant_record_tier(env, &record);
+                crate::verification_registry::VerificationExpiryIndex::index(
+                    env,
+                    project_id,
+                    record.expires_at,
+                );
                 project.verification_status = VerificationStatus::Verified;
                 project.current_verification_id = Some(record.request_id);
                 project.updated_at = now;
@@ -479,6 +530,12 @@ impl AdminManager {
                     caller.clone(),
                     now,
                 );
+                crate::fee_escrow::FeeEscrowManager::settle_decision(
+                    env,
+                    project_id,
+                    true,
+                    crate::events::FeeOperation::Verification,
+                )?;
             }
             ProposalPayload::RejectVerification(project_id) => {
                 let mut project =
@@ -494,6 +551,7 @@ impl AdminManager {
                     VerificationStatus::Rejected,
                 )?;
                 let now = env.ledger().timestamp();
+                crate::verification_registry::VerificationQueue::dequeue(env, &record);
                 record.status = VerificationStatus::Rejected;
                 record.decided_at = now;
                 env.storage()
@@ -514,12 +572,21 @@ impl AdminManager {
                     caller.clone(),
                     now,
                 );
+                crate::fee_escrow::FeeEscrowManager::settle_decision(
+                    env,
+                    project_id,
+                    false,
+                    crate::events::FeeOperation::Verification,
+                )?;
             }
             ProposalPayload::RevokeVerification(project_id, reason) => {
                 let mut project =
                     crate::project_registry::ProjectRegistry::get_project(env, project_id)
                         .ok_or(ContractError::ProjectNotFound)?;
-                if project.verification_status != VerificationStatus::Verified {
+                if !matches!(
+                    project.verification_status,
+                    VerificationStatus::Verified | VerificationStatus::Suspended
+                ) {
                     return Err(ContractError::InvalidStatus);
                 }
                 let mut record =
@@ -542,6 +609,10 @@ impl AdminManager {
                 env.storage()
                     .persistent()
                     .set(&StorageKey::Project(project_id), &project);
+                crate::verification_registry::VerificationTierRegistry::clear_grants(
+                    env, project_id,
+                );
+                crate::verification_registry::VerificationExpiryIndex::unindex(env, project_id);
                 crate::events::publish_verification_revoked_event(
                     env,
                     project_id,
@@ -549,6 +620,29 @@ impl AdminManager {
                     reason,
                 );
             }
+            ProposalPayload::SetReviewPolicy(project_id, policy) => {
+                crate::review_registry::ReviewRegistry::store_review_policy(
+                    env,
+                    project_id,
+                    policy,
+                    caller.clone(),
+                )?;
+            }
+            ProposalPayload::ClearProjectReviewPolicy(project_id) => {
+                crate::review_registry::ReviewRegistry::remove_project_review_policy(
+                    env,
+                    project_id,
+                    caller.clone(),
+                )?;
+            }
+            ProposalPayload::SetTreasurySplits(operation, splits) => {
+                crate::fee_manager::FeeManager::store_treasury_splits(
+                    env,
+                    operation,
+                    splits,
+                    caller.clone(),
+                )?;
+            }
         }
 
         proposal.status = ProposalStatus::Executed;
@@ -0,0 +1,122 @@
+//! Admin-managed address blocklist.
+//!
+//! A blocked address is rejected by `register_project`, `add_review`,
+//! `report_project`, `report_review`, `endorse_project` and `follow_project`
+//! with `ContractError::AddressBlocked`. Blocks may carry an expiry timestamp,
+//! after which they stop applying without further admin action.
+
+use crate::admin_action_log::AdminActionLog;
+use crate::errors::ContractError;
+use crate::events::{publish_address_blocked_event, publish_address_unblocked_event};
+use crate::storage_keys::FeatureKey;
+use crate::types::{AdminActionType, BlocklistEntry};
+use crate::utils::Utils;
+use soroban_sdk::{Address, Env, String};
+
+pub struct Blocklist;
+
+impl Blocklist {
+    /// Get the blocklist entry for an address, including expired entries.
+    pub fn get_entry(env: &Env, address: &Address) -> Option<BlocklistEntry> {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::BlockedAddress(address.clone()))
+    }
+
+    /// Whether an address is currently blocked (entry present and not expired).
+    pub fn is_blocked(env: &Env, address: &Address) -> bool {
+        match Self::get_entry(env, address) {
+            Some(entry) => match entry.expires_at {
+                Some(expires_at) => env.ledger().timestamp() < expires_at,
+                None => true,
+            },
+            None => false,
+        }
+    }
+
+    /// Guard: return `ContractError::AddressBlocked` if the address is blocked.
+    pub fn require_not_blocked(env: &Env, address: &Address) -> Result<(), ContractError> {
+        if Self::is_blocked(env, address) {
+            Err(ContractError::AddressBlocked)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Admin: block an address. Re-blocking replaces the existing entry.
+    pub fn block_address(
+        env: &Env,
+        admin: Address,
+        address: Address,
+        reason_cid: String,
+        expires_at: Option<u64>,
+    ) -> Result<(), ContractError> {
+        crate::auth::require_admin_auth(env, &admin)?;
+        Utils::validate_report_reason_cid(&reason_cid)?;
+
+        let now = env.ledger().timestamp();
+        if let Some(expires_at) = expires_at {
+            if expires_at <= now {
+                return Err(ContractError::InvalidInput);
+            }
+        }
+
+        let entry = BlocklistEntry {
+            address: address.clone(),
+            reason_cid: reason_cid.clone(),
+            blocked_by: admin.clone(),
+            blocked_at: now,
+            expires_at,
+        };
+        env.storage()
+            .persistent()
+            .set(&FeatureKey::BlockedAddress(address.clone()), &entry);
+
+        publish_address_blocked_event(
+            env,
+            address.clone(),
+            admin.clone(),
+            reason_cid.clone(),
+            expires_at,
+        );
+
+        AdminActionLog::record_action(
+            env,
+            admin,
+            AdminActionType::AddressBlocked,
+            None,
+            Some(address),
+            Some(reason_cid),
+        );
+
+        Ok(())
+    }
+
+    /// Admin: remove an address from the blocklist.
+    pub fn unblock_address(
+        env: &Env,
+        admin: Address,
+        address: Address,
+    ) -> Result<(), ContractError> {
+        crate::auth::require_admin_auth(env, &admin)?;
+
+        let key = FeatureKey::BlockedAddress(address.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(ContractError::AddressNotBlocked);
+        }
+        env.storage().persistent().remove(&key);
+
+        publish_address_unblocked_event(env, address.clone(), admin.clone());
+
+        AdminActionLog::record_action(
+            env,
+            admin,
+            AdminActionType::AddressUnblocked,
+            None,
+            Some(address),
+            None,
+        );
+
+        Ok(())
+    }
+}
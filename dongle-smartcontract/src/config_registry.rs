@@ -17,8 +17,7 @@ use crate::admin_action_log::AdminActionLog;
 use crate::auth;
 use crate::constants::{
     CONTRACT_VERSION, LEDGER_BUMP_CRITICAL, LEDGER_THRESHOLD_CRITICAL, MAX_DESCRIPTION_LEN,
-    MAX_NAME_LEN, MAX_PAGE_LIMIT, MAX_PROJECTS_PER_USER, MAX_REVIEWS_PER_PROJECT,
-    VERIFICATION_VALIDITY_PERIOD,
+    MAX_NAME_LEN, MAX_PAGE_LIMIT, MAX_PROJECTS_PER_USER, VERIFICATION_VALIDITY_PERIOD,
 };
 use crate::errors::ContractError;
 use crate::storage_keys::{ExtensionKey, StorageKey};
@@ -101,8 +100,8 @@ impl ConfigRegistry {
     /// Composed from existing storage: fee config
     /// (`StorageKey::FeeConfig`), treasury address (`StorageKey::Treasury`),
     /// admin count + threshold (`AdminManager`), the pause flag
-    /// (`ExtensionKey::Paused`), and the static `ContractLimits` derived
-    /// from `constants.rs`.
+    /// (`ExtensionKey::Paused`), the static `ContractLimits` derived
+    /// from `constants.rs`, and the global `ReviewPolicy`.
     ///
     /// # Behaviour absent `set_fee`
     /// If `set_fee` has never been called the view still returns a fully
@@ -139,11 +138,12 @@ impl ConfigRegistry {
             limits: ContractLimits {
                 max_page_limit: MAX_PAGE_LIMIT,
                 max_projects_per_user: MAX_PROJECTS_PER_USER,
-                max_reviews_per_project: MAX_REVIEWS_PER_PROJECT,
+                max_reviews_per_project: u32::MAX,
                 max_name_len: MAX_NAME_LEN as u32,
                 max_description_len: MAX_DESCRIPTION_LEN as u32,
                 verification_validity_period: VERIFICATION_VALIDITY_PERIOD,
             },
+            review_policy: crate::review_registry::ReviewRegistry::get_global_review_policy(env),
         })
     }
 }
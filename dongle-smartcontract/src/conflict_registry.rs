@@ -0,0 +1,82 @@
+//! Conflict-of-interest guards for admin and verifier decisions.
+//!
+//! An actor may not approve a project's verification or renewal, decide a
+//! claim or dispute about it, or moderate it when they own, maintain or have
+//! endorsed the project, or when they have recused themselves from it.
+//! Recusals are permanent and recorded in the admin action log.
+
+use crate::admin_action_log::AdminActionLog;
+use crate::admin_manager::AdminManager;
+use crate::endorsement_registry::EndorsementRegistry;
+use crate::errors::ContractError;
+use crate::events::publish_recused_event;
+use crate::project_registry::ProjectRegistry;
+use crate::storage_keys::FeatureKey;
+use crate::types::AdminActionType;
+use crate::verification_registry::VerifierRegistry;
+use soroban_sdk::{Address, Env, Vec};
+
+pub struct ConflictRegistry;
+
+impl ConflictRegistry {
+    /// Admin or verifier: recuse `actor` from decisions about the project.
+    pub fn recuse(env: &Env, project_id: u64, actor: Address) -> Result<(), ContractError> {
+        actor.require_auth();
+        if !AdminManager::is_admin(env, &actor)
+            && VerifierRegistry::active_grant(env, &actor).is_none()
+        {
+            return Err(ContractError::AdminOnly);
+        }
+        ProjectRegistry::get_project(env, project_id).ok_or(ContractError::ProjectNotFound)?;
+
+        let mut recusals = Self::get_recusals(env, project_id);
+        if recusals.contains(&actor) {
+            return Err(ContractError::InvalidInput);
+        }
+        recusals.push_back(actor.clone());
+        env.storage()
+            .persistent()
+            .set(&FeatureKey::ProjectRecusals(project_id), &recusals);
+
+        publish_recused_event(env, project_id, actor.clone());
+        AdminActionLog::record_action(
+            env,
+            actor,
+            AdminActionType::Recused,
+            Some(project_id),
+            None,
+            None,
+        );
+        Ok(())
+    }
+
+    pub fn get_recusals(env: &Env, project_id: u64) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::ProjectRecusals(project_id))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Whether `actor` owns, maintains, has endorsed or is recused from the
+    /// project.
+    pub fn has_conflict(env: &Env, project_id: u64, actor: &Address) -> bool {
+        let is_owner = ProjectRegistry::get_project(env, project_id)
+            .map(|project| project.owner == *actor)
+            .unwrap_or(false);
+        is_owner
+            || ProjectRegistry::is_maintainer(env, project_id, actor)
+            || EndorsementRegistry::has_endorsed(env, project_id, actor)
+            || Self::get_recusals(env, project_id).contains(actor)
+    }
+
+    pub fn require_no_conflict(
+        env: &Env,
+        project_id: u64,
+        actor: &Address,
+    ) -> Result<(), ContractError> {
+        if Self::has_conflict(env, project_id, actor) {
+            return Err(ContractError::ConflictOfInterest);
+        }
+        Ok(())
+    }
+}
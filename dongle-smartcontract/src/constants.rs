@@ -8,12 +8,20 @@ pub const MAX_PROJECTS_PER_USER: u32 = 50;
 // Vec-based indexes are capped on write to avoid unbounded per-user/project growth.
 // See STORAGE_INDEXES.md for the full index catalog and pagination strategy.
 
-/// Maximum unique reviewers indexed per project (`ProjectReviews`).
-pub const MAX_REVIEWS_PER_PROJECT: u32 = 500;
+/// Entries per storage page of the chunked review indexes (`ProjectReviewPage`,
+/// `UserReviewPage`). Appends and removals touch at most two pages, so review
+/// counts are not capped.
+pub const REVIEW_INDEX_PAGE_SIZE: u32 = 50;
 
-/// Maximum projects indexed per reviewer (`UserReviews`).
-/// Also used as the shared index-capacity error (`MaxProjectsExceeded`) for review indexes.
-pub const MAX_REVIEWS_PER_USER: u32 = 200;
+/// Index pages walked by calls that read a whole project index
+/// (`list_reviews_sorted`, `get_project_review_cids`) or refresh a whole index
+/// TTL, keeping their cost bounded at `MAX_REVIEW_SCAN_PAGES *
+/// REVIEW_INDEX_PAGE_SIZE` entries however many reviews exist.
+pub const MAX_REVIEW_SCAN_PAGES: u32 = 2;
+
+/// Entries per storage page of the pending verification queue
+/// (`VerificationKey::QueuePage`).
+pub const VERIFICATION_QUEUE_PAGE_SIZE: u32 = 50;
 
 /// Maximum items returned per paginated read query across list endpoints.
 pub const MAX_PAGE_LIMIT: u32 = 100;
@@ -50,6 +58,7 @@ pub const MAX_SECURITY_CONTACT_LEN: usize = 256;
 pub const MAX_CID_LEN: usize = 128;
 
 /// Maximum stored edit revisions per review (oldest dropped when exceeded).
+/// Default for `ReviewPolicy::max_revisions` and the largest value it may take.
 pub const MAX_REVIEW_REVISIONS: u32 = 50;
 
 /// Bayesian prior review count for weighted rating (see RatingCalculator::calculate_weighted).
@@ -58,6 +67,42 @@ pub const WEIGHTED_RATING_PRIOR_COUNT: u32 = 5;
 /// Bayesian prior mean rating scaled by 100 (350 = 3.50 stars).
 pub const WEIGHTED_RATING_PRIOR_MEAN: u32 = 350;
 
+// ── Reviewer reputation ─────────────────────────────────────────────────────
+// Scores are scaled by 100 (100 = neutral 1.00x weight), see ReputationRegistry.
+
+/// Starting reputation score for every reviewer.
+pub const REPUTATION_BASE_SCORE: u32 = 100;
+
+/// Lower bound for a reviewer's reputation score (a review never weighs zero).
+pub const REPUTATION_MIN_SCORE: u32 = 10;
+
+/// Upper bound for a reviewer's reputation score.
+pub const REPUTATION_MAX_SCORE: u32 = 400;
+
+/// Score added per full day since the reviewer's first interaction.
+pub const REPUTATION_AGE_BONUS_PER_DAY: u32 = 1;
+
+/// Maximum score contributed by account age.
+pub const REPUTATION_MAX_AGE_BONUS: u32 = 100;
+
+/// Score added per helpful vote received from a voter with the base score;
+/// each vote is scaled by the voter's score over `REPUTATION_BASE_SCORE`.
+pub const REPUTATION_HELPFUL_VOTE_BONUS: u32 = 5;
+
+/// Minimum reputation score an address needs to vote a review helpful, so a
+/// fresh address cannot vote until it has history (30 days of age, or a
+/// verified project and some age).
+pub const REPUTATION_MIN_VOTER_SCORE: u32 = 130;
+
+/// Maximum score contributed by helpful votes.
+pub const REPUTATION_MAX_HELPFUL_BONUS: u32 = 150;
+
+/// Score added when the reviewer owns at least one verified project.
+pub const REPUTATION_VERIFIED_OWNER_BONUS: u32 = 50;
+
+/// Score removed per hidden or admin-deleted review.
+pub const REPUTATION_MODERATION_PENALTY: u32 = 25;
+
 /// Project metadata fields whose changes invalidate an existing verification.
 pub const MAJOR_METADATA_FIELD_NAME: &str = "name";
 pub const MAJOR_METADATA_FIELD_WEBSITE: &str = "website";
@@ -154,15 +199,85 @@ pub const DEFAULT_VERIFICATION_DURATION_SECS: u64 = 31_536_000;
 /// Scheduled actions must have execution_timestamp >= now + TIMELOCK_MIN_DELAY.
 pub const TIMELOCK_MIN_DELAY: u64 = 86400;
 
-/// Fee payment validity window in seconds (7 days).
+/// Default fee payment validity window in seconds (7 days).
 /// After this window, the payment record is considered expired and the
-/// verification request is rejected until the owner re-pays.
+/// verification request is rejected until the owner re-prices or reclaims it.
 pub const FEE_PAYMENT_EXPIRY_SECONDS: u64 = 7 * 24 * 60 * 60;
 
-/// Minimum seconds a reviewer must wait before updating their review again (default: 1 hour).
-/// Configurable by changing this constant.
+/// Length of the fee revenue report periods (30 days). Periods start at
+/// multiples of this length since the Unix epoch.
+pub const FEE_REVENUE_PERIOD_SECONDS: u64 = 30 * 24 * 60 * 60;
+
+/// Default minimum seconds a reviewer must wait before updating their review
+/// again (1 hour). Adjustable through `ReviewPolicy::update_cooldown_seconds`.
 pub const REVIEW_UPDATE_COOLDOWN_SECONDS: u64 = 3600;
 
+/// Upper bound for the cooldown and edit window of a `ReviewPolicy` (365 days).
+pub const MAX_REVIEW_POLICY_PERIOD_SECONDS: u64 = 365 * 24 * 60 * 60;
+
+/// Maximum number of verification tiers. Bounds tier listings and the
+/// per-project tier grant list.
+pub const MAX_VERIFICATION_TIERS: u32 = 16;
+
+/// Verifier sign-offs required per verification request unless configured.
+pub const DEFAULT_VERIFICATION_QUORUM: u32 = 1;
+
+/// Upper bound on the verification quorum; bounds each record's approval list.
+pub const MAX_VERIFICATION_QUORUM: u32 = 10;
+
+/// Maximum number of addresses holding the verifier role.
+pub const MAX_VERIFIERS: u32 = 50;
+
+/// Maximum categories or tiers in one verifier's scope.
+pub const MAX_VERIFIER_SCOPE_ENTRIES: u32 = 10;
+
+/// Maximum reports attached to one verification request.
+pub const MAX_VERIFICATION_REPORTS: u32 = 20;
+
+/// Maximum items in one verification checklist.
+pub const MAX_CHECKLIST_ITEMS: u32 = 20;
+
+/// Maximum number of registered off-chain attestors.
+pub const MAX_ATTESTORS: u32 = 20;
+
+/// Maximum claim types one attestor may be trusted for.
+pub const MAX_ATTESTOR_CLAIM_TYPES: u32 = 10;
+
+/// Maximum off-chain attestations stored per project.
+pub const MAX_PROJECT_ATTESTATIONS: u32 = 20;
+
+/// Width of one expiry index bucket (1 day).
+pub const EXPIRY_BUCKET_SECONDS: u64 = 24 * 60 * 60;
+
+/// How long after expiry a pending renewal request keeps a verification from
+/// being swept (14 days).
+pub const RENEWAL_SWEEP_GRACE_SECONDS: u64 = 14 * 24 * 60 * 60;
+
+/// Seconds before expiry that renewal reminders start (default: 30 days).
+pub const DEFAULT_EXPIRY_REMINDER_WINDOW: u64 = 30 * 24 * 60 * 60;
+
+/// Most history records pruned by the retention policy per request, so
+/// tightening the policy never makes one request unaffordable.
+pub const MAX_HISTORY_PRUNE_PER_REQUEST: u32 = 10;
+
+/// Maximum number of accepted fee tokens besides the default one.
+pub const MAX_ACCEPTED_FEE_TOKENS: u32 = 10;
+
+/// Maximum number of recipients in a fee type's treasury splits.
+pub const MAX_TREASURY_SPLIT_RECIPIENTS: u32 = 10;
+
+/// Basis points in 100%.
+pub const MAX_BPS: u32 = 10_000;
+
+/// Share of the fee refunded when an owner withdraws a verification request
+/// (default: 0, no refund).
+pub const DEFAULT_WITHDRAWAL_REFUND_BPS: u32 = 0;
+
+/// Limits on evidence summaries, matching `verification-evidence.schema.json`.
+pub const MAX_EVIDENCE_PROOFS: u32 = 50;
+pub const MAX_EVIDENCE_ATTESTATIONS: u32 = 25;
+pub const MAX_EVIDENCE_SIGNATURES: u32 = 25;
+
 /// Minimum age in seconds for a reviewer before they can submit a review (default: 0, disabled).
 pub const DEFAULT_MIN_REVIEWER_AGE_SECONDS: u64 = 0;
 
@@ -172,6 +287,12 @@ pub const DEFAULT_REQUIRE_ENDORSEMENT: bool = false;
 /// Default review fee amount (default: 0, free).
 pub const DEFAULT_REVIEW_FEE: u128 = 0;
 
+/// Longest commit or reveal phase a project owner may set for a review round (30 days).
+pub const MAX_REVIEW_ROUND_PHASE_SECONDS: u64 = 30 * 24 * 60 * 60;
+
+/// Approximate ledger close time, used to size temporary-storage TTLs from durations.
+pub const SECONDS_PER_LEDGER: u64 = 5;
+
 // ── Contract metadata (read by `get_config`) ────────────────────────────────
 
 /// Semantic version of the contract, surfaced verbatim through `get_config`.
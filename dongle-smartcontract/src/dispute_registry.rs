@@ -1,5 +1,6 @@
 use crate::admin_action_log::AdminActionLog;
 use crate::admin_manager::AdminManager;
+use crate::conflict_registry::ConflictRegistry;
 use crate::errors::ContractError;
 use crate::events::{
     publish_duplicate_dispute_opened_event, publish_duplicate_dispute_resolved_event,
@@ -134,6 +135,11 @@ impl DisputeRegistry {
         if dispute.status != DisputeStatus::Pending {
             return Err(ContractError::InvalidStatus);
         }
+        if dispute.creator == admin {
+            return Err(ContractError::ConflictOfInterest);
+        }
+        ConflictRegistry::require_no_conflict(env, dispute.project_id, &admin)?;
+        ConflictRegistry::require_no_conflict(env, dispute.original_project_id, &admin)?;
 
         let now = env.ledger().timestamp();
 
@@ -1,3 +1,4 @@
+use crate::blocklist::Blocklist;
 use crate::errors::ContractError;
 use crate::events::{publish_project_endorsed_event, publish_project_unendorsed_event};
 use crate::project_registry::ProjectRegistry;
@@ -11,6 +12,7 @@ pub struct EndorsementRegistry;
 impl EndorsementRegistry {
     pub fn endorse_project(env: &Env, project_id: u64, user: Address) -> Result<(), ContractError> {
         user.require_auth();
+        Blocklist::require_not_blocked(env, &user)?;
 
         ProjectRegistry::get_project(env, project_id).ok_or(ContractError::ProjectNotFound)?;
 
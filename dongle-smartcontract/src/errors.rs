@@ -140,6 +140,74 @@ pub enum ContractError {
     PayloadHashMismatch = 67,
     /// Tag list is invalid (empty, over-length, too many, bad charset, or duplicates)
     InvalidTags = 68,
+    /// Caller has already voted this review helpful
+    AlreadyVoted = 69,
+    /// Attestor is neither a verified project contract nor a trusted attestor
+    AttestorNotTrusted = 70,
+    /// Project has no approved contract claims to attest interactions against
+    NoVerifiedContracts = 71,
+    /// Caller address is on the admin blocklist
+    AddressBlocked = 72,
+    /// Address is not on the admin blocklist (or its block has expired)
+    AddressNotBlocked = 73,
+    /// Project has an open commit-reveal round (or one is already open)
+    ReviewRoundActive = 74,
+    /// Project has no open commit-reveal review round
+    NoActiveReviewRound = 75,
+    /// The round's commit phase has ended
+    CommitPhaseClosed = 76,
+    /// Reveal attempted outside the round's reveal window
+    NotInRevealWindow = 77,
+    /// No commitment from this reviewer in the current round
+    CommitmentNotFound = 78,
+    /// Revealed rating, CID and salt do not hash to the commitment
+    CommitmentMismatch = 79,
+    /// Round cannot be finalized before its reveal window ends
+    ReviewRoundNotEnded = 80,
+    /// Review can no longer be edited: the policy's edit window has passed
+    ReviewEditWindowClosed = 81,
+    /// Policy requires a new content CID when the rating changes
+    ReviewContentUnchanged = 82,
+    /// Verification tier does not exist
+    VerificationTierNotFound = 83,
+    /// Verification tier is deactivated and accepts no new requests
+    VerificationTierInactive = 84,
+    /// Verifier has already signed off on this verification request
+    AlreadyAttested = 85,
+    /// Verifier's role does not cover this project, tier or assignment
+    VerifierOutOfScope = 86,
+    /// Address does not hold a verifier role
+    VerifierNotFound = 87,
+    /// Evidence CID does not match the verification request's current evidence
+    EvidenceCidMismatch = 88,
+    /// Verification request is already assigned or has sign-offs
+    VerificationInReview = 89,
+    /// Required checklist items have not all been attested
+    ChecklistIncomplete = 90,
+    /// Checklist item does not apply to this verification request
+    ChecklistItemNotFound = 91,
+    /// Public key is not a registered attestor
+    AttestorNotFound = 92,
+    /// Attestor is not trusted for this claim type
+    AttestorOutOfScope = 93,
+    /// Attestation's submission deadline has passed
+    AttestationExpired = 94,
+    /// Attestation nonce has already been used or is not above the stored one
+    AttestationReplayed = 95,
+    /// Actor owns, maintains, has endorsed or is recused from the project
+    ConflictOfInterest = 96,
+    /// Fee payment is older than the payment validity window
+    FeePaymentExpired = 97,
+    /// A new review round cannot open until the previous one has been over
+    /// for as long as it ran
+    ReviewRoundCooldown = 98,
+    /// Review was updated more recently than the review policy's cooldown
+    ReviewCooldownActive = 99,
+    /// Voter's reputation score is below `REPUTATION_MIN_VOTER_SCORE`
+    ReputationTooLow = 101,
+    /// The verification fee payment is not held in escrow, so only the
+    /// treasury can refund it
+    FeeNotEscrowed = 102,
 }
 
 pub type Error = ContractError;
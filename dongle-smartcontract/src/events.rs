@@ -1,5 +1,10 @@
-use crate::types::{AdminActionType, ReviewAction, ReviewEventData, VerificationStatus};
-use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol, Vec};
+use crate::types::{
+    AdminActionType, Attestor, ChecklistAttestation, ChecklistScope, EvidenceCommitment,
+    HistorySummary, PendingVerification, ProjectAttestation, ReviewAction, ReviewEventData,
+    ReviewPolicy, TokenFees, TreasuryPayout, TreasurySplit, VerificationAssignmentMode,
+    VerificationStatus, VerificationSuspension, VerificationTier, VerifierGrant,
+};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Map, String, Symbol, Vec};
 
 pub const REVIEW: Symbol = symbol_short!("REVIEW");
 
@@ -8,6 +13,10 @@ pub const REVIEW: Symbol = symbol_short!("REVIEW");
 pub enum FeeOperation {
     Verification,
     Registration,
+    Review,
+    /// Verification fee payments consumed by a renewal request. Renewals are
+    /// charged the verification fee.
+    Renewal,
 }
 
 // ── Event structs ─────────────────────────────────────────────────────────────
@@ -152,6 +161,35 @@ pub struct ReviewDeletedByAdminEvent {
     pub timestamp: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewHelpfulVotedEvent {
+    pub project_id: u64,
+    pub reviewer: Address,
+    pub voter: Address,
+    pub helpful_votes: u32,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewInteractionVerifiedEvent {
+    pub project_id: u64,
+    pub reviewer: Address,
+    pub attestor: Address,
+    pub from_project_contract: bool,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrustedAttestorEvent {
+    pub attestor: Address,
+    pub admin: Address,
+    pub trusted: bool,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct VerificationRequestedEvent {
@@ -637,6 +675,26 @@ pub fn publish_admin_removed_event(env: &Env, admin: Address) {
     );
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecusedEvent {
+    pub project_id: u64,
+    pub actor: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_recused_event(env: &Env, project_id: u64, actor: Address) {
+    let event_data = RecusedEvent {
+        project_id,
+        actor,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("ADMIN"), symbol_short!("RECUSED"), project_id),
+        event_data,
+    );
+}
+
 pub fn publish_review_reported_event(
     env: &Env,
     project_id: u64,
@@ -716,6 +774,67 @@ pub fn publish_review_deleted_by_admin_event(
     );
 }
 
+pub fn publish_review_helpful_voted_event(
+    env: &Env,
+    project_id: u64,
+    reviewer: Address,
+    voter: Address,
+    helpful_votes: u32,
+) {
+    let event_data = ReviewHelpfulVotedEvent {
+        project_id,
+        reviewer,
+        voter,
+        helpful_votes,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (
+            symbol_short!("REVIEW"),
+            symbol_short!("HELPFUL"),
+            project_id,
+        ),
+        event_data,
+    );
+}
+
+pub fn publish_review_interaction_verified_event(
+    env: &Env,
+    project_id: u64,
+    reviewer: Address,
+    attestor: Address,
+    from_project_contract: bool,
+) {
+    let event_data = ReviewInteractionVerifiedEvent {
+        project_id,
+        reviewer,
+        attestor,
+        from_project_contract,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (
+            symbol_short!("REVIEW"),
+            symbol_short!("VERIFIED"),
+            project_id,
+        ),
+        event_data,
+    );
+}
+
+pub fn publish_trusted_attestor_event(env: &Env, attestor: Address, admin: Address, trusted: bool) {
+    let event_data = TrustedAttestorEvent {
+        attestor,
+        admin,
+        trusted,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("REVIEW"), symbol_short!("ATTESTOR")),
+        event_data,
+    );
+}
+
 pub fn publish_verification_requested_event(
     env: &Env,
     project_id: u64,
@@ -854,6 +973,59 @@ pub fn publish_renewal_history_cleared_event(
     );
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistoryPrunedEvent {
+    pub project_id: u64,
+    pub pruned_count: u32,
+    pub summary: HistorySummary,
+    pub timestamp: u64,
+}
+
+pub fn publish_verification_history_pruned_event(
+    env: &Env,
+    project_id: u64,
+    pruned_count: u32,
+    summary: HistorySummary,
+) {
+    let event_data = HistoryPrunedEvent {
+        project_id,
+        pruned_count,
+        summary,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (
+            symbol_short!("VERIFY"),
+            symbol_short!("HISTPRUNE"),
+            project_id,
+        ),
+        event_data,
+    );
+}
+
+pub fn publish_renewal_history_pruned_event(
+    env: &Env,
+    project_id: u64,
+    pruned_count: u32,
+    summary: HistorySummary,
+) {
+    let event_data = HistoryPrunedEvent {
+        project_id,
+        pruned_count,
+        summary,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (
+            symbol_short!("RENEW"),
+            symbol_short!("HISTPRUNE"),
+            project_id,
+        ),
+        event_data,
+    );
+}
+
 pub fn publish_verification_renewal_requested_event(
     env: &Env,
     project_id: u64,
@@ -1906,6 +2078,161 @@ pub fn publish_fee_refunded_event(
     );
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeRepricedEvent {
+    pub project_id: u64,
+    pub payer: Address,
+    pub token: Option<Address>,
+    pub old_amount: u128,
+    pub new_amount: u128,
+    pub timestamp: u64,
+}
+
+pub fn publish_fee_repriced_event(
+    env: &Env,
+    project_id: u64,
+    payer: Address,
+    token: Option<Address>,
+    old_amount: u128,
+    new_amount: u128,
+) {
+    let event_data = FeeRepricedEvent {
+        project_id,
+        payer,
+        token,
+        old_amount,
+        new_amount,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("FEE"), symbol_short!("REPRICED"), project_id),
+        event_data,
+    );
+}
+
+// ── Accepted Fee Token Events ──────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeTokenSetEvent {
+    pub admin: Address,
+    pub fees: TokenFees,
+    /// `false` when the token was removed from the accepted set.
+    pub accepted: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_fee_token_set_event(env: &Env, admin: Address, fees: TokenFees, accepted: bool) {
+    let event_data = FeeTokenSetEvent {
+        admin,
+        fees,
+        accepted,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("CONFIG"), symbol_short!("FEETOKEN")),
+        event_data,
+    );
+}
+
+// ── Treasury Split Events ──────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasurySplitsSetEvent {
+    pub admin: Address,
+    pub operation: FeeOperation,
+    /// Empty when the splits were cleared.
+    pub splits: Vec<TreasurySplit>,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeSplitEvent {
+    pub project_id: u64,
+    pub token: Address,
+    pub operation: FeeOperation,
+    pub payouts: Vec<TreasuryPayout>,
+    pub timestamp: u64,
+}
+
+pub fn publish_treasury_splits_set_event(
+    env: &Env,
+    admin: Address,
+    operation: FeeOperation,
+    splits: Vec<TreasurySplit>,
+) {
+    let event_data = TreasurySplitsSetEvent {
+        admin,
+        operation,
+        splits,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("CONFIG"), symbol_short!("SPLITS")),
+        event_data,
+    );
+}
+
+pub fn publish_fee_split_event(
+    env: &Env,
+    project_id: u64,
+    token: Address,
+    operation: FeeOperation,
+    payouts: Vec<TreasuryPayout>,
+) {
+    let event_data = FeeSplitEvent {
+        project_id,
+        token,
+        operation,
+        payouts,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("FEE"), symbol_short!("SPLIT"), project_id),
+        event_data,
+    );
+}
+
+// ── Fee Escrow Events ──────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeEscrowSettledEvent {
+    pub project_id: u64,
+    pub payer: Address,
+    pub token: Address,
+    /// Amount released to the treasury or its split recipients.
+    pub released: u128,
+    /// Amount refunded to the payer.
+    pub refunded: u128,
+    pub timestamp: u64,
+}
+
+pub fn publish_fee_escrow_settled_event(
+    env: &Env,
+    project_id: u64,
+    payer: Address,
+    token: Address,
+    released: u128,
+    refunded: u128,
+) {
+    let event_data = FeeEscrowSettledEvent {
+        project_id,
+        payer,
+        token,
+        released,
+        refunded,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("FEE"), symbol_short!("SETTLED"), project_id),
+        event_data,
+    );
+}
+
 // ── Verification Assignment Events ─────────────────────────────────────────
 
 #[contracttype]
@@ -1942,69 +2269,845 @@ pub fn publish_verification_assigned_event(
     );
 }
 
-// ── Reserved Name Events ──────────────────────────────────────────────────
+// ── Verification Tier Events ──────────────────────────────────────────
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ReservedNameAddedEvent {
-    pub name: String,
+pub struct VerificationTierEvent {
+    pub tier: VerificationTier,
     pub admin: Address,
     pub timestamp: u64,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ReservedNameRemovedEvent {
-    pub name: String,
-    pub admin: Address,
+pub struct VerificationTierGrantedEvent {
+    pub project_id: u64,
+    pub tier_id: u32,
+    pub request_id: u64,
+    pub expires_at: u64,
     pub timestamp: u64,
 }
 
-pub fn publish_reserved_name_added_event(env: &Env, name: String, admin: Address) {
-    let event_data = ReservedNameAddedEvent {
-        name,
+/// Emitted when a tier is created or changed (including activation toggles).
+pub fn publish_verification_tier_set_event(env: &Env, tier: VerificationTier, admin: Address) {
+    let event_data = VerificationTierEvent {
+        tier,
         admin,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(
-        (symbol_short!("CONFIG"), symbol_short!("RSVD_ADD")),
-        event_data,
-    );
+    env.events()
+        .publish((symbol_short!("VERIFY"), symbol_short!("TIER")), event_data);
 }
 
-pub fn publish_reserved_name_removed_event(env: &Env, name: String, admin: Address) {
-    let event_data = ReservedNameRemovedEvent {
-        name,
-        admin,
+pub fn publish_verification_tier_granted_event(
+    env: &Env,
+    project_id: u64,
+    tier_id: u32,
+    request_id: u64,
+    expires_at: u64,
+) {
+    let event_data = VerificationTierGrantedEvent {
+        project_id,
+        tier_id,
+        request_id,
+        expires_at,
         timestamp: env.ledger().timestamp(),
     };
     env.events().publish(
-        (symbol_short!("CONFIG"), symbol_short!("RSVD_REM")),
+        (
+            symbol_short!("VERIFY"),
+            symbol_short!("TIERGRANT"),
+            project_id,
+        ),
         event_data,
     );
 }
 
-// ── Project Changelog Events ──────────────────────────────────────────
+// ── Verification Quorum Events ────────────────────────────────────────
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ChangelogAddedEvent {
-    pub changelog_id: u64,
+pub struct VerificationAttestedEvent {
     pub project_id: u64,
-    pub owner: Address,
-    pub cid: String,
+    pub request_id: u64,
+    pub verifier: Address,
+    pub notes_cid: Option<String>,
+    pub approvals: u32,
+    pub required_approvals: u32,
     pub timestamp: u64,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ChangelogRemovedEvent {
-    pub changelog_id: u64,
+pub struct VerificationQuorumSetEvent {
+    pub admin: Address,
+    pub previous_quorum: u32,
+    pub quorum: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_verification_attested_event(
+    env: &Env,
+    project_id: u64,
+    request_id: u64,
+    verifier: Address,
+    notes_cid: Option<String>,
+    approvals: u32,
+    required_approvals: u32,
+) {
+    let event_data = VerificationAttestedEvent {
+        project_id,
+        request_id,
+        verifier,
+        notes_cid,
+        approvals,
+        required_approvals,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("VERIFY"), symbol_short!("ATTEST"), project_id),
+        event_data,
+    );
+}
+
+pub fn publish_verification_quorum_set_event(
+    env: &Env,
+    admin: Address,
+    previous_quorum: u32,
+    quorum: u32,
+) {
+    let event_data = VerificationQuorumSetEvent {
+        admin,
+        previous_quorum,
+        quorum,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("VERIFY"), symbol_short!("QUORUM")),
+        event_data,
+    );
+}
+
+// ── Verifier Role Events ──────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifierGrantedEvent {
+    pub grant: VerifierGrant,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifierRevokedEvent {
+    pub verifier: Address,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationReportAttachedEvent {
+    pub project_id: u64,
+    pub request_id: u64,
+    pub author: Address,
+    pub report_cid: String,
+    pub timestamp: u64,
+}
+
+pub fn publish_verifier_granted_event(env: &Env, grant: VerifierGrant) {
+    let event_data = VerifierGrantedEvent {
+        grant,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("VERIFY"), symbol_short!("VERIFIER")),
+        event_data,
+    );
+}
+
+pub fn publish_verifier_revoked_event(env: &Env, verifier: Address, admin: Address) {
+    let event_data = VerifierRevokedEvent {
+        verifier,
+        admin,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("VERIFY"), symbol_short!("VRFREVOKE")),
+        event_data,
+    );
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestorRegisteredEvent {
+    pub attestor: Attestor,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestorRevokedEvent {
+    pub public_key: BytesN<32>,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationSubmittedEvent {
+    pub project_id: u64,
+    pub attestation: ProjectAttestation,
+}
+
+pub fn publish_attestor_registered_event(env: &Env, attestor: Attestor) {
+    let event_data = AttestorRegisteredEvent {
+        attestor,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("VERIFY"), symbol_short!("ATTESTOR")),
+        event_data,
+    );
+}
+
+pub fn publish_attestor_revoked_event(env: &Env, public_key: BytesN<32>, admin: Address) {
+    let event_data = AttestorRevokedEvent {
+        public_key,
+        admin,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("VERIFY"), symbol_short!("ATTREVOKE")),
+        event_data,
+    );
+}
+
+pub fn publish_attestation_submitted_event(
+    env: &Env,
+    project_id: u64,
+    attestation: ProjectAttestation,
+) {
+    let event_data = AttestationSubmittedEvent {
+        project_id,
+        attestation,
+    };
+    env.events().publish(
+        (
+            symbol_short!("VERIFY"),
+            symbol_short!("OFFCHAIN"),
+            project_id,
+        ),
+        event_data,
+    );
+}
+
+pub fn publish_verification_report_attached_event(
+    env: &Env,
+    project_id: u64,
+    request_id: u64,
+    author: Address,
+    report_cid: String,
+) {
+    let event_data = VerificationReportAttachedEvent {
+        project_id,
+        request_id,
+        author,
+        report_cid,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("VERIFY"), symbol_short!("REPORT"), project_id),
+        event_data,
+    );
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EvidenceCommittedEvent {
+    pub project_id: u64,
+    pub request_id: u64,
+    pub evidence_cid: String,
+    pub evidence_sha256: BytesN<32>,
+    pub proof_count: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_evidence_committed_event(
+    env: &Env,
+    project_id: u64,
+    commitment: &EvidenceCommitment,
+) {
+    let event_data = EvidenceCommittedEvent {
+        project_id,
+        request_id: commitment.request_id,
+        evidence_cid: commitment.evidence_cid.clone(),
+        evidence_sha256: commitment.evidence_sha256.clone(),
+        proof_count: commitment.summary.proof_count,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (
+            symbol_short!("VERIFY"),
+            symbol_short!("EVIDHASH"),
+            project_id,
+        ),
+        event_data,
+    );
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChecklistSetEvent {
+    pub scope: ChecklistScope,
+    pub admin: Address,
+    pub item_count: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_checklist_set_event(
+    env: &Env,
+    scope: ChecklistScope,
+    admin: Address,
+    item_count: u32,
+) {
+    let event_data = ChecklistSetEvent {
+        scope,
+        admin,
+        item_count,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("VERIFY"), symbol_short!("CHECKLIST")),
+        event_data,
+    );
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChecklistItemAttestedEvent {
+    pub project_id: u64,
+    pub request_id: u64,
+    pub attestation: ChecklistAttestation,
+}
+
+pub fn publish_checklist_item_attested_event(
+    env: &Env,
+    project_id: u64,
+    request_id: u64,
+    attestation: ChecklistAttestation,
+) {
+    let event_data = ChecklistItemAttestedEvent {
+        project_id,
+        request_id,
+        attestation,
+    };
+    env.events().publish(
+        (
+            symbol_short!("VERIFY"),
+            symbol_short!("CHECKED"),
+            project_id,
+        ),
+        event_data,
+    );
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationQueueConfiguredEvent {
+    pub admin: Address,
+    pub assignment_mode: VerificationAssignmentMode,
+    pub sla_seconds: u64,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationStaleEvent {
+    pub project_id: u64,
+    pub request_id: u64,
+    pub requested_at: u64,
+    pub age_seconds: u64,
+    pub sla_seconds: u64,
+    pub assigned_admin: Option<Address>,
+    pub timestamp: u64,
+}
+
+pub fn publish_verification_queue_configured_event(
+    env: &Env,
+    admin: Address,
+    assignment_mode: VerificationAssignmentMode,
+    sla_seconds: u64,
+) {
+    let event_data = VerificationQueueConfiguredEvent {
+        admin,
+        assignment_mode,
+        sla_seconds,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("VERIFY"), symbol_short!("QUEUECFG")),
+        event_data,
+    );
+}
+
+pub fn publish_verification_stale_event(
+    env: &Env,
+    pending: &PendingVerification,
+    sla_seconds: u64,
+) {
+    let event_data = VerificationStaleEvent {
+        project_id: pending.project_id,
+        request_id: pending.request_id,
+        requested_at: pending.requested_at,
+        age_seconds: pending.age_seconds,
+        sla_seconds,
+        assigned_admin: pending.assigned_admin.clone(),
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (
+            symbol_short!("VERIFY"),
+            symbol_short!("STALE"),
+            pending.project_id,
+        ),
+        event_data,
+    );
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationExpiringSoonEvent {
+    pub project_id: u64,
+    pub owner: Address,
+    pub expires_at: u64,
+    pub window_seconds: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_verification_expiring_soon_event(
+    env: &Env,
+    project_id: u64,
+    owner: Address,
+    expires_at: u64,
+    window_seconds: u64,
+) {
+    let event_data = VerificationExpiringSoonEvent {
+        project_id,
+        owner,
+        expires_at,
+        window_seconds,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (
+            symbol_short!("VERIFY"),
+            symbol_short!("EXPSOON"),
+            project_id,
+        ),
+        event_data,
+    );
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationWithdrawnEvent {
+    pub project_id: u64,
+    pub request_id: u64,
+    pub owner: Address,
+    pub refund_amount: u128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalRefundSetEvent {
+    pub admin: Address,
+    pub previous_bps: u32,
+    pub refund_bps: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_verification_withdrawn_event(
+    env: &Env,
+    project_id: u64,
+    request_id: u64,
+    owner: Address,
+    refund_amount: u128,
+) {
+    let event_data = VerificationWithdrawnEvent {
+        project_id,
+        request_id,
+        owner,
+        refund_amount,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (
+            symbol_short!("VERIFY"),
+            symbol_short!("WITHDRAWN"),
+            project_id,
+        ),
+        event_data,
+    );
+}
+
+pub fn publish_withdrawal_refund_set_event(
+    env: &Env,
+    admin: Address,
+    previous_bps: u32,
+    refund_bps: u32,
+) {
+    let event_data = WithdrawalRefundSetEvent {
+        admin,
+        previous_bps,
+        refund_bps,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("VERIFY"), symbol_short!("REFUNDBPS")),
+        event_data,
+    );
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationSuspendedEvent {
+    pub project_id: u64,
+    pub suspension: VerificationSuspension,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationResumedEvent {
+    pub project_id: u64,
+    pub request_id: u64,
+    pub caller: Address,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_verification_suspended_event(
+    env: &Env,
+    project_id: u64,
+    suspension: VerificationSuspension,
+) {
+    let event_data = VerificationSuspendedEvent {
+        project_id,
+        suspension,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (
+            symbol_short!("VERIFY"),
+            symbol_short!("SUSPENDED"),
+            project_id,
+        ),
+        event_data,
+    );
+}
+
+pub fn publish_verification_resumed_event(
+    env: &Env,
+    project_id: u64,
+    request_id: u64,
+    caller: Address,
+    expires_at: u64,
+) {
+    let event_data = VerificationResumedEvent {
+        project_id,
+        request_id,
+        caller,
+        expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (
+            symbol_short!("VERIFY"),
+            symbol_short!("RESUMED"),
+            project_id,
+        ),
+        event_data,
+    );
+}
+
+// ── Reserved Name Events ──────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReservedNameAddedEvent {
+    pub name: String,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReservedNameRemovedEvent {
+    pub name: String,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_reserved_name_added_event(env: &Env, name: String, admin: Address) {
+    let event_data = ReservedNameAddedEvent {
+        name,
+        admin,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("CONFIG"), symbol_short!("RSVD_ADD")),
+        event_data,
+    );
+}
+
+pub fn publish_reserved_name_removed_event(env: &Env, name: String, admin: Address) {
+    let event_data = ReservedNameRemovedEvent {
+        name,
+        admin,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("CONFIG"), symbol_short!("RSVD_REM")),
+        event_data,
+    );
+}
+
+// ── Project Changelog Events ──────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChangelogAddedEvent {
+    pub changelog_id: u64,
+    pub project_id: u64,
+    pub owner: Address,
+    pub cid: String,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChangelogRemovedEvent {
+    pub changelog_id: u64,
     pub project_id: u64,
     pub owner: Address,
     pub timestamp: u64,
 }
 
+// ── Commit-Reveal Review Round Events ──────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewRoundOpenedEvent {
+    pub project_id: u64,
+    pub round_id: u32,
+    pub opened_by: Address,
+    pub commit_ends_at: u64,
+    pub reveal_ends_at: u64,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewCommitRevealEvent {
+    pub project_id: u64,
+    pub round_id: u32,
+    pub reviewer: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewRoundFinalizedEvent {
+    pub project_id: u64,
+    pub round_id: u32,
+    pub revealed_count: u32,
+    /// Commitments that were never revealed and expired.
+    pub expired_count: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_review_round_opened_event(
+    env: &Env,
+    project_id: u64,
+    round_id: u32,
+    opened_by: Address,
+    commit_ends_at: u64,
+    reveal_ends_at: u64,
+) {
+    let event_data = ReviewRoundOpenedEvent {
+        project_id,
+        round_id,
+        opened_by,
+        commit_ends_at,
+        reveal_ends_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("ROUND"), symbol_short!("OPENED"), project_id),
+        event_data,
+    );
+}
+
+pub fn publish_review_committed_event(
+    env: &Env,
+    project_id: u64,
+    round_id: u32,
+    reviewer: Address,
+) {
+    let event_data = ReviewCommitRevealEvent {
+        project_id,
+        round_id,
+        reviewer,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events()
+        .publish((REVIEW, symbol_short!("COMMITTED"), project_id), event_data);
+}
+
+pub fn publish_review_revealed_event(env: &Env, project_id: u64, round_id: u32, reviewer: Address) {
+    let event_data = ReviewCommitRevealEvent {
+        project_id,
+        round_id,
+        reviewer,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events()
+        .publish((REVIEW, symbol_short!("REVEALED"), project_id), event_data);
+}
+
+pub fn publish_review_round_finalized_event(
+    env: &Env,
+    project_id: u64,
+    round_id: u32,
+    revealed_count: u32,
+    expired_count: u32,
+) {
+    let event_data = ReviewRoundFinalizedEvent {
+        project_id,
+        round_id,
+        revealed_count,
+        expired_count,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (
+            symbol_short!("ROUND"),
+            symbol_short!("FINALIZED"),
+            project_id,
+        ),
+        event_data,
+    );
+}
+
+// ── Review Policy Events ───────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewPolicyChangedEvent {
+    /// `None` for the global policy, otherwise the project whose override changed.
+    pub project_id: Option<u64>,
+    /// Policy in effect after the change.
+    pub policy: ReviewPolicy,
+    pub changed_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_review_policy_changed_event(
+    env: &Env,
+    project_id: Option<u64>,
+    policy: ReviewPolicy,
+    changed_by: Address,
+) {
+    let event_data = ReviewPolicyChangedEvent {
+        project_id,
+        policy,
+        changed_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events()
+        .publish((REVIEW, symbol_short!("POLICY")), event_data);
+}
+
+// ── Blocklist Events ───────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddressBlockedEvent {
+    pub address: Address,
+    pub admin: Address,
+    pub reason_cid: String,
+    pub expires_at: Option<u64>,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddressUnblockedEvent {
+    pub address: Address,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewsBulkHiddenEvent {
+    pub reviewer: Address,
+    pub admin: Address,
+    pub hidden_count: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_address_blocked_event(
+    env: &Env,
+    address: Address,
+    admin: Address,
+    reason_cid: String,
+    expires_at: Option<u64>,
+) {
+    let event_data = AddressBlockedEvent {
+        address,
+        admin,
+        reason_cid,
+        expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events()
+        .publish((symbol_short!("BLOCK"), symbol_short!("ADDED")), event_data);
+}
+
+pub fn publish_address_unblocked_event(env: &Env, address: Address, admin: Address) {
+    let event_data = AddressUnblockedEvent {
+        address,
+        admin,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("BLOCK"), symbol_short!("REMOVED")),
+        event_data,
+    );
+}
+
+pub fn publish_reviews_bulk_hidden_event(
+    env: &Env,
+    reviewer: Address,
+    admin: Address,
+    hidden_count: u32,
+) {
+    let event_data = ReviewsBulkHiddenEvent {
+        reviewer,
+        admin,
+        hidden_count,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.events().publish(
+        (symbol_short!("REVIEW"), symbol_short!("BULKHIDE")),
+        event_data,
+    );
+}
+
 // ── Contract Pause / Emergency Stop Events ─────────────────────────────
 
 #[contracttype]
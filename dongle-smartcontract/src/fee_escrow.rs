@@ -0,0 +1,285 @@
+//! Escrow of verification fees until the verification decision.
+//!
+//! In escrow mode, verification fee payments are transferred to the contract
+//! instead of the treasury. Once a request has consumed the payment, the fee
+//! is released to the treasury (or its split recipients) when the request or
+//! renewal is approved, and settled under the configured refund policy when
+//! it is rejected. Cancelling an unconsumed payment refunds it in full, and
+//! withdrawing a request refunds the withdrawal share. A request or renewal
+//! that leaves Pending without a decision, such as a renewal dropped by the
+//! expiry sweep or a major metadata change, is refunded in full. Payments
+//! made before escrow mode was enabled keep their treasury refund paths.
+//! Verification fees can only be split across recipients in escrow mode, so
+//! it cannot be turned off while such splits are set.
+
+use crate::admin_action_log::AdminActionLog;
+use crate::auth::require_admin_auth;
+use crate::constants::MAX_BPS;
+use crate::errors::ContractError;
+use crate::events::{publish_fee_escrow_settled_event, FeeOperation};
+use crate::fee_manager::FeeManager;
+use crate::fee_revenue::FeeRevenueLedger;
+use crate::storage_keys::FeeKey;
+use crate::types::{
+    AdminActionType, EscrowRefundPolicy, EscrowTokenTotal, EscrowedFee, FeeEscrowConfig,
+};
+use soroban_sdk::{token, Address, Env, Vec};
+
+pub struct FeeEscrowManager;
+
+impl FeeEscrowManager {
+    pub fn get_config(env: &Env) -> FeeEscrowConfig {
+        env.storage()
+            .persistent()
+            .get(&FeeKey::FeeEscrowConfig)
+            .unwrap_or(FeeEscrowConfig {
+                enabled: false,
+                refund_policy: EscrowRefundPolicy::Full,
+            })
+    }
+
+    /// Admin: turn escrow mode on or off and set the rejection refund
+    /// policy. Same threshold rule as `set_fee`.
+    pub fn set_config(
+        env: &Env,
+        admin: Address,
+        config: FeeEscrowConfig,
+    ) -> Result<(), ContractError> {
+        require_admin_auth(env, &admin)?;
+
+        if crate::admin_manager::AdminManager::get_admin_approval_threshold(env) > 1 {
+            return Err(ContractError::Unauthorized);
+        }
+        if let EscrowRefundPolicy::Partial(bps) = config.refund_policy {
+            if bps > MAX_BPS {
+                return Err(ContractError::InvalidInput);
+            }
+        }
+        if !config.enabled
+            && !FeeManager::get_treasury_splits(env, FeeOperation::Verification).is_empty()
+        {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&FeeKey::FeeEscrowConfig, &config);
+        AdminActionLog::record_action(env, admin, AdminActionType::FeeChanged, None, None, None);
+        Ok(())
+    }
+
+    pub fn is_enabled(env: &Env) -> bool {
+        Self::get_config(env).enabled
+    }
+
+    /// The fee held for a project, if any.
+    pub fn get_escrow(env: &Env, project_id: u64) -> Option<EscrowedFee> {
+        env.storage()
+            .persistent()
+            .get(&FeeKey::FeeEscrow(project_id))
+    }
+
+    fn get_total(env: &Env, token: &Address) -> u128 {
+        env.storage()
+            .persistent()
+            .get(&FeeKey::EscrowedTotal(token.clone()))
+            .unwrap_or(0)
+    }
+
+    fn set_total(env: &Env, token: &Address, total: u128) {
+        env.storage()
+            .persistent()
+            .set(&FeeKey::EscrowedTotal(token.clone()), &total);
+    }
+
+    /// Fees currently held per token, with the contract's balance of each
+    /// token so the two can be reconciled.
+    pub fn get_totals(env: &Env) -> Vec<EscrowTokenTotal> {
+        let tokens: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&FeeKey::EscrowTokens)
+            .unwrap_or_else(|| Vec::new(env));
+        let mut totals = Vec::new(env);
+        for token in tokens.iter() {
+            let balance = token::Client::new(env, &token).balance(&env.current_contract_address());
+            totals.push_back(EscrowTokenTotal {
+                escrowed: Self::get_total(env, &token),
+                token,
+                balance,
+            });
+        }
+        totals
+    }
+
+    /// Move a verification fee payment from the payer into escrow. A project
+    /// holds at most one escrowed fee.
+    pub(crate) fn hold(
+        env: &Env,
+        project_id: u64,
+        payer: &Address,
+        token: &Address,
+        amount: u128,
+    ) -> Result<(), ContractError> {
+        let key = FeeKey::FeeEscrow(project_id);
+        if env.storage().persistent().has(&key) {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        token::Client::new(env, token).transfer(
+            payer,
+            &env.current_contract_address(),
+            &(amount as i128),
+        );
+        env.storage().persistent().set(
+            &key,
+            &EscrowedFee {
+                payer: payer.clone(),
+                token: token.clone(),
+                amount,
+                escrowed_at: env.ledger().timestamp(),
+            },
+        );
+
+        let mut tokens: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&FeeKey::EscrowTokens)
+            .unwrap_or_else(|| Vec::new(env));
+        if !tokens.contains(token) {
+            tokens.push_back(token.clone());
+            env.storage()
+                .persistent()
+                .set(&FeeKey::EscrowTokens, &tokens);
+        }
+        Self::set_total(
+            env,
+            token,
+            Self::get_total(env, token).saturating_add(amount),
+        );
+        Ok(())
+    }
+
+    /// Remove and return a project's escrowed fee without moving funds.
+    pub(crate) fn take(env: &Env, project_id: u64) -> Option<EscrowedFee> {
+        let escrow = Self::get_escrow(env, project_id)?;
+        env.storage()
+            .persistent()
+            .remove(&FeeKey::FeeEscrow(project_id));
+        Self::set_total(
+            env,
+            &escrow.token,
+            Self::get_total(env, &escrow.token).saturating_sub(escrow.amount),
+        );
+        Some(escrow)
+    }
+
+    /// Change a project's escrowed fee to `amount`, taking the difference
+    /// from the payer or refunding it to them.
+    pub(crate) fn adjust(env: &Env, project_id: u64, amount: u128) {
+        let Some(mut escrow) = Self::get_escrow(env, project_id) else {
+            return;
+        };
+        let client = token::Client::new(env, &escrow.token);
+        let contract = env.current_contract_address();
+        let total = Self::get_total(env, &escrow.token);
+        if amount > escrow.amount {
+            let top_up = amount - escrow.amount;
+            client.transfer(&escrow.payer, &contract, &(top_up as i128));
+            Self::set_total(env, &escrow.token, total.saturating_add(top_up));
+        } else {
+            let refund = escrow.amount - amount;
+            client.transfer(&contract, &escrow.payer, &(refund as i128));
+            Self::set_total(env, &escrow.token, total.saturating_sub(refund));
+        }
+
+        escrow.amount = amount;
+        env.storage()
+            .persistent()
+            .set(&FeeKey::FeeEscrow(project_id), &escrow);
+    }
+
+    /// Refund up to `refund` of a consumed escrowed fee to the payer and
+    /// release the rest. `operation` is the fee type the refund is counted
+    /// against. Returns whether an escrowed fee was settled; a fee whose
+    /// payment has not been consumed yet is left in escrow.
+    pub(crate) fn settle(
+        env: &Env,
+        project_id: u64,
+        refund: u128,
+        operation: FeeOperation,
+    ) -> Result<bool, ContractError> {
+        if FeeManager::is_fee_paid(env, project_id) {
+            return Ok(false);
+        }
+        let Some(escrow) = Self::take(env, project_id) else {
+            return Ok(false);
+        };
+
+        let contract = env.current_contract_address();
+        let refunded = refund.min(escrow.amount);
+        let released = escrow.amount - refunded;
+        if refunded > 0 {
+            token::Client::new(env, &escrow.token).transfer(
+                &contract,
+                &escrow.payer,
+                &(refunded as i128),
+            );
+            FeeRevenueLedger::record_refunded(env, &escrow.token, operation, refunded);
+        }
+        if released > 0 {
+            FeeManager::pay_out(
+                env,
+                &contract,
+                &escrow.token,
+                released,
+                FeeOperation::Verification,
+                project_id,
+            )?;
+        }
+        publish_fee_escrow_settled_event(
+            env,
+            project_id,
+            escrow.payer,
+            escrow.token,
+            released,
+            refunded,
+        );
+        Ok(true)
+    }
+
+    /// Refund a project's consumed escrowed fee in full when its request or
+    /// renewal leaves Pending without a decision.
+    pub(crate) fn refund_undecided(env: &Env, project_id: u64, operation: FeeOperation) {
+        // Nothing is released to the treasury, so settling cannot fail.
+        let _ = Self::settle(env, project_id, u128::MAX, operation);
+    }
+
+    /// Settle a project's consumed escrowed fee on a verification or renewal
+    /// decision: release it all on approval, or apply the refund policy on
+    /// rejection. `operation` is `Verification` or `Renewal`.
+    pub(crate) fn settle_decision(
+        env: &Env,
+        project_id: u64,
+        approved: bool,
+        operation: FeeOperation,
+    ) -> Result<(), ContractError> {
+        let Some(escrow) = Self::get_escrow(env, project_id) else {
+            return Ok(());
+        };
+        let refund = if approved {
+            0
+        } else {
+            match Self::get_config(env).refund_policy {
+                EscrowRefundPolicy::Full => escrow.amount,
+                EscrowRefundPolicy::Partial(bps) => {
+                    let (bps, max_bps) = (bps as u128, MAX_BPS as u128);
+                    (escrow.amount / max_bps) * bps + (escrow.amount % max_bps) * bps / max_bps
+                }
+                EscrowRefundPolicy::KeepReviewFee(fee) => escrow.amount.saturating_sub(fee),
+            }
+        };
+        Self::settle(env, project_id, refund, operation)?;
+        Ok(())
+    }
+}
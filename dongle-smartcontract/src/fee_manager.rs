@@ -1,15 +1,51 @@
 //! Fee configuration and payment with validation and events.
+//!
+//! Fees are priced in the default `FeeConfig` token and, optionally, in a set
+//! of admin-accepted tokens that each carry their own `TokenFees`. A payment
+//! is charged at the price of the token it is made in, and the token is kept
+//! on its `FeePaymentRecord` so refunds go back in that same token. Tier fees
+//! are only priced in the default token. The review fee is paid by the
+//! reviewer, per project, and consumed by the review it pays for.
+//!
+//! Each fee type can split its revenue across several recipients in basis
+//! points instead of paying it all to the treasury. Shares are rounded down
+//! and the rounding dust goes to the first recipient. Verification fees are
+//! refundable, so they can only be split in escrow mode, where refunds come
+//! out of escrow; verification fees paid outside escrow went to the treasury
+//! in full, which refunds them.
+//!
+//! Verification fee payments expire after the window configured when they
+//! were paid; payments made before expiry existed never expire. Expired
+//! payments cannot be used for a request, and payments priced below the
+//! current fee are rejected; the owner can re-price a payment to the current
+//! fee, which renews it, or reclaim an expired one. A tier payment is
+//! re-priced to its tier's current fee. Outside escrow, a payment can only be
+//! re-priced upwards, and the difference goes to the treasury, which refunds
+//! such payments; their payer cannot reclaim them alone, so an admin cancels
+//! them with `cancel_fee_payment`, which the treasury authorizes.
 
 use crate::admin_action_log::AdminActionLog;
 use crate::auth::{require_admin_auth, require_self_auth};
+use crate::constants::{
+    FEE_PAYMENT_EXPIRY_SECONDS, MAX_ACCEPTED_FEE_TOKENS, MAX_BPS, MAX_TREASURY_SPLIT_RECIPIENTS,
+};
 use crate::errors::ContractError;
 use crate::events::{
-    publish_fee_consumed_event, publish_fee_paid_event, publish_fee_set_event, FeeOperation,
+    publish_fee_consumed_event, publish_fee_paid_event, publish_fee_payment_cleared_event,
+    publish_fee_repriced_event, publish_fee_set_event, publish_fee_split_event,
+    publish_fee_token_set_event, publish_treasury_splits_set_event, FeeOperation,
 };
+use crate::fee_escrow::FeeEscrowManager;
+use crate::fee_revenue::FeeRevenueLedger;
 use crate::project_registry::ProjectRegistry;
-use crate::storage_keys::{ExtensionKey, StorageKey};
-use crate::types::{AdminActionType, FeeConfig, FeePaymentRecord};
-use soroban_sdk::{Address, Env};
+use crate::review_registry::ReviewRegistry;
+use crate::storage_keys::{ExtensionKey, FeatureKey, FeeKey, StorageKey};
+use crate::types::{
+    AdminActionType, FeeConfig, FeePaymentDetails, FeePaymentRecord, FeePaymentStatus, TokenFees,
+    TreasuryPayout, TreasurySplit,
+};
+use crate::verification_registry::VerificationTierRegistry;
+use soroban_sdk::{Address, Env, Vec};
 
 pub struct FeeManager;
 
@@ -55,10 +91,276 @@ impl FeeManager {
         Ok(())
     }
 
+    /// Accept fee payments in `fees.token` at the given amounts, or update
+    /// the amounts of an already accepted token (admin only). The default fee
+    /// configuration must be set first, since it holds the treasury.
+    pub fn set_accepted_fee_token(
+        env: &Env,
+        admin: Address,
+        fees: TokenFees,
+    ) -> Result<(), ContractError> {
+        require_admin_auth(env, &admin)?;
+
+        if crate::admin_manager::AdminManager::get_admin_approval_threshold(env) > 1 {
+            return Err(ContractError::Unauthorized);
+        }
+        Self::get_fee_config(env)?;
+
+        let mut tokens = Self::list_accepted_fee_tokens(env);
+        if !tokens.contains(&fees.token) {
+            if tokens.len() >= MAX_ACCEPTED_FEE_TOKENS {
+                return Err(ContractError::InvalidInput);
+            }
+            tokens.push_back(fees.token.clone());
+            env.storage()
+                .persistent()
+                .set(&FeatureKey::AcceptedFeeTokens, &tokens);
+        }
+        env.storage()
+            .persistent()
+            .set(&FeatureKey::AcceptedFeeToken(fees.token.clone()), &fees);
+
+        publish_fee_token_set_event(env, admin.clone(), fees, true);
+        AdminActionLog::record_action(env, admin, AdminActionType::FeeChanged, None, None, None);
+        Ok(())
+    }
+
+    /// Stop accepting fee payments in `token` (admin only). Payments already
+    /// made in it can still be consumed and are refunded in it.
+    pub fn remove_accepted_fee_token(
+        env: &Env,
+        admin: Address,
+        token: Address,
+    ) -> Result<(), ContractError> {
+        require_admin_auth(env, &admin)?;
+
+        if crate::admin_manager::AdminManager::get_admin_approval_threshold(env) > 1 {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let fees = Self::get_token_fees(env, &token).ok_or(ContractError::InvalidInput)?;
+        let mut tokens = Self::list_accepted_fee_tokens(env);
+        if let Some(index) = tokens.first_index_of(&token) {
+            tokens.remove(index);
+        }
+        if tokens.is_empty() {
+            env.storage()
+                .persistent()
+                .remove(&FeatureKey::AcceptedFeeTokens);
+        } else {
+            env.storage()
+                .persistent()
+                .set(&FeatureKey::AcceptedFeeTokens, &tokens);
+        }
+        env.storage()
+            .persistent()
+            .remove(&FeatureKey::AcceptedFeeToken(token));
+
+        publish_fee_token_set_event(env, admin.clone(), fees, false);
+        AdminActionLog::record_action(env, admin, AdminActionType::FeeChanged, None, None, None);
+        Ok(())
+    }
+
+    /// Tokens accepted for fee payments besides the default `FeeConfig` token.
+    pub fn list_accepted_fee_tokens(env: &Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::AcceptedFeeTokens)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Fee amounts for an accepted token, if it is accepted.
+    pub fn get_token_fees(env: &Env, token: &Address) -> Option<TokenFees> {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::AcceptedFeeToken(token.clone()))
+    }
+
+    fn default_fee(env: &Env, operation: &FeeOperation) -> u128 {
+        match operation {
+            FeeOperation::Review => ReviewRegistry::get_review_eligibility_config(env).review_fee,
+            _ => match Self::get_fee_config(env) {
+                Ok(config) if *operation == FeeOperation::Registration => config.registration_fee,
+                Ok(config) => config.verification_fee,
+                Err(_) => 0,
+            },
+        }
+    }
+
+    fn token_fee(fees: &TokenFees, operation: &FeeOperation) -> u128 {
+        match operation {
+            FeeOperation::Verification | FeeOperation::Renewal => fees.verification_fee,
+            FeeOperation::Registration => fees.registration_fee,
+            FeeOperation::Review => fees.review_fee,
+        }
+    }
+
+    /// Fee charged for `operation` when paying in `token`: the accepted
+    /// token's own amount, or the default amount when `token` is the default
+    /// fee token. Any other token is rejected with `InvalidProjectData`.
+    pub fn get_fee_quote(
+        env: &Env,
+        token: Option<Address>,
+        operation: FeeOperation,
+    ) -> Result<u128, ContractError> {
+        if let Some(fees) = token
+            .as_ref()
+            .and_then(|token| Self::get_token_fees(env, token))
+        {
+            return Ok(Self::token_fee(&fees, &operation));
+        }
+        if Self::get_fee_config(env)?.token != token {
+            return Err(ContractError::InvalidProjectData);
+        }
+        Ok(Self::default_fee(env, &operation))
+    }
+
+    /// Whether `operation` costs anything in the default token or in any
+    /// accepted token, in which case a fee payment must be consumed for it.
+    pub fn is_fee_required(env: &Env, operation: FeeOperation) -> bool {
+        Self::default_fee(env, &operation) > 0
+            || Self::list_accepted_fee_tokens(env).iter().any(|token| {
+                Self::get_token_fees(env, &token)
+                    .is_some_and(|fees| Self::token_fee(&fees, &operation) > 0)
+            })
+    }
+
+    /// Split a fee type's revenue across `splits` (admin only), or pay it all
+    /// to the treasury again with an empty list. Same threshold rule as
+    /// `set_fee`; multisig deployments use `ProposalPayload::SetTreasurySplits`.
+    pub fn set_treasury_splits(
+        env: &Env,
+        admin: Address,
+        operation: FeeOperation,
+        splits: Vec<TreasurySplit>,
+    ) -> Result<(), ContractError> {
+        require_admin_auth(env, &admin)?;
+
+        if crate::admin_manager::AdminManager::get_admin_approval_threshold(env) > 1 {
+            return Err(ContractError::Unauthorized);
+        }
+        Self::store_treasury_splits(env, operation, splits, admin)
+    }
+
+    /// Validate and persist a fee type's splits. Shared by the direct admin
+    /// setter and governance proposal execution; callers check authorization.
+    pub(crate) fn store_treasury_splits(
+        env: &Env,
+        operation: FeeOperation,
+        splits: Vec<TreasurySplit>,
+        changed_by: Address,
+    ) -> Result<(), ContractError> {
+        // Renewals consume verification payments, which are split as such.
+        if operation == FeeOperation::Renewal || splits.len() > MAX_TREASURY_SPLIT_RECIPIENTS {
+            return Err(ContractError::InvalidInput);
+        }
+        let mut total: u32 = 0;
+        for (index, split) in splits.iter().enumerate() {
+            if split.bps == 0
+                || splits
+                    .iter()
+                    .skip(index + 1)
+                    .any(|other| other.recipient == split.recipient)
+            {
+                return Err(ContractError::InvalidInput);
+            }
+            total = total.saturating_add(split.bps);
+        }
+        if !splits.is_empty() && total != MAX_BPS {
+            return Err(ContractError::InvalidInput);
+        }
+        // Recipients cannot be charged for refunds, so only escrowed
+        // verification fees may be split.
+        if operation == FeeOperation::Verification
+            && !splits.is_empty()
+            && !FeeEscrowManager::is_enabled(env)
+        {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        let key = FeatureKey::TreasurySplits(operation.clone());
+        if splits.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &splits);
+        }
+
+        publish_treasury_splits_set_event(env, changed_by.clone(), operation, splits);
+        AdminActionLog::record_action(
+            env,
+            changed_by,
+            AdminActionType::FeeChanged,
+            None,
+            None,
+            None,
+        );
+        Ok(())
+    }
+
+    /// Recipients sharing a fee type's revenue; empty when it all goes to the
+    /// treasury.
+    pub fn get_treasury_splits(env: &Env, operation: FeeOperation) -> Vec<TreasurySplit> {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::TreasurySplits(operation))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Each recipient's share of `amount`, rounded down, with the rounding
+    /// dust added to the first recipient.
+    fn split_amount(env: &Env, amount: u128, splits: &Vec<TreasurySplit>) -> Vec<TreasuryPayout> {
+        let mut payouts = Vec::new(env);
+        let mut paid: u128 = 0;
+        for split in splits.iter() {
+            let bps = split.bps as u128;
+            let max_bps = MAX_BPS as u128;
+            // Split the multiplication so large amounts cannot overflow.
+            let share = (amount / max_bps) * bps + (amount % max_bps) * bps / max_bps;
+            paid += share;
+            payouts.push_back(TreasuryPayout {
+                recipient: split.recipient,
+                amount: share,
+            });
+        }
+        if let Some(mut first) = payouts.get(0) {
+            first.amount += amount - paid;
+            payouts.set(0, first);
+        }
+        payouts
+    }
+
+    /// Transfer `amount` of `token` from `from` to the treasury, or to the
+    /// fee type's split recipients.
+    pub(crate) fn pay_out(
+        env: &Env,
+        from: &Address,
+        token: &Address,
+        amount: u128,
+        operation: FeeOperation,
+        event_project_id: u64,
+    ) -> Result<(), ContractError> {
+        let client = soroban_sdk::token::Client::new(env, token);
+        let splits = Self::get_treasury_splits(env, operation.clone());
+        if splits.is_empty() {
+            client.transfer(from, &Self::get_treasury(env)?, &(amount as i128));
+            return Ok(());
+        }
+
+        let payouts = Self::split_amount(env, amount, &splits);
+        for payout in payouts.iter() {
+            if payout.amount > 0 {
+                client.transfer(from, &payout.recipient, &(payout.amount as i128));
+            }
+        }
+        publish_fee_split_event(env, event_project_id, token.clone(), operation, payouts);
+        Ok(())
+    }
+
     /// Shared payment path for verification and registration fees.
     ///
-    /// Validates fee config/treasury, transfers tokens (when amount > 0), sets the
-    /// paid flag, stores a [`FeePaymentRecord`], and emits a fee-paid event.
+    /// Validates fee config/treasury, transfers `amount` of `token` (when
+    /// amount > 0), sets the paid flag, stores a [`FeePaymentRecord`], and
+    /// emits a fee-paid event. Callers price `amount` for `token`.
     fn execute_fee_payment(
         env: &Env,
         payer: Address,
@@ -69,16 +371,8 @@ impl FeeManager {
         event_project_id: u64,
         operation: FeeOperation,
     ) -> Result<(), ContractError> {
-        let config = Self::get_fee_config(env)?;
-        let treasury: Address = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::Treasury)
-            .ok_or(ContractError::TreasuryNotSet)?;
-
-        if config.token != token {
-            return Err(ContractError::InvalidProjectData);
-        }
+        Self::get_fee_config(env)?;
+        Self::get_treasury(env)?;
 
         if amount > 0 {
             // Safety: fee amounts are stored as u128 but the token interface requires i128.
@@ -86,13 +380,24 @@ impl FeeManager {
             if amount > i128::MAX as u128 {
                 return Err(ContractError::InvalidProjectData);
             }
-            // set_fee enforces that token is Some when fees are non-zero, so this
-            // ok_or branch is a defensive guard against corrupted storage state.
-            let token_address = config.token.ok_or(ContractError::FeeConfigNotSet)?;
-            let client = soroban_sdk::token::Client::new(env, &token_address);
+            // Only a default config without a token prices fees for `None`, so
+            // this ok_or branch is a defensive guard against corrupted state.
+            let token_address = token.clone().ok_or(ContractError::FeeConfigNotSet)?;
             // Transfer must succeed before we set the payment flag.
             // If transfer fails, this function returns early without setting the flag.
-            client.transfer(&payer, &treasury, &(amount as i128));
+            if operation == FeeOperation::Verification && FeeEscrowManager::is_enabled(env) {
+                FeeEscrowManager::hold(env, event_project_id, &payer, &token_address, amount)?;
+            } else {
+                Self::pay_out(
+                    env,
+                    &payer,
+                    &token_address,
+                    amount,
+                    operation.clone(),
+                    event_project_id,
+                )?;
+            }
+            FeeRevenueLedger::record_collected(env, &token_address, operation.clone(), amount);
         }
 
         // Only set payment flag after successful token transfer
@@ -140,7 +445,7 @@ impl FeeManager {
             return Err(ContractError::Unauthorized);
         }
 
-        let amount = Self::get_fee_config(env)?.verification_fee;
+        let amount = Self::get_fee_quote(env, token.clone(), FeeOperation::Verification)?;
         Self::execute_fee_payment(
             env,
             payer,
@@ -150,7 +455,51 @@ impl FeeManager {
             ExtensionKey::FeePaymentDetails(project_id),
             project_id,
             FeeOperation::Verification,
-        )
+        )?;
+        env.storage()
+            .persistent()
+            .remove(&FeeKey::FeePaymentTier(project_id));
+        Self::renew_payment_expiry(env, project_id);
+        Ok(())
+    }
+
+    /// Pay the fee for a tiered verification request or renewal. Works like
+    /// `pay_fee`, but charges the tier's fee instead of the global one, which
+    /// is only payable in the default fee token.
+    pub fn pay_tier_fee(
+        env: &Env,
+        payer: Address,
+        project_id: u64,
+        tier_id: u32,
+        token: Option<Address>,
+    ) -> Result<(), ContractError> {
+        require_self_auth(&payer);
+
+        let project =
+            ProjectRegistry::get_project(env, project_id).ok_or(ContractError::ProjectNotFound)?;
+        if project.owner != payer {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let tier = VerificationTierRegistry::require_active_tier(env, tier_id)?;
+        if Self::get_fee_config(env)?.token != token {
+            return Err(ContractError::InvalidProjectData);
+        }
+        Self::execute_fee_payment(
+            env,
+            payer,
+            tier.fee,
+            token,
+            StorageKey::FeePaidForProject(project_id),
+            ExtensionKey::FeePaymentDetails(project_id),
+            project_id,
+            FeeOperation::Verification,
+        )?;
+        env.storage()
+            .persistent()
+            .set(&FeeKey::FeePaymentTier(project_id), &tier_id);
+        Self::renew_payment_expiry(env, project_id);
+        Ok(())
     }
 
     /// Check if the fee has been paid for a project
@@ -186,6 +535,11 @@ impl FeeManager {
         if !Self::is_fee_paid(env, project_id) {
             return Err(ContractError::InsufficientFee);
         }
+        if Self::get_fee_payment_status(env, project_id)
+            .is_some_and(|details| details.status == FeePaymentStatus::Expired)
+        {
+            return Err(ContractError::FeePaymentExpired);
+        }
         Self::execute_consume_fee_payment(
             env,
             StorageKey::FeePaidForProject(project_id),
@@ -253,7 +607,7 @@ impl FeeManager {
     ) -> Result<(), ContractError> {
         require_self_auth(&payer);
 
-        let amount = Self::get_fee_config(env)?.registration_fee;
+        let amount = Self::get_fee_quote(env, token.clone(), FeeOperation::Registration)?;
         Self::execute_fee_payment(
             env,
             payer.clone(),
@@ -266,6 +620,86 @@ impl FeeManager {
         )
     }
 
+    /// Pay the review fee for `project_id` at the price of `token`. The
+    /// payment is held for `reviewer` until their next review or review
+    /// commitment on the project consumes it; only one can be pending.
+    pub fn pay_review_fee(
+        env: &Env,
+        reviewer: Address,
+        project_id: u64,
+        token: Option<Address>,
+    ) -> Result<(), ContractError> {
+        require_self_auth(&reviewer);
+        ProjectRegistry::get_project(env, project_id).ok_or(ContractError::ProjectNotFound)?;
+
+        let key = FeeKey::ReviewFeePayment(project_id, reviewer.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(ContractError::InvalidStatus);
+        }
+        let amount = Self::get_fee_quote(env, token.clone(), FeeOperation::Review)?;
+        if amount > 0 {
+            if amount > i128::MAX as u128 {
+                return Err(ContractError::InvalidProjectData);
+            }
+            let token_address = token.clone().ok_or(ContractError::FeeConfigNotSet)?;
+            Self::pay_out(
+                env,
+                &reviewer,
+                &token_address,
+                amount,
+                FeeOperation::Review,
+                project_id,
+            )?;
+            FeeRevenueLedger::record_collected(env, &token_address, FeeOperation::Review, amount);
+        }
+
+        let payment_record = FeePaymentRecord {
+            paid_at: env.ledger().timestamp(),
+            payer: reviewer.clone(),
+            amount,
+            token: token.clone(),
+        };
+        env.storage().persistent().set(&key, &payment_record);
+
+        publish_fee_paid_event(
+            env,
+            project_id,
+            reviewer,
+            token,
+            FeeOperation::Review,
+            amount,
+        );
+        Ok(())
+    }
+
+    /// A reviewer's unconsumed review fee payment for a project.
+    pub fn get_review_fee_payment(
+        env: &Env,
+        project_id: u64,
+        reviewer: &Address,
+    ) -> Option<FeePaymentRecord> {
+        env.storage()
+            .persistent()
+            .get(&FeeKey::ReviewFeePayment(project_id, reviewer.clone()))
+    }
+
+    /// Consume a reviewer's review fee payment, if any, once their review
+    /// or review commitment is stored.
+    pub fn consume_review_fee_payment(env: &Env, project_id: u64, reviewer: &Address) {
+        if let Some(payment) = Self::get_review_fee_payment(env, project_id, reviewer) {
+            env.storage()
+                .persistent()
+                .remove(&FeeKey::ReviewFeePayment(project_id, reviewer.clone()));
+            publish_fee_consumed_event(
+                env,
+                project_id,
+                reviewer.clone(),
+                FeeOperation::Review,
+                payment.amount,
+            );
+        }
+    }
+
     /// Get fee payment details for a project (payer, amount, token, timestamp)
     pub fn get_fee_payment_details(env: &Env, project_id: u64) -> Option<FeePaymentRecord> {
         env.storage()
@@ -273,6 +707,240 @@ impl FeeManager {
             .get(&ExtensionKey::FeePaymentDetails(project_id))
     }
 
+    /// Seconds a verification fee payment stays valid; 0 means payments do
+    /// not expire.
+    pub fn get_fee_payment_expiry(env: &Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&FeeKey::FeePaymentExpiry)
+            .unwrap_or(FEE_PAYMENT_EXPIRY_SECONDS)
+    }
+
+    /// Admin: set how long verification fee payments stay valid (0 disables
+    /// expiry).
+    pub fn set_fee_payment_expiry(
+        env: &Env,
+        admin: Address,
+        expiry_seconds: u64,
+    ) -> Result<(), ContractError> {
+        require_admin_auth(env, &admin)?;
+        env.storage()
+            .persistent()
+            .set(&FeeKey::FeePaymentExpiry, &expiry_seconds);
+        AdminActionLog::record_action(env, admin, AdminActionType::FeeChanged, None, None, None);
+        Ok(())
+    }
+
+    /// Fix when a project's verification fee payment expires under the
+    /// current expiry window.
+    fn renew_payment_expiry(env: &Env, project_id: u64) {
+        let expiry = Self::get_fee_payment_expiry(env);
+        let expires_at = if expiry == 0 {
+            0
+        } else {
+            env.ledger().timestamp().saturating_add(expiry)
+        };
+        env.storage()
+            .persistent()
+            .set(&FeeKey::FeePaymentExpiresAt(project_id), &expires_at);
+    }
+
+    /// Fee payment details for a project with their expiry status.
+    pub fn get_fee_payment_status(env: &Env, project_id: u64) -> Option<FeePaymentDetails> {
+        let record = Self::get_fee_payment_details(env, project_id)?;
+        let expires_at = env
+            .storage()
+            .persistent()
+            .get(&FeeKey::FeePaymentExpiresAt(project_id))
+            .unwrap_or(0);
+        let status = if !Self::is_fee_paid(env, project_id) {
+            FeePaymentStatus::Consumed
+        } else if expires_at != 0 && env.ledger().timestamp() > expires_at {
+            FeePaymentStatus::Expired
+        } else {
+            FeePaymentStatus::Valid
+        };
+        Some(FeePaymentDetails {
+            paid_at: record.paid_at,
+            payer: record.payer,
+            amount: record.amount,
+            token: record.token,
+            expires_at,
+            status,
+        })
+    }
+
+    /// Owner: bring an unconsumed verification fee payment to the current
+    /// verification fee of its token, or a tier payment to its tier's current
+    /// fee, paying or refunding the difference, and renew its validity
+    /// window. Outside escrow, a lower fee is rejected with `InvalidStatus`
+    /// and a higher one is paid to the treasury. Returns the new amount.
+    pub fn reprice_fee_payment(
+        env: &Env,
+        owner: Address,
+        project_id: u64,
+    ) -> Result<u128, ContractError> {
+        require_self_auth(&owner);
+        if !Self::is_fee_paid(env, project_id) {
+            return Err(ContractError::InsufficientFee);
+        }
+        let mut record =
+            Self::get_fee_payment_details(env, project_id).ok_or(ContractError::InsufficientFee)?;
+        if record.payer != owner {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let tier_id: Option<u32> = env
+            .storage()
+            .persistent()
+            .get(&FeeKey::FeePaymentTier(project_id));
+        let amount = match tier_id {
+            Some(tier_id) => VerificationTierRegistry::require_active_tier(env, tier_id)?.fee,
+            None => Self::get_fee_quote(env, record.token.clone(), FeeOperation::Verification)?,
+        };
+        let escrowed = FeeEscrowManager::get_escrow(env, project_id).is_some();
+        if !escrowed && amount < record.amount {
+            return Err(ContractError::InvalidStatus);
+        }
+        if amount != record.amount {
+            let token = record.token.clone().ok_or(ContractError::FeeConfigNotSet)?;
+            if escrowed {
+                FeeEscrowManager::adjust(env, project_id, amount);
+            } else {
+                // Payments made outside escrow are refunded by the treasury,
+                // so the top-up goes there rather than to split recipients.
+                soroban_sdk::token::Client::new(env, &token).transfer(
+                    &owner,
+                    &Self::get_treasury(env)?,
+                    &((amount - record.amount) as i128),
+                );
+            }
+            if amount > record.amount {
+                FeeRevenueLedger::record_collected(
+                    env,
+                    &token,
+                    FeeOperation::Verification,
+                    amount - record.amount,
+                );
+            } else {
+                FeeRevenueLedger::record_refunded(
+                    env,
+                    &token,
+                    FeeOperation::Verification,
+                    record.amount - amount,
+                );
+            }
+        }
+
+        publish_fee_repriced_event(
+            env,
+            project_id,
+            owner,
+            record.token.clone(),
+            record.amount,
+            amount,
+        );
+        record.amount = amount;
+        record.paid_at = env.ledger().timestamp();
+        env.storage()
+            .persistent()
+            .set(&ExtensionKey::FeePaymentDetails(project_id), &record);
+        Self::renew_payment_expiry(env, project_id);
+        Ok(amount)
+    }
+
+    /// Payer: reclaim an expired, unconsumed verification fee payment in
+    /// full. Only escrowed payments can be reclaimed this way; a payment made
+    /// outside escrow is refunded by the treasury, so it is rejected with
+    /// `FeeNotEscrowed` and has to be cancelled by an admin.
+    pub fn reclaim_fee_payment(
+        env: &Env,
+        payer: Address,
+        project_id: u64,
+    ) -> Result<(), ContractError> {
+        require_self_auth(&payer);
+        let details =
+            Self::get_fee_payment_status(env, project_id).ok_or(ContractError::InsufficientFee)?;
+        if details.payer != payer {
+            return Err(ContractError::Unauthorized);
+        }
+        if details.status != FeePaymentStatus::Expired {
+            return Err(ContractError::InvalidStatus);
+        }
+        if details.amount > 0 && FeeEscrowManager::get_escrow(env, project_id).is_none() {
+            return Err(ContractError::FeeNotEscrowed);
+        }
+
+        let record = FeePaymentRecord {
+            paid_at: details.paid_at,
+            payer: details.payer,
+            amount: details.amount,
+            token: details.token,
+        };
+        Self::refund_payment(env, project_id, &record)?;
+        publish_fee_payment_cleared_event(
+            env,
+            project_id,
+            record.payer,
+            record.paid_at,
+            env.ledger().timestamp(),
+        );
+        Ok(())
+    }
+
+    /// Refund an unconsumed verification fee payment in full, from escrow or
+    /// the treasury, and remove it.
+    fn refund_payment(
+        env: &Env,
+        project_id: u64,
+        record: &FeePaymentRecord,
+    ) -> Result<(), ContractError> {
+        // Process refund if fee amount > 0 and token is configured. Escrowed
+        // fees are refunded by the contract instead of the treasury.
+        if let Some(escrow) = FeeEscrowManager::take(env, project_id) {
+            soroban_sdk::token::Client::new(env, &escrow.token).transfer(
+                &env.current_contract_address(),
+                &escrow.payer,
+                &(escrow.amount as i128),
+            );
+            FeeRevenueLedger::record_refunded(
+                env,
+                &escrow.token,
+                FeeOperation::Verification,
+                escrow.amount,
+            );
+        } else if record.amount > 0 {
+            let token_address = record.token.clone().ok_or(ContractError::FeeConfigNotSet)?;
+            let treasury = Self::get_treasury(env)?;
+
+            // Treasury authorization is required to transfer tokens out of the treasury
+            treasury.require_auth();
+            let token_client = soroban_sdk::token::Client::new(env, &token_address);
+            token_client.transfer(&treasury, &record.payer, &(record.amount as i128));
+            FeeRevenueLedger::record_refunded(
+                env,
+                &token_address,
+                FeeOperation::Verification,
+                record.amount,
+            );
+        }
+
+        // Remove payment records from storage
+        env.storage()
+            .persistent()
+            .remove(&StorageKey::FeePaidForProject(project_id));
+        env.storage()
+            .persistent()
+            .remove(&ExtensionKey::FeePaymentDetails(project_id));
+        env.storage()
+            .persistent()
+            .remove(&FeeKey::FeePaymentExpiresAt(project_id));
+        env.storage()
+            .persistent()
+            .remove(&FeeKey::FeePaymentTier(project_id));
+        Ok(())
+    }
+
     /// Get registration fee payment details for an address
     pub fn get_registration_fee_payment_details(
         env: &Env,
@@ -342,24 +1010,7 @@ impl FeeManager {
             }
         }
 
-        // Process refund if fee amount > 0 and token is configured
-        if record.amount > 0 {
-            let token_address = record.token.clone().ok_or(ContractError::FeeConfigNotSet)?;
-            let treasury = Self::get_treasury(env)?;
-
-            // Treasury authorization is required to transfer tokens out of the treasury
-            treasury.require_auth();
-            let token_client = soroban_sdk::token::Client::new(env, &token_address);
-            token_client.transfer(&treasury, &record.payer, &(record.amount as i128));
-        }
-
-        // Remove payment records from storage
-        env.storage()
-            .persistent()
-            .remove(&StorageKey::FeePaidForProject(project_id));
-        env.storage()
-            .persistent()
-            .remove(&ExtensionKey::FeePaymentDetails(project_id));
+        Self::refund_payment(env, project_id, &record)?;
 
         // Publish event
         crate::events::publish_fee_cancelled_event(
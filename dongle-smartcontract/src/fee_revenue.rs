@@ -0,0 +1,161 @@
+//! Running fee revenue totals per token and fee type.
+//!
+//! Fees are counted as collected when they are paid, whether they go to the
+//! treasury or into escrow, and as refunded whenever any part goes back to
+//! the payer (cancellation, reclaim, re-pricing, withdrawal or an escrow
+//! refund). Each total is also kept in the report period it happened in.
+//! Verification fee payments consumed by a renewal request are moved from
+//! `Verification` to `Renewal` in the period they were paid in.
+
+use crate::constants::FEE_REVENUE_PERIOD_SECONDS;
+use crate::events::FeeOperation;
+use crate::storage_keys::FeeKey;
+use crate::types::{FeeRevenueEntry, FeeRevenueReport, FeeRevenueTotals};
+use soroban_sdk::{vec, Address, Env, Vec};
+
+pub struct FeeRevenueLedger;
+
+impl FeeRevenueLedger {
+    fn period_start(timestamp: u64) -> u64 {
+        timestamp - timestamp % FEE_REVENUE_PERIOD_SECONDS
+    }
+
+    fn get_totals(env: &Env, key: &FeeKey) -> FeeRevenueTotals {
+        env.storage().persistent().get(key).unwrap_or_default()
+    }
+
+    /// Apply `update` to the all-time totals and to the totals of the period
+    /// containing `timestamp`.
+    fn update(
+        env: &Env,
+        token: &Address,
+        operation: FeeOperation,
+        timestamp: u64,
+        update: impl Fn(&mut FeeRevenueTotals),
+    ) {
+        let period = Self::period_start(timestamp);
+        for key in [
+            FeeKey::RevenueTotal(token.clone(), operation.clone()),
+            FeeKey::RevenuePeriod(token.clone(), operation.clone(), period),
+        ] {
+            let mut totals = Self::get_totals(env, &key);
+            update(&mut totals);
+            env.storage().persistent().set(&key, &totals);
+        }
+    }
+
+    /// Count a fee paid in `token` for `operation`.
+    pub(crate) fn record_collected(
+        env: &Env,
+        token: &Address,
+        operation: FeeOperation,
+        amount: u128,
+    ) {
+        if amount == 0 {
+            return;
+        }
+        let mut tokens: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&FeeKey::RevenueTokens)
+            .unwrap_or_else(|| Vec::new(env));
+        if !tokens.contains(token) {
+            tokens.push_back(token.clone());
+            env.storage()
+                .persistent()
+                .set(&FeeKey::RevenueTokens, &tokens);
+        }
+        Self::update(env, token, operation, env.ledger().timestamp(), |totals| {
+            totals.collected = totals.collected.saturating_add(amount);
+        });
+    }
+
+    /// Count a fee amount refunded to its payer.
+    pub(crate) fn record_refunded(
+        env: &Env,
+        token: &Address,
+        operation: FeeOperation,
+        amount: u128,
+    ) {
+        if amount == 0 {
+            return;
+        }
+        Self::update(env, token, operation, env.ledger().timestamp(), |totals| {
+            totals.refunded = totals.refunded.saturating_add(amount);
+        });
+    }
+
+    /// Move a fee collected at `paid_at` from one fee type to another.
+    pub(crate) fn reattribute(
+        env: &Env,
+        token: &Address,
+        from: FeeOperation,
+        to: FeeOperation,
+        amount: u128,
+        paid_at: u64,
+    ) {
+        if amount == 0 {
+            return;
+        }
+        Self::update(env, token, from, paid_at, |totals| {
+            totals.collected = totals.collected.saturating_sub(amount);
+        });
+        Self::update(env, token, to, paid_at, |totals| {
+            totals.collected = totals.collected.saturating_add(amount);
+        });
+    }
+
+    /// All-time fee revenue, or the revenue of the period containing
+    /// `period`. Only token and fee type pairs with activity are listed.
+    pub fn get_report(env: &Env, period: Option<u64>) -> FeeRevenueReport {
+        let (period_start, period_end) = match period {
+            Some(timestamp) => {
+                let start = Self::period_start(timestamp);
+                (start, start.saturating_add(FEE_REVENUE_PERIOD_SECONDS))
+            }
+            None => (0, 0),
+        };
+        let tokens: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&FeeKey::RevenueTokens)
+            .unwrap_or_else(|| Vec::new(env));
+        let operations = vec![
+            env,
+            FeeOperation::Verification,
+            FeeOperation::Registration,
+            FeeOperation::Review,
+            FeeOperation::Renewal,
+        ];
+
+        let mut entries = Vec::new(env);
+        for token in tokens.iter() {
+            for operation in operations.iter() {
+                let key = match period {
+                    Some(_) => {
+                        FeeKey::RevenuePeriod(token.clone(), operation.clone(), period_start)
+                    }
+                    None => FeeKey::RevenueTotal(token.clone(), operation.clone()),
+                };
+                let totals = Self::get_totals(env, &key);
+                if totals == FeeRevenueTotals::default() {
+                    continue;
+                }
+                entries.push_back(FeeRevenueEntry {
+                    token: token.clone(),
+                    operation,
+                    collected: totals.collected,
+                    refunded: totals.refunded,
+                    net: i128::try_from(totals.collected)
+                        .unwrap_or(i128::MAX)
+                        .saturating_sub(i128::try_from(totals.refunded).unwrap_or(i128::MAX)),
+                });
+            }
+        }
+        FeeRevenueReport {
+            period_start,
+            period_end,
+            entries,
+        }
+    }
+}
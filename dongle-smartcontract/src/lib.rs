@@ -4,10 +4,12 @@
 mod admin_action_log;
 mod admin_manager;
 pub mod auth;
+mod blocklist;
 mod bookmark_registry;
 mod changelog_registry;
 mod collection_registry;
 mod config_registry;
+mod conflict_registry;
 pub mod constants;
 mod dependency_registry;
 mod dispute_registry;
@@ -16,14 +18,18 @@ mod endorsement_registry;
 pub mod errors;
 pub mod events;
 mod featured_registry;
+mod fee_escrow;
 mod fee_manager;
+mod fee_revenue;
 pub mod pagination;
 mod project_registry;
 pub mod rating_calculator;
 mod report_registry;
+mod reputation_registry;
 pub mod review_registry;
 pub mod storage_keys;
 pub mod storage_manager;
+mod storage_migration;
 mod subscription_registry;
 mod timelock_manager;
 pub mod types;
@@ -35,29 +41,47 @@ mod tests;
 
 use crate::admin_action_log::AdminActionLog;
 use crate::admin_manager::AdminManager;
+use crate::blocklist::Blocklist;
 use crate::changelog_registry::ChangelogRegistry;
 use crate::collection_registry::CollectionRegistry;
 use crate::config_registry::ConfigRegistry;
+use crate::conflict_registry::ConflictRegistry;
 use crate::emergency_pause::EmergencyPause;
 use crate::errors::ContractError;
+use crate::events::FeeOperation;
 use crate::featured_registry::FeaturedRegistry;
+use crate::fee_escrow::FeeEscrowManager;
 use crate::fee_manager::FeeManager;
+use crate::fee_revenue::FeeRevenueLedger;
 use crate::project_registry::ProjectRegistry;
 use crate::report_registry::ReportRegistry;
-use crate::review_registry::ReviewRegistry;
+use crate::reputation_registry::ReputationRegistry;
+use crate::review_registry::{ReviewRegistry, ReviewRoundRegistry};
 use crate::storage_keys::ExtensionKey;
 use crate::storage_manager::StorageManager;
 use crate::timelock_manager::TimelockManager;
 use crate::types::{
-    AdminActionEntry, AdminProposal, ChangelogEntry, ChangelogSortMode, ClaimRequest, ClaimStatus,
-    Collection, ContractClaimRequest, ContractConfigView, DependencyRef, DisputeResolutionAction,
-    DisputeStatus, DuplicateDispute, FeeConfig, FeePaymentRecord, Project, ProjectDependency,
-    ProjectRegistrationParams, ProjectReport, ProjectSortMode, ProjectStats, ProjectUpdateParams,
-    ProposalPayload, Review, ReviewRevision, ReviewSortMode, ReviewTombstone,
-    SecurityContactStatus, TimelockAction, VerificationRecord, VerificationStatus,
+    AdminActionEntry, AdminProposal, AttestationPayload, Attestor, BlocklistEntry, ChangelogEntry,
+    ChangelogSortMode, ChecklistItem, ChecklistScope, ClaimRequest, ClaimStatus, Collection,
+    ContractClaimRequest, ContractConfigView, DependencyRef, DisputeResolutionAction,
+    DisputeStatus, DuplicateDispute, EscrowTokenTotal, EscrowedFee, EvidenceCommitment,
+    EvidenceSummary, ExpiringVerificationPage, ExpiryCursor, FeeConfig, FeeEscrowConfig,
+    FeePaymentDetails, FeePaymentRecord, FeeRevenueReport, HistoryRetentionPolicy, HistorySummary,
+    PendingVerification, Project, ProjectAttestation, ProjectDependency, ProjectRegistrationParams,
+    ProjectReport, ProjectSortMode, ProjectStats, ProjectUpdateParams, ProposalPayload,
+    RenewalHistoryPage, Review, ReviewInteractionProof, ReviewPolicy, ReviewRevision, ReviewRound,
+    ReviewSortMode, ReviewTombstone, ReviewerReputation, ReviewerReviewPage, SecurityContactStatus,
+    TimelockAction, TokenFees, TreasurySplit, VerificationAssignmentMode, VerificationHistoryPage,
+    VerificationRecord, VerificationReport, VerificationStatus, VerificationSuspension,
+    VerificationSweepResult, VerificationTier, VerificationTierGrant, VerificationTierParams,
+    VerifierGrant,
 };
-use crate::verification_registry::VerificationRegistry;
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use crate::verification_registry::{
+    AttestorRegistry, EvidenceRegistry, VerificationChecklistRegistry, VerificationExpiryIndex,
+    VerificationHistoryRegistry, VerificationQueue, VerificationRegistry, VerificationTierRegistry,
+    VerifierRegistry,
+};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String, Symbol, Vec};
 
 #[contract]
 pub struct DongleContract;
@@ -167,6 +191,37 @@ impl DongleContract {
         EmergencyPause::is_paused(&env)
     }
 
+    // --- Blocklist ---
+
+    /// Admin: block an address, optionally until `expires_at` (ledger timestamp).
+    pub fn block_address(
+        env: Env,
+        admin: Address,
+        address: Address,
+        reason_cid: String,
+        expires_at: Option<u64>,
+    ) -> Result<(), ContractError> {
+        Blocklist::block_address(&env, admin, address, reason_cid, expires_at)
+    }
+
+    /// Admin: remove an address from the blocklist.
+    pub fn unblock_address(
+        env: Env,
+        admin: Address,
+        address: Address,
+    ) -> Result<(), ContractError> {
+        Blocklist::unblock_address(&env, admin, address)
+    }
+
+    /// Returns true if the address is blocked and the block has not expired.
+    pub fn is_address_blocked(env: Env, address: Address) -> bool {
+        Blocklist::is_blocked(&env, &address)
+    }
+
+    pub fn get_blocklist_entry(env: Env, address: Address) -> Option<BlocklistEntry> {
+        Blocklist::get_entry(&env, &address)
+    }
+
     // --- Project Registry ---
 
     pub fn register_project(
@@ -320,7 +375,17 @@ impl DongleContract {
         start_id: u64,
         limit: u32,
     ) -> Vec<Project> {
-        ProjectRegistry::list_projects_by_status(&env, status, start_id, limit)
+        ProjectRegistry::list_projects_by_status(&env, status, None, start_id, limit)
+    }
+
+    pub fn list_projects_by_status_and_tier(
+        env: Env,
+        status: VerificationStatus,
+        tier_id: u32,
+        start_id: u64,
+        limit: u32,
+    ) -> Vec<Project> {
+        ProjectRegistry::list_projects_by_status(&env, status, Some(tier_id), start_id, limit)
     }
 
     pub fn list_projects_by_category(
@@ -462,6 +527,100 @@ impl DongleContract {
         ReviewRegistry::delete_review(&env, project_id, reviewer)
     }
 
+    /// Set the global review edit policy (`project_id = None`) or a project override.
+    pub fn set_review_policy(
+        env: Env,
+        admin: Address,
+        project_id: Option<u64>,
+        policy: ReviewPolicy,
+    ) -> Result<(), ContractError> {
+        ReviewRegistry::set_review_policy(&env, admin, project_id, policy)
+    }
+
+    pub fn clear_project_review_policy(
+        env: Env,
+        admin: Address,
+        project_id: u64,
+    ) -> Result<(), ContractError> {
+        ReviewRegistry::clear_project_review_policy(&env, admin, project_id)
+    }
+
+    /// Review edit policy: the global one for `None`, else the one in effect for the project.
+    pub fn get_review_policy(env: Env, project_id: Option<u64>) -> ReviewPolicy {
+        match project_id {
+            Some(id) => ReviewRegistry::get_review_policy(&env, id),
+            None => ReviewRegistry::get_global_review_policy(&env),
+        }
+    }
+
+    pub fn start_review_round(
+        env: Env,
+        project_id: u64,
+        owner: Address,
+        commit_duration: u64,
+        reveal_duration: u64,
+    ) -> Result<ReviewRound, ContractError> {
+        ReviewRoundRegistry::start_review_round(
+            &env,
+            project_id,
+            owner,
+            commit_duration,
+            reveal_duration,
+        )
+    }
+
+    pub fn commit_review(
+        env: Env,
+        project_id: u64,
+        reviewer: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        ReviewRoundRegistry::commit_review(&env, project_id, reviewer, commitment)
+    }
+
+    pub fn reveal_review(
+        env: Env,
+        project_id: u64,
+        reviewer: Address,
+        rating: u32,
+        comment_cid: Option<String>,
+        salt: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        ReviewRoundRegistry::reveal_review(&env, project_id, reviewer, rating, comment_cid, salt)
+    }
+
+    pub fn finalize_review_round(
+        env: Env,
+        project_id: u64,
+        limit: u32,
+    ) -> Result<u32, ContractError> {
+        ReviewRoundRegistry::finalize_review_round(&env, project_id, limit)
+    }
+
+    pub fn get_review_round(env: Env, project_id: u64) -> Option<ReviewRound> {
+        ReviewRoundRegistry::get_round(&env, project_id)
+    }
+
+    pub fn compute_review_commitment(
+        env: Env,
+        project_id: u64,
+        round_id: u32,
+        reviewer: Address,
+        rating: u32,
+        comment_cid: Option<String>,
+        salt: BytesN<32>,
+    ) -> Result<BytesN<32>, ContractError> {
+        ReviewRoundRegistry::compute_commitment(
+            &env,
+            project_id,
+            round_id,
+            &reviewer,
+            rating,
+            &comment_cid,
+            &salt,
+        )
+    }
+
     pub fn submit_review(
         env: Env,
         project_id: u64,
@@ -515,6 +674,73 @@ impl DongleContract {
         ReviewRegistry::get_weighted_rating(&env, project_id)
     }
 
+    /// Reputation-weighted average rating (scaled by 100). Each review counts
+    /// with its reviewer's reputation score at submission time.
+    pub fn get_reputation_weighted_rating(env: Env, project_id: u64) -> u32 {
+        ReputationRegistry::get_reputation_weighted_rating(&env, project_id)
+    }
+
+    /// Vote a review as helpful. One vote per address per review; feeds the
+    /// reviewer's reputation score.
+    pub fn vote_review_helpful(
+        env: Env,
+        project_id: u64,
+        reviewer: Address,
+        voter: Address,
+    ) -> Result<(), ContractError> {
+        ReputationRegistry::vote_review_helpful(&env, project_id, reviewer, voter)
+    }
+
+    pub fn has_voted_helpful(env: Env, project_id: u64, reviewer: Address, voter: Address) -> bool {
+        ReputationRegistry::has_voted_helpful(&env, project_id, reviewer, voter)
+    }
+
+    /// Current reputation snapshot for a reviewer (score scaled by 100).
+    pub fn get_reviewer_reputation(env: Env, reviewer: Address) -> ReviewerReputation {
+        ReputationRegistry::get_reviewer_reputation(&env, reviewer)
+    }
+
+    /// Attach a proof of interaction to a review. Requires auth from both the
+    /// reviewer and the attestor (a verified project contract or trusted attestor).
+    pub fn attach_interaction_proof(
+        env: Env,
+        project_id: u64,
+        reviewer: Address,
+        attestor: Address,
+    ) -> Result<(), ContractError> {
+        ReviewRegistry::attach_interaction_proof(&env, project_id, reviewer, attestor)
+    }
+
+    pub fn get_interaction_proof(
+        env: Env,
+        project_id: u64,
+        reviewer: Address,
+    ) -> Option<ReviewInteractionProof> {
+        ReviewRegistry::get_interaction_proof(&env, project_id, reviewer)
+    }
+
+    /// Admin: trust an address to attest reviewer interactions for any project.
+    pub fn add_trusted_attestor(
+        env: Env,
+        admin: Address,
+        attestor: Address,
+    ) -> Result<(), ContractError> {
+        ReviewRegistry::add_trusted_attestor(&env, admin, attestor)
+    }
+
+    /// Admin: stop trusting an attestor. Existing proofs are kept.
+    pub fn remove_trusted_attestor(
+        env: Env,
+        admin: Address,
+        attestor: Address,
+    ) -> Result<(), ContractError> {
+        ReviewRegistry::remove_trusted_attestor(&env, admin, attestor)
+    }
+
+    pub fn get_trusted_attestors(env: Env) -> Vec<Address> {
+        ReviewRegistry::get_trusted_attestors(&env)
+    }
+
     pub fn get_review_revision_count(env: Env, project_id: u64, reviewer: Address) -> u32 {
         ReviewRegistry::get_review_revision_count(&env, project_id, reviewer)
     }
@@ -564,6 +790,18 @@ impl DongleContract {
         ReviewRegistry::hide_review(&env, project_id, reviewer, admin)
     }
 
+    /// Admin: hide up to `limit` reviews by a blocked address, starting at
+    /// `start_index` of the reviewer's review index. Returns the number hidden.
+    pub fn hide_reviews_by_reviewer(
+        env: Env,
+        admin: Address,
+        reviewer: Address,
+        start_index: u32,
+        limit: u32,
+    ) -> Result<u32, ContractError> {
+        ReviewRegistry::hide_reviews_by_reviewer(&env, admin, reviewer, start_index, limit)
+    }
+
     pub fn restore_review(
         env: Env,
         project_id: u64,
@@ -592,8 +830,19 @@ impl DongleContract {
         ReviewRegistry::get_review_tombstone(&env, project_id, reviewer)
     }
 
+    /// Page through a reviewer's full record, including hidden and deleted reviews.
+    pub fn list_reviews_by_reviewer(
+        env: Env,
+        reviewer: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> ReviewerReviewPage {
+        ReviewRegistry::list_reviews_by_reviewer(&env, reviewer, cursor, limit)
+    }
+
     /// List reviews sorted by the given sort mode with pagination.
-    /// Sorting is performed on-chain in-memory; compute cost scales with review count.
+    /// Sorting is performed on-chain in-memory over the first
+    /// `MAX_REVIEW_SCAN_PAGES` pages of the project's review index.
     pub fn list_reviews_sorted(
         env: Env,
         project_id: u64,
@@ -601,7 +850,19 @@ impl DongleContract {
         limit: u32,
         sort_mode: ReviewSortMode,
     ) -> Vec<Review> {
-        ReviewRegistry::list_reviews_sorted(&env, project_id, start_index, limit, sort_mode)
+        ReviewRegistry::list_reviews_sorted(&env, project_id, start_index, limit, sort_mode, false)
+    }
+
+    /// Same as `list_reviews_sorted`, but only reviews with a
+    /// verified-interaction badge.
+    pub fn list_verified_reviews_sorted(
+        env: Env,
+        project_id: u64,
+        start_index: u32,
+        limit: u32,
+        sort_mode: ReviewSortMode,
+    ) -> Vec<Review> {
+        ReviewRegistry::list_reviews_sorted(&env, project_id, start_index, limit, sort_mode, true)
     }
 
     // --- Verification Registry ---
@@ -651,6 +912,29 @@ impl DongleContract {
         VerificationRegistry::approve_verification(&env, project_id, admin)
     }
 
+    /// Sign off on a pending verification request with optional notes. The
+    /// request is approved once the verification quorum is reached.
+    pub fn attest_verification(
+        env: Env,
+        project_id: u64,
+        verifier: Address,
+        notes_cid: Option<String>,
+    ) -> Result<(), ContractError> {
+        VerificationRegistry::attest_verification(&env, project_id, verifier, notes_cid)
+    }
+
+    pub fn set_verification_quorum(
+        env: Env,
+        admin: Address,
+        quorum: u32,
+    ) -> Result<(), ContractError> {
+        VerificationRegistry::set_verification_quorum(&env, admin, quorum)
+    }
+
+    pub fn get_verification_quorum(env: Env) -> u32 {
+        VerificationRegistry::get_verification_quorum(&env)
+    }
+
     pub fn reject_verification(
         env: Env,
         project_id: u64,
@@ -668,6 +952,36 @@ impl DongleContract {
         VerificationRegistry::revoke_verification(&env, project_id, admin, reason)
     }
 
+    /// Admin: suspend a verified project during an incident. `reason_cid`
+    /// points at the incident notice; a nonzero `resume_at` ends the
+    /// suspension automatically at that time. `expires_at` is unchanged.
+    pub fn suspend_verification(
+        env: Env,
+        project_id: u64,
+        admin: Address,
+        reason_cid: String,
+        resume_at: u64,
+    ) -> Result<(), ContractError> {
+        VerificationRegistry::suspend_verification(&env, project_id, admin, reason_cid, resume_at)
+    }
+
+    /// Return a suspended project to Verified. Admins can resume at any
+    /// time; anyone can once the suspension's `resume_at` has passed.
+    pub fn resume_verification(
+        env: Env,
+        project_id: u64,
+        caller: Address,
+    ) -> Result<(), ContractError> {
+        VerificationRegistry::resume_verification(&env, project_id, caller)
+    }
+
+    pub fn get_verification_suspension(
+        env: Env,
+        project_id: u64,
+    ) -> Option<VerificationSuspension> {
+        VerificationRegistry::get_verification_suspension(&env, project_id)
+    }
+
     pub fn get_verification(env: Env, project_id: u64) -> Option<VerificationRecord> {
         VerificationRegistry::get_verification(&env, project_id)
     }
@@ -684,6 +998,16 @@ impl DongleContract {
         VerificationRegistry::is_verification_active(&env, project_id)
     }
 
+    /// Move expired verifications to Unverified, checking up to `limit`
+    /// projects from `cursor`. Call again with `next_cursor` until it is `None`.
+    pub fn sweep_expired_verifications(
+        env: Env,
+        cursor: u64,
+        limit: u32,
+    ) -> VerificationSweepResult {
+        VerificationRegistry::sweep_expired_verifications(&env, cursor, limit)
+    }
+
     pub fn renew_verification(
         env: Env,
         project_id: u64,
@@ -713,6 +1037,83 @@ impl DongleContract {
         VerificationRegistry::reject_renewal(&env, project_id, admin)
     }
 
+    // --- Verification Tiers ---
+
+    pub fn create_verification_tier(
+        env: Env,
+        admin: Address,
+        params: VerificationTierParams,
+    ) -> Result<u32, ContractError> {
+        VerificationTierRegistry::create_tier(&env, admin, params)
+    }
+
+    pub fn update_verification_tier(
+        env: Env,
+        admin: Address,
+        tier_id: u32,
+        params: VerificationTierParams,
+    ) -> Result<(), ContractError> {
+        VerificationTierRegistry::update_tier(&env, admin, tier_id, params)
+    }
+
+    pub fn set_verification_tier_active(
+        env: Env,
+        admin: Address,
+        tier_id: u32,
+        active: bool,
+    ) -> Result<(), ContractError> {
+        VerificationTierRegistry::set_tier_active(&env, admin, tier_id, active)
+    }
+
+    pub fn get_verification_tier(env: Env, tier_id: u32) -> Option<VerificationTier> {
+        VerificationTierRegistry::get_tier(&env, tier_id)
+    }
+
+    pub fn list_verification_tiers(env: Env) -> Vec<VerificationTier> {
+        VerificationTierRegistry::list_tiers(&env)
+    }
+
+    /// The highest-level active tier the project currently holds, if any.
+    pub fn get_project_verification_tier(env: Env, project_id: u64) -> Option<VerificationTier> {
+        VerificationTierRegistry::get_project_tier(&env, project_id)
+    }
+
+    pub fn get_project_tier_grants(env: Env, project_id: u64) -> Vec<VerificationTierGrant> {
+        VerificationTierRegistry::get_grants(&env, project_id)
+    }
+
+    pub fn request_tier_verification(
+        env: Env,
+        project_id: u64,
+        requester: Address,
+        tier_id: u32,
+        evidence_cid: String,
+    ) -> Result<(), ContractError> {
+        VerificationRegistry::request_tier_verification(
+            &env,
+            project_id,
+            requester,
+            tier_id,
+            evidence_cid,
+        )
+    }
+
+    pub fn request_tier_renewal(
+        env: Env,
+        project_id: u64,
+        requester: Address,
+        tier_id: u32,
+        evidence_cid: String,
+    ) -> Result<(), ContractError> {
+        VerificationRegistry::request_tier_renewal(
+            &env,
+            project_id,
+            requester,
+            tier_id,
+            evidence_cid,
+        )
+    }
+
     pub fn get_renewal_request(
         env: Env,
         project_id: u64,
@@ -764,6 +1165,54 @@ impl DongleContract {
         VerificationRegistry::clear_renewal_history(&env, project_id, &admin)
     }
 
+    // --- Verification History ---
+
+    /// A page of the project's retained verification records, in request
+    /// order, starting at request ID `cursor` (0 = oldest).
+    pub fn list_verification_history(
+        env: Env,
+        project_id: u64,
+        cursor: u64,
+        limit: u32,
+    ) -> VerificationHistoryPage {
+        VerificationHistoryRegistry::list_verification_history(&env, project_id, cursor, limit)
+    }
+
+    /// A page of the project's retained renewal records, in approval order,
+    /// starting at history index `cursor`.
+    pub fn list_renewal_history(
+        env: Env,
+        project_id: u64,
+        cursor: u32,
+        limit: u32,
+    ) -> RenewalHistoryPage {
+        VerificationHistoryRegistry::list_renewal_history(&env, project_id, cursor, limit)
+    }
+
+    /// Admin: set the retention policy applied to verification and renewal
+    /// history on each new request.
+    pub fn set_history_retention_policy(
+        env: Env,
+        admin: Address,
+        policy: HistoryRetentionPolicy,
+    ) -> Result<(), ContractError> {
+        VerificationHistoryRegistry::set_retention_policy(&env, admin, policy)
+    }
+
+    pub fn get_history_retention_policy(env: Env) -> HistoryRetentionPolicy {
+        VerificationHistoryRegistry::get_retention_policy(&env)
+    }
+
+    /// Hash chain over the project's pruned verification records.
+    pub fn get_verification_history_summary(env: Env, project_id: u64) -> Option<HistorySummary> {
+        VerificationHistoryRegistry::get_verification_summary(&env, project_id)
+    }
+
+    /// Hash chain over the project's pruned renewal records.
+    pub fn get_renewal_history_summary(env: Env, project_id: u64) -> Option<HistorySummary> {
+        VerificationHistoryRegistry::get_renewal_summary(&env, project_id)
+    }
+
     // --- Verification Assignment ---
 
     /// Admin: assign a pending verification to a specific admin for review.
@@ -781,6 +1230,320 @@ impl DongleContract {
         VerificationRegistry::get_assigned_admin(&env, project_id)
     }
 
+    // --- Delegated Verifiers ---
+
+    /// Admin: grant or update the verifier role. Empty `categories` or
+    /// `tier_ids` mean no restriction; `expires_at` 0 means no expiry.
+    pub fn grant_verifier(
+        env: Env,
+        admin: Address,
+        verifier: Address,
+        categories: Vec<String>,
+        tier_ids: Vec<u32>,
+        expires_at: u64,
+    ) -> Result<(), ContractError> {
+        VerifierRegistry::grant_verifier(&env, admin, verifier, categories, tier_ids, expires_at)
+    }
+
+    pub fn revoke_verifier(
+        env: Env,
+        admin: Address,
+        verifier: Address,
+    ) -> Result<(), ContractError> {
+        VerifierRegistry::revoke_verifier(&env, admin, verifier)
+    }
+
+    pub fn get_verifier(env: Env, verifier: Address) -> Option<VerifierGrant> {
+        VerifierRegistry::get_verifier(&env, &verifier)
+    }
+
+    pub fn list_verifiers(env: Env) -> Vec<Address> {
+        VerifierRegistry::list_verifiers(&env)
+    }
+
+    /// Admin or in-scope verifier: attach a report to the project's current
+    /// verification request.
+    pub fn attach_verification_report(
+        env: Env,
+        project_id: u64,
+        author: Address,
+        report_cid: String,
+    ) -> Result<(), ContractError> {
+        VerifierRegistry::attach_report(&env, project_id, author, report_cid)
+    }
+
+    pub fn get_verification_reports(env: Env, request_id: u64) -> Vec<VerificationReport> {
+        VerifierRegistry::get_reports(&env, request_id)
+    }
+
+    // --- Conflicts of Interest ---
+
+    /// Admin or verifier: recuse `actor` from approvals, claims, disputes and
+    /// moderation for the project. Recusals cannot be withdrawn.
+    pub fn recuse_from_project(
+        env: Env,
+        project_id: u64,
+        actor: Address,
+    ) -> Result<(), ContractError> {
+        ConflictRegistry::recuse(&env, project_id, actor)
+    }
+
+    pub fn get_project_recusals(env: Env, project_id: u64) -> Vec<Address> {
+        ConflictRegistry::get_recusals(&env, project_id)
+    }
+
+    /// Whether `actor` owns, maintains, has endorsed or is recused from the
+    /// project, and so may not decide on it.
+    pub fn has_conflict_of_interest(env: Env, project_id: u64, actor: Address) -> bool {
+        ConflictRegistry::has_conflict(&env, project_id, &actor)
+    }
+
+    // --- Verification Checklists ---
+
+    /// Admin: replace the checklist for a project category or verification
+    /// tier. An empty list removes it.
+    pub fn set_verification_checklist(
+        env: Env,
+        admin: Address,
+        scope: ChecklistScope,
+        items: Vec<ChecklistItem>,
+    ) -> Result<(), ContractError> {
+        VerificationChecklistRegistry::set_checklist(&env, admin, scope, items)
+    }
+
+    pub fn get_verification_checklist(env: Env, scope: ChecklistScope) -> Vec<ChecklistItem> {
+        VerificationChecklistRegistry::get_checklist(&env, scope)
+    }
+
+    /// The checklist that applies to the project's current verification
+    /// request; ticks are in the record's `checklist` field.
+    pub fn get_project_checklist(env: Env, project_id: u64) -> Vec<ChecklistItem> {
+        VerificationChecklistRegistry::get_project_checklist(&env, project_id)
+    }
+
+    /// Admin or in-scope verifier: tick a checklist item on the project's
+    /// pending request, with an optional note CID.
+    pub fn attest_checklist_item(
+        env: Env,
+        project_id: u64,
+        verifier: Address,
+        item_key: Symbol,
+        note_cid: Option<String>,
+    ) -> Result<(), ContractError> {
+        VerificationChecklistRegistry::attest_item(&env, project_id, verifier, item_key, note_cid)
+    }
+
+    // --- Off-chain Attestations ---
+
+    /// Admin: trust the attestation service with ed25519 `public_key`,
+    /// optionally only for `claim_types` (empty = any claim type).
+    pub fn register_attestor(
+        env: Env,
+        admin: Address,
+        public_key: BytesN<32>,
+        name: String,
+        claim_types: Vec<Symbol>,
+    ) -> Result<(), ContractError> {
+        AttestorRegistry::register_attestor(&env, admin, public_key, name, claim_types)
+    }
+
+    pub fn revoke_attestor(
+        env: Env,
+        admin: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        AttestorRegistry::revoke_attestor(&env, admin, public_key)
+    }
+
+    pub fn get_attestor(env: Env, public_key: BytesN<32>) -> Option<Attestor> {
+        AttestorRegistry::get_attestor(&env, &public_key)
+    }
+
+    pub fn list_attestors(env: Env) -> Vec<BytesN<32>> {
+        AttestorRegistry::list_attestors(&env)
+    }
+
+    /// The bytes a registered attestor must sign for `submit_attestation`.
+    pub fn get_attestation_message(
+        env: Env,
+        project_id: u64,
+        claim_type: Symbol,
+        payload: AttestationPayload,
+    ) -> Bytes {
+        AttestorRegistry::attestation_message(&env, project_id, claim_type, payload)
+    }
+
+    /// Store an attestation signed off-chain by a registered attestor. The
+    /// signature is checked with ed25519; each nonce is accepted once and
+    /// only before the payload's `expires_at`.
+    pub fn submit_attestation(
+        env: Env,
+        project_id: u64,
+        claim_type: Symbol,
+        payload: AttestationPayload,
+        signature: BytesN<64>,
+    ) -> Result<(), ContractError> {
+        AttestorRegistry::submit_attestation(&env, project_id, claim_type, payload, signature)
+    }
+
+    pub fn get_project_attestations(env: Env, project_id: u64) -> Vec<ProjectAttestation> {
+        AttestorRegistry::get_project_attestations(&env, project_id)
+    }
+
+    /// Whether the project holds an attestation of `claim_type` from a
+    /// currently registered attestor.
+    pub fn has_attestation(env: Env, project_id: u64, claim_type: Symbol) -> bool {
+        AttestorRegistry::has_attestation(&env, project_id, claim_type)
+    }
+
+    // --- Evidence Commitments ---
+
+    /// Owner: commit the SHA-256 of the canonical evidence document and its
+    /// summary fields for the pending request's evidence CID.
+    pub fn commit_verification_evidence(
+        env: Env,
+        project_id: u64,
+        requester: Address,
+        evidence_cid: String,
+        evidence_sha256: BytesN<32>,
+        summary: EvidenceSummary,
+    ) -> Result<(), ContractError> {
+        EvidenceRegistry::commit_evidence(
+            &env,
+            project_id,
+            requester,
+            evidence_cid,
+            evidence_sha256,
+            summary,
+        )
+    }
+
+    pub fn get_evidence_commitment(env: Env, request_id: u64) -> Option<EvidenceCommitment> {
+        EvidenceRegistry::get_commitment(&env, request_id)
+    }
+
+    /// Whether `evidence_sha256` matches the commitment for the project's
+    /// current verification request.
+    pub fn verify_evidence_hash(env: Env, project_id: u64, evidence_sha256: BytesN<32>) -> bool {
+        EvidenceRegistry::verify_evidence_hash(&env, project_id, evidence_sha256)
+    }
+
+    /// Owner: withdraw a pending request that has not been assigned or
+    /// signed off yet. Returns the refunded amount; a nonzero refund is paid
+    /// from the treasury and needs its authorization.
+    pub fn withdraw_verification_request(
+        env: Env,
+        project_id: u64,
+        owner: Address,
+    ) -> Result<u128, ContractError> {
+        VerificationRegistry::withdraw_verification_request(&env, project_id, owner)
+    }
+
+    /// Admin: set the share of the fee, in basis points, refunded on withdrawal.
+    pub fn set_withdrawal_refund_bps(
+        env: Env,
+        admin: Address,
+        refund_bps: u32,
+    ) -> Result<(), ContractError> {
+        VerificationRegistry::set_withdrawal_refund_bps(&env, admin, refund_bps)
+    }
+
+    pub fn get_withdrawal_refund_bps(env: Env) -> u32 {
+        VerificationRegistry::get_withdrawal_refund_bps(&env)
+    }
+
+    // --- Verification Queue ---
+
+    /// Pending verification requests, oldest first. Pass `assignee` to list
+    /// only the requests assigned to that admin or verifier.
+    pub fn list_pending_verifications(
+        env: Env,
+        assignee: Option<Address>,
+        start: u32,
+        limit: u32,
+    ) -> Vec<PendingVerification> {
+        VerificationQueue::list_pending(&env, assignee, start, limit)
+    }
+
+    /// 1-based position of the project's pending request in the queue.
+    pub fn get_verification_queue_position(env: Env, project_id: u64) -> Option<u32> {
+        VerificationQueue::get_queue_position(&env, project_id)
+    }
+
+    /// Number of pending requests assigned to `assignee`.
+    pub fn get_verifier_load(env: Env, assignee: Address) -> u32 {
+        VerificationQueue::get_load(&env, &assignee)
+    }
+
+    /// Admin: set how new requests are assigned to delegated verifiers.
+    pub fn set_verification_assignment_mode(
+        env: Env,
+        admin: Address,
+        mode: VerificationAssignmentMode,
+    ) -> Result<(), ContractError> {
+        VerificationQueue::set_assignment_mode(&env, admin, mode)
+    }
+
+    pub fn get_verification_assignment_mode(env: Env) -> VerificationAssignmentMode {
+        VerificationQueue::get_assignment_mode(&env)
+    }
+
+    /// Admin: set the SLA in seconds for pending requests (0 disables it).
+    pub fn set_verification_sla(
+        env: Env,
+        admin: Address,
+        sla_seconds: u64,
+    ) -> Result<(), ContractError> {
+        VerificationQueue::set_sla(&env, admin, sla_seconds)
+    }
+
+    pub fn get_verification_sla(env: Env) -> u64 {
+        VerificationQueue::get_sla(&env)
+    }
+
+    /// Emit a stale event for pending requests past the SLA, scanning up to
+    /// `limit` queue entries from `start`. Anyone may call this; returns the
+    /// project IDs flagged by this call.
+    pub fn flag_stale_verifications(env: Env, start: u32, limit: u32) -> Vec<u64> {
+        VerificationQueue::flag_stale(&env, start, limit)
+    }
+
+    // --- Verification Expiry ---
+
+    /// Verifications expiring at or before `before_ts`, soonest first,
+    /// including lapsed ones not yet swept. Pass a default cursor to start,
+    /// then `next_cursor` back as `cursor` while `has_more` is set.
+    pub fn list_expiring_verifications(
+        env: Env,
+        before_ts: u64,
+        cursor: ExpiryCursor,
+        limit: u32,
+    ) -> ExpiringVerificationPage {
+        VerificationExpiryIndex::list_expiring(&env, before_ts, cursor, limit)
+    }
+
+    /// Emit a renewal reminder for verifications expiring within the
+    /// reminder window, once per project and expiry time, among up to
+    /// `limit` listed verifications from `cursor`. Anyone may call this;
+    /// returns the project IDs reminded by this call.
+    pub fn notify_expiring_verifications(env: Env, cursor: ExpiryCursor, limit: u32) -> Vec<u64> {
+        VerificationExpiryIndex::notify_expiring(&env, cursor, limit)
+    }
+
+    /// Admin: set how many seconds before expiry renewal reminders start
+    /// (0 disables them).
+    pub fn set_expiry_reminder_window(
+        env: Env,
+        admin: Address,
+        window_seconds: u64,
+    ) -> Result<(), ContractError> {
+        VerificationExpiryIndex::set_reminder_window(&env, admin, window_seconds)
+    }
+
+    pub fn get_expiry_reminder_window(env: Env) -> u64 {
+        VerificationExpiryIndex::get_reminder_window(&env)
+    }
+
     // --- Reserved Project Names ---
 
     /// Admin: add a name to the reserved list.
@@ -836,6 +1599,36 @@ impl DongleContract {
         FeeManager::pay_fee(&env, payer, project_id, token)
     }
 
+    pub fn pay_tier_fee(
+        env: Env,
+        payer: Address,
+        project_id: u64,
+        tier_id: u32,
+        token: Option<Address>,
+    ) -> Result<(), ContractError> {
+        FeeManager::pay_tier_fee(&env, payer, project_id, tier_id, token)
+    }
+
+    /// Pay the review fee for a project in `token`, at that token's price.
+    /// The next review or review commitment by `reviewer` consumes it.
+    pub fn pay_review_fee(
+        env: Env,
+        reviewer: Address,
+        project_id: u64,
+        token: Option<Address>,
+    ) -> Result<(), ContractError> {
+        FeeManager::pay_review_fee(&env, reviewer, project_id, token)
+    }
+
+    /// A reviewer's unconsumed review fee payment for a project.
+    pub fn get_review_fee_payment(
+        env: Env,
+        project_id: u64,
+        reviewer: Address,
+    ) -> Option<FeePaymentRecord> {
+        FeeManager::get_review_fee_payment(&env, project_id, &reviewer)
+    }
+
     pub fn cancel_fee_payment(
         env: Env,
         caller: Address,
@@ -863,9 +1656,45 @@ impl DongleContract {
         FeeManager::get_fee_config(&env)
     }
 
-    /// Get fee payment details for a project (payer, amount, token, timestamp).
-    pub fn get_fee_payment_details(env: Env, project_id: u64) -> Option<FeePaymentRecord> {
-        FeeManager::get_fee_payment_details(&env, project_id)
+    /// Get fee payment details for a project (payer, amount, token, timestamp)
+    /// with its expiry time and status.
+    pub fn get_fee_payment_details(env: Env, project_id: u64) -> Option<FeePaymentDetails> {
+        FeeManager::get_fee_payment_status(&env, project_id)
+    }
+
+    /// Seconds a verification fee payment stays valid (0 = no expiry).
+    pub fn get_fee_payment_expiry(env: Env) -> u64 {
+        FeeManager::get_fee_payment_expiry(&env)
+    }
+
+    /// Admin: set how long verification fee payments stay valid.
+    pub fn set_fee_payment_expiry(
+        env: Env,
+        admin: Address,
+        expiry_seconds: u64,
+    ) -> Result<(), ContractError> {
+        FeeManager::set_fee_payment_expiry(&env, admin, expiry_seconds)
+    }
+
+    /// Payer: reclaim an expired, unused verification fee payment.
+    pub fn reclaim_fee_payment(
+        env: Env,
+        payer: Address,
+        project_id: u64,
+    ) -> Result<(), ContractError> {
+        EmergencyPause::require_not_paused(&env)?;
+        FeeManager::reclaim_fee_payment(&env, payer, project_id)
+    }
+
+    /// Owner: re-price an unused verification fee payment to the current fee
+    /// and renew it. Returns the new amount.
+    pub fn reprice_fee_payment(
+        env: Env,
+        owner: Address,
+        project_id: u64,
+    ) -> Result<u128, ContractError> {
+        EmergencyPause::require_not_paused(&env)?;
+        FeeManager::reprice_fee_payment(&env, owner, project_id)
     }
 
     /// Get registration fee payment details for an address.
@@ -873,6 +1702,90 @@ impl DongleContract {
         FeeManager::get_registration_fee_payment_details(&env, &address)
     }
 
+    /// Admin: accept fee payments in another token at its own fee amounts.
+    pub fn set_accepted_fee_token(
+        env: Env,
+        admin: Address,
+        fees: TokenFees,
+    ) -> Result<(), ContractError> {
+        FeeManager::set_accepted_fee_token(&env, admin, fees)
+    }
+
+    /// Admin: stop accepting fee payments in `token`.
+    pub fn remove_accepted_fee_token(
+        env: Env,
+        admin: Address,
+        token: Address,
+    ) -> Result<(), ContractError> {
+        FeeManager::remove_accepted_fee_token(&env, admin, token)
+    }
+
+    pub fn list_accepted_fee_tokens(env: Env) -> Vec<Address> {
+        FeeManager::list_accepted_fee_tokens(&env)
+    }
+
+    pub fn get_token_fees(env: Env, token: Address) -> Option<TokenFees> {
+        FeeManager::get_token_fees(&env, &token)
+    }
+
+    /// Fee charged for `operation` when paying in `token`.
+    pub fn get_fee_quote(
+        env: Env,
+        token: Option<Address>,
+        operation: FeeOperation,
+    ) -> Result<u128, ContractError> {
+        FeeManager::get_fee_quote(&env, token, operation)
+    }
+
+    /// Admin: split a fee type's revenue across recipients in basis points
+    /// (summing to 10000), or pay it all to the treasury with an empty list.
+    pub fn set_treasury_splits(
+        env: Env,
+        admin: Address,
+        operation: FeeOperation,
+        splits: Vec<TreasurySplit>,
+    ) -> Result<(), ContractError> {
+        FeeManager::set_treasury_splits(&env, admin, operation, splits)
+    }
+
+    pub fn get_treasury_splits(env: Env, operation: FeeOperation) -> Vec<TreasurySplit> {
+        FeeManager::get_treasury_splits(&env, operation)
+    }
+
+    // --- Fee Escrow ---
+
+    /// Admin: hold verification fees in the contract until the decision, and
+    /// set how rejected requests are refunded.
+    pub fn set_fee_escrow_config(
+        env: Env,
+        admin: Address,
+        config: FeeEscrowConfig,
+    ) -> Result<(), ContractError> {
+        FeeEscrowManager::set_config(&env, admin, config)
+    }
+
+    pub fn get_fee_escrow_config(env: Env) -> FeeEscrowConfig {
+        FeeEscrowManager::get_config(&env)
+    }
+
+    /// The verification fee held in escrow for a project, if any.
+    pub fn get_fee_escrow(env: Env, project_id: u64) -> Option<EscrowedFee> {
+        FeeEscrowManager::get_escrow(&env, project_id)
+    }
+
+    /// Escrowed fees per token, next to the contract's balance of each token.
+    pub fn get_fee_escrow_totals(env: Env) -> Vec<EscrowTokenTotal> {
+        FeeEscrowManager::get_totals(&env)
+    }
+
+    // --- Fee Revenue ---
+
+    /// Fees collected, refunded and net per token and fee type, all-time or
+    /// for the 30-day period containing `period`.
+    pub fn get_fee_revenue_report(env: Env, period: Option<u64>) -> FeeRevenueReport {
+        FeeRevenueLedger::get_report(&env, period)
+    }
+
     // --- TTL Management ---
 
     /// Extend TTL for a specific project and its related data
@@ -919,9 +1832,8 @@ impl DongleContract {
             if let Some((project_id, reviewer)) = review_ids.get(i) {
                 if ReviewRegistry::get_review(&env, project_id, reviewer.clone()).is_some() {
                     StorageManager::extend_review_ttl(&env, project_id, &reviewer);
-                    StorageManager::extend_project_reviews_ttl(&env, project_id);
+                    StorageManager::extend_review_index_ttl(&env, project_id, &reviewer);
                     StorageManager::extend_project_stats_ttl(&env, project_id);
-                    StorageManager::extend_user_reviews_ttl(&env, &reviewer);
                     refreshed = refreshed.saturating_add(1);
                 }
             }
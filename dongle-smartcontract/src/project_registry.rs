@@ -1,4 +1,5 @@
 use crate::admin_manager::AdminManager;
+use crate::conflict_registry::ConflictRegistry;
 use crate::constants::{
     MAJOR_METADATA_FIELD_METADATA_CID, MAJOR_METADATA_FIELD_NAME, MAJOR_METADATA_FIELD_WEBSITE,
     MAX_PAGE_LIMIT, MAX_PROJECTS_PER_USER,
@@ -9,8 +10,9 @@ use crate::events::{
     publish_claim_request_submitted_event, publish_ownership_transferred_event,
     publish_project_archived_event, publish_project_claimable_set_event,
     publish_project_reactivated_event, publish_project_registered_event,
-    publish_project_updated_event, publish_verification_status_reset_event,
+    publish_project_updated_event, publish_verification_status_reset_event, FeeOperation,
 };
+use crate::fee_escrow::FeeEscrowManager;
 use crate::fee_manager::FeeManager;
 use crate::storage_keys::{ExtensionKey, StorageKey};
 use crate::storage_manager::StorageManager;
@@ -19,6 +21,7 @@ use crate::types::{
     ProjectSortMode, ProjectUpdateParams, SecurityContactStatus, VerificationStatus,
 };
 use crate::utils::Utils;
+use crate::verification_registry::VerificationTierRegistry;
 use soroban_sdk::{Address, Bytes, Env, String, Vec};
 
 pub struct ProjectRegistry;
@@ -43,6 +46,7 @@ impl ProjectRegistry {
     ) -> Result<u64, ContractError> {
         // Validation phase
         params.owner.require_auth();
+        crate::blocklist::Blocklist::require_not_blocked(env, &params.owner)?;
 
         // Validate inputs - return typed errors instead of panicking
         Utils::validate_project_name(&params.name)?;
@@ -52,14 +56,11 @@ impl ProjectRegistry {
         Self::check_reserved_name(env, &params.name)?;
 
         // Check registration fee payment
-        if let Ok(config) = FeeManager::get_fee_config(env) {
-            if config.registration_fee > 0 {
-                FeeManager::consume_registration_fee_payment(
-                    env,
-                    &params.owner,
-                    config.registration_fee,
-                )?;
-            }
+        if FeeManager::is_fee_required(env, FeeOperation::Registration) {
+            let amount = FeeManager::get_registration_fee_payment_details(env, &params.owner)
+                .map(|payment| payment.amount)
+                .unwrap_or(0);
+            FeeManager::consume_registration_fee_payment(env, &params.owner, amount)?;
         }
 
         // Validate description with comprehensive checks
@@ -440,21 +441,35 @@ impl ProjectRegistry {
         if major_metadata_changed {
             let now = env.ledger().timestamp();
             if let Some(request_id) = project.current_verification_id {
-                if let Some(mut record) = env
-                    .storage()
-                    .persistent()
-                    .get::<StorageKey, crate::types::VerificationRecord>(
-                        &StorageKey::VerificationRecord(request_id),
+                if let Some(mut record) =
+                    crate::verification_registry::VerificationRegistry::get_verification_record(
+                        env, request_id,
                     )
                 {
+                    crate::verification_registry::VerificationQueue::dequeue(env, &record);
+                    let was_pending = record.status == VerificationStatus::Pending;
                     record.status = VerificationStatus::Unverified;
                     record.revoke_reason = Some(String::from_str(env, "MajorMetadataChanged"));
                     record.decided_at = now;
                     env.storage()
                         .persistent()
                         .set(&StorageKey::VerificationRecord(request_id), &record);
+                    // A request or renewal ended without a decision gets its
+                    // escrowed fee back in full.
+                    if was_pending {
+                        FeeEscrowManager::refund_undecided(
+                            env,
+                            params.project_id,
+                            FeeOperation::Verification,
+                        );
+                    }
                 }
             }
+            let renewal_key = StorageKey::VerificationRenewal(params.project_id);
+            if env.storage().persistent().has(&renewal_key) {
+                env.storage().persistent().remove(&renewal_key);
+                FeeEscrowManager::refund_undecided(env, params.project_id, FeeOperation::Renewal);
+            }
             project.verification_status = VerificationStatus::Unverified;
         }
 
@@ -883,9 +898,12 @@ impl ProjectRegistry {
         projects
     }
 
+    /// Non-archived projects with the given status. With `tier_id`, only
+    /// projects whose current verification tier is that tier are included.
     pub fn list_projects_by_status(
         env: &Env,
         status: VerificationStatus,
+        tier_id: Option<u32>,
         start_id: u64,
         limit: u32,
     ) -> Vec<Project> {
@@ -917,7 +935,18 @@ impl ProjectRegistry {
                 break;
             }
             if let Some(project) = Self::get_project(env, id) {
-                if project.verification_status == status && !project.archived {
+                if project.verification_status != status || project.archived {
+                    continue;
+                }
+                let in_tier = match tier_id {
+                    Some(tier_id) => {
+                        VerificationTierRegistry::get_project_tier(env, project.id)
+                            .map(|tier| tier.tier_id)
+                            == Some(tier_id)
+                    }
+                    None => true,
+                };
+                if in_tier {
                     projects.push_back(project);
                     collected += 1;
                 }
@@ -1397,6 +1426,10 @@ impl ProjectRegistry {
         if !AdminManager::is_admin(env, &admin) {
             return Err(ContractError::AdminOnly);
         }
+        if claim_request.claimant == admin {
+            return Err(ContractError::ConflictOfInterest);
+        }
+        ConflictRegistry::require_no_conflict(env, claim_request.project_id, &admin)?;
 
         // Shared pending→approved transition (ClaimKind::Ownership)
         Self::apply_claim_decision(&mut claim_request.status, ClaimKind::Ownership, true)?;
@@ -105,6 +105,18 @@ impl RatingCalculator {
         }
         (numerator / denominator) as u32
     }
+
+    /// Calculate the reputation-weighted average rating (scaled by 100).
+    ///
+    /// `weighted_sum` is the sum of `rating * 100 * weight` over all counted
+    /// reviews and `weight_total` is the sum of their weights.
+    /// Returns 0 if `weight_total` is 0, matching `calculate_average`.
+    pub fn calculate_reputation_weighted(weighted_sum: u64, weight_total: u64) -> u32 {
+        if weight_total == 0 {
+            return 0;
+        }
+        (weighted_sum / weight_total) as u32
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,7 @@
 //! Project reporting functionality for spam, scams, broken links, or abusive metadata.
 
 use crate::admin_action_log::AdminActionLog;
+use crate::blocklist::Blocklist;
 use crate::errors::ContractError;
 use crate::events::publish_project_reported_event;
 use crate::project_registry::ProjectRegistry;
@@ -24,6 +25,7 @@ impl ReportRegistry {
 
         // Require authentication
         reporter.require_auth();
+        Blocklist::require_not_blocked(env, &reporter)?;
 
         // Validate reason CID
         Utils::validate_report_reason_cid(&reason_cid)?;
@@ -116,6 +118,7 @@ impl ReportRegistry {
         // Project must exist
         crate::project_registry::ProjectRegistry::get_project(env, project_id)
             .ok_or(ContractError::ProjectNotFound)?;
+        crate::conflict_registry::ConflictRegistry::require_no_conflict(env, project_id, admin)?;
 
         let count = Self::get_project_report_count(env, project_id);
         if count == 0 {
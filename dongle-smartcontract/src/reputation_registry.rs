@@ -0,0 +1,326 @@
+//! Reviewer reputation: helpful votes, moderation signals, and the
+//! reputation-weighted rating aggregate maintained alongside `ProjectStats`.
+
+use crate::constants::{
+    REPUTATION_AGE_BONUS_PER_DAY, REPUTATION_BASE_SCORE, REPUTATION_HELPFUL_VOTE_BONUS,
+    REPUTATION_MAX_AGE_BONUS, REPUTATION_MAX_HELPFUL_BONUS, REPUTATION_MAX_SCORE,
+    REPUTATION_MIN_SCORE, REPUTATION_MIN_VOTER_SCORE, REPUTATION_MODERATION_PENALTY,
+    REPUTATION_VERIFIED_OWNER_BONUS,
+};
+use crate::errors::ContractError;
+use crate::events::publish_review_helpful_voted_event;
+use crate::project_registry::ProjectRegistry;
+use crate::rating_calculator::RatingCalculator;
+use crate::review_registry::ReviewRegistry;
+use crate::storage_keys::{ExtensionKey, StorageKey};
+use crate::types::{
+    ReputationWeightedStats, ReviewerReputation, ReviewerSignals, VerificationStatus,
+};
+use soroban_sdk::{Address, Env, Vec};
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+pub struct ReputationRegistry;
+
+impl ReputationRegistry {
+    // ── Signals ──────────────────────────────────────────────────────────
+
+    /// Vote a review as helpful. Each address may vote once per review and
+    /// reviewers cannot vote on their own reviews. Hidden reviews cannot
+    /// receive votes. The voter needs at least `REPUTATION_MIN_VOTER_SCORE`,
+    /// and the vote is worth `REPUTATION_HELPFUL_VOTE_BONUS` scaled by the
+    /// voter's score, so fresh addresses cannot inflate each other.
+    pub fn vote_review_helpful(
+        env: &Env,
+        project_id: u64,
+        reviewer: Address,
+        voter: Address,
+    ) -> Result<(), ContractError> {
+        voter.require_auth();
+
+        if ProjectRegistry::get_project(env, project_id).is_none() {
+            return Err(ContractError::ProjectNotFound);
+        }
+
+        let review = ReviewRegistry::get_review(env, project_id, reviewer.clone())
+            .ok_or(ContractError::ReviewNotFound)?;
+        if review.hidden {
+            return Err(ContractError::ReviewNotFound);
+        }
+        if voter == reviewer {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let vote_key = ExtensionKey::ReviewHelpfulVote(project_id, reviewer.clone(), voter.clone());
+        if env.storage().persistent().has(&vote_key) {
+            return Err(ContractError::AlreadyVoted);
+        }
+
+        let voter_score = Self::get_reviewer_reputation(env, voter.clone()).score;
+        if voter_score < REPUTATION_MIN_VOTER_SCORE {
+            return Err(ContractError::ReputationTooLow);
+        }
+
+        ReviewRegistry::record_first_interaction(env, &voter);
+
+        let mut signals = Self::get_reviewer_signals(env, &reviewer);
+        signals.helpful_votes = signals.helpful_votes.saturating_add(1);
+        signals.helpful_points = signals.helpful_points.saturating_add(
+            REPUTATION_HELPFUL_VOTE_BONUS.saturating_mul(voter_score) / REPUTATION_BASE_SCORE,
+        );
+        Self::set_reviewer_signals(env, &reviewer, &signals);
+        env.storage().persistent().set(&vote_key, &true);
+
+        publish_review_helpful_voted_event(env, project_id, reviewer, voter, signals.helpful_votes);
+        Ok(())
+    }
+
+    pub fn has_voted_helpful(
+        env: &Env,
+        project_id: u64,
+        reviewer: Address,
+        voter: Address,
+    ) -> bool {
+        env.storage()
+            .persistent()
+            .has(&ExtensionKey::ReviewHelpfulVote(
+                project_id, reviewer, voter,
+            ))
+    }
+
+    /// Called by moderation when one of the reviewer's reviews is hidden.
+    pub fn record_review_hidden(env: &Env, reviewer: &Address) {
+        let mut signals = Self::get_reviewer_signals(env, reviewer);
+        signals.hidden_reviews = signals.hidden_reviews.saturating_add(1);
+        Self::set_reviewer_signals(env, reviewer, &signals);
+    }
+
+    /// Called by moderation when a hidden review is restored, lifting the penalty.
+    pub fn record_review_restored(env: &Env, reviewer: &Address) {
+        let mut signals = Self::get_reviewer_signals(env, reviewer);
+        signals.hidden_reviews = signals.hidden_reviews.saturating_sub(1);
+        Self::set_reviewer_signals(env, reviewer, &signals);
+    }
+
+    /// Called when an admin hard-deletes one of the reviewer's reviews. If the
+    /// review was hidden at the time, its hidden penalty becomes a deleted one.
+    pub fn record_review_deleted_by_admin(env: &Env, reviewer: &Address, was_hidden: bool) {
+        let mut signals = Self::get_reviewer_signals(env, reviewer);
+        if was_hidden {
+            signals.hidden_reviews = signals.hidden_reviews.saturating_sub(1);
+        }
+        signals.deleted_reviews = signals.deleted_reviews.saturating_add(1);
+        Self::set_reviewer_signals(env, reviewer, &signals);
+    }
+
+    pub fn get_reviewer_signals(env: &Env, reviewer: &Address) -> ReviewerSignals {
+        env.storage()
+            .persistent()
+            .get(&ExtensionKey::ReviewerSignals(reviewer.clone()))
+            .unwrap_or_default()
+    }
+
+    fn set_reviewer_signals(env: &Env, reviewer: &Address, signals: &ReviewerSignals) {
+        env.storage()
+            .persistent()
+            .set(&ExtensionKey::ReviewerSignals(reviewer.clone()), signals);
+    }
+
+    // ── Score ────────────────────────────────────────────────────────────
+
+    /// Compute the reviewer's current reputation from stored signals.
+    ///
+    /// ```text
+    /// score = BASE
+    ///       + min(age_days * AGE_BONUS_PER_DAY, MAX_AGE_BONUS)
+    ///       + min(helpful_points, MAX_HELPFUL_BONUS)
+    ///       + VERIFIED_OWNER_BONUS (if the reviewer owns a verified project)
+    ///       - (hidden_reviews + deleted_reviews) * MODERATION_PENALTY
+    /// ```
+    /// clamped to `[REPUTATION_MIN_SCORE, REPUTATION_MAX_SCORE]`.
+    pub fn get_reviewer_reputation(env: &Env, reviewer: Address) -> ReviewerReputation {
+        let signals = Self::get_reviewer_signals(env, &reviewer);
+
+        let first_interaction: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&ExtensionKey::FirstInteraction(reviewer.clone()));
+        let account_age_seconds = first_interaction
+            .map(|ts| env.ledger().timestamp().saturating_sub(ts))
+            .unwrap_or(0);
+
+        let owns_verified_project = Self::owns_verified_project(env, &reviewer);
+
+        let age_days = (account_age_seconds / SECONDS_PER_DAY).min(u32::MAX as u64) as u32;
+        let age_bonus = age_days
+            .saturating_mul(REPUTATION_AGE_BONUS_PER_DAY)
+            .min(REPUTATION_MAX_AGE_BONUS);
+        let helpful_bonus = signals.helpful_points.min(REPUTATION_MAX_HELPFUL_BONUS);
+        let owner_bonus = if owns_verified_project {
+            REPUTATION_VERIFIED_OWNER_BONUS
+        } else {
+            0
+        };
+        let penalty = signals
+            .hidden_reviews
+            .saturating_add(signals.deleted_reviews)
+            .saturating_mul(REPUTATION_MODERATION_PENALTY);
+
+        let score = REPUTATION_BASE_SCORE
+            .saturating_add(age_bonus)
+            .saturating_add(helpful_bonus)
+            .saturating_add(owner_bonus)
+            .saturating_sub(penalty)
+            .clamp(REPUTATION_MIN_SCORE, REPUTATION_MAX_SCORE);
+
+        ReviewerReputation {
+            reviewer,
+            score,
+            account_age_seconds,
+            helpful_votes: signals.helpful_votes,
+            hidden_reviews: signals.hidden_reviews,
+            deleted_reviews: signals.deleted_reviews,
+            owns_verified_project,
+        }
+    }
+
+    fn owns_verified_project(env: &Env, owner: &Address) -> bool {
+        let ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::OwnerProjects(owner.clone()))
+            .unwrap_or_else(|| Vec::new(env));
+        for i in 0..ids.len() {
+            if let Some(project_id) = ids.get(i) {
+                if let Some(project) = ProjectRegistry::get_project(env, project_id) {
+                    if project.owner == *owner
+                        && project.verification_status == VerificationStatus::Verified
+                    {
+                        return true;
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    // ── Weighted aggregate ───────────────────────────────────────────────
+
+    /// Snapshot the reviewer's current reputation as the weight of a newly
+    /// submitted review and add it to the project's weighted aggregate.
+    pub fn on_review_added(env: &Env, project_id: u64, reviewer: &Address, rating: u32) {
+        let weight = Self::get_reviewer_reputation(env, reviewer.clone()).score;
+        env.storage().persistent().set(
+            &ExtensionKey::ReviewWeight(project_id, reviewer.clone()),
+            &weight,
+        );
+        let mut stats = Self::get_weighted_stats(env, project_id);
+        stats.weighted_rating_sum = stats
+            .weighted_rating_sum
+            .saturating_add(Self::weighted_rating(rating, weight));
+        stats.weight_total = stats.weight_total.saturating_add(weight as u64);
+        Self::set_weighted_stats(env, project_id, &stats);
+    }
+
+    /// Re-apply a previously removed review (e.g. after `restore_review`)
+    /// using its original weight snapshot.
+    pub fn on_review_restored(env: &Env, project_id: u64, reviewer: &Address, rating: u32) {
+        let Some(weight) = Self::get_review_weight(env, project_id, reviewer) else {
+            return;
+        };
+        let mut stats = Self::get_weighted_stats(env, project_id);
+        stats.weighted_rating_sum = stats
+            .weighted_rating_sum
+            .saturating_add(Self::weighted_rating(rating, weight));
+        stats.weight_total = stats.weight_total.saturating_add(weight as u64);
+        Self::set_weighted_stats(env, project_id, &stats);
+    }
+
+    /// Swap a review's rating in the weighted aggregate, keeping its weight.
+    pub fn on_review_updated(
+        env: &Env,
+        project_id: u64,
+        reviewer: &Address,
+        old_rating: u32,
+        new_rating: u32,
+    ) {
+        let Some(weight) = Self::get_review_weight(env, project_id, reviewer) else {
+            return;
+        };
+        let mut stats = Self::get_weighted_stats(env, project_id);
+        stats.weighted_rating_sum = stats
+            .weighted_rating_sum
+            .saturating_sub(Self::weighted_rating(old_rating, weight))
+            .saturating_add(Self::weighted_rating(new_rating, weight));
+        Self::set_weighted_stats(env, project_id, &stats);
+    }
+
+    /// Remove a review from the weighted aggregate. When `forget_weight` is
+    /// set (the review itself is being deleted) the weight snapshot is dropped too.
+    pub fn on_review_removed(
+        env: &Env,
+        project_id: u64,
+        reviewer: &Address,
+        rating: u32,
+        forget_weight: bool,
+    ) {
+        let Some(weight) = Self::get_review_weight(env, project_id, reviewer) else {
+            return;
+        };
+        let mut stats = Self::get_weighted_stats(env, project_id);
+        stats.weighted_rating_sum = stats
+            .weighted_rating_sum
+            .saturating_sub(Self::weighted_rating(rating, weight));
+        stats.weight_total = stats.weight_total.saturating_sub(weight as u64);
+        Self::set_weighted_stats(env, project_id, &stats);
+
+        if forget_weight {
+            env.storage()
+                .persistent()
+                .remove(&ExtensionKey::ReviewWeight(project_id, reviewer.clone()));
+        }
+    }
+
+    /// Forget a review's weight snapshot without touching the aggregate
+    /// (used when the review was already excluded, e.g. deleted while hidden).
+    pub fn forget_review_weight(env: &Env, project_id: u64, reviewer: &Address) {
+        env.storage()
+            .persistent()
+            .remove(&ExtensionKey::ReviewWeight(project_id, reviewer.clone()));
+    }
+
+    pub fn get_review_weight(env: &Env, project_id: u64, reviewer: &Address) -> Option<u32> {
+        env.storage()
+            .persistent()
+            .get(&ExtensionKey::ReviewWeight(project_id, reviewer.clone()))
+    }
+
+    pub fn get_weighted_stats(env: &Env, project_id: u64) -> ReputationWeightedStats {
+        env.storage()
+            .persistent()
+            .get(&ExtensionKey::ReputationWeightedStats(project_id))
+            .unwrap_or_default()
+    }
+
+    fn set_weighted_stats(env: &Env, project_id: u64, stats: &ReputationWeightedStats) {
+        env.storage()
+            .persistent()
+            .set(&ExtensionKey::ReputationWeightedStats(project_id), stats);
+    }
+
+    /// Reputation-weighted average rating for a project (scaled by 100).
+    /// Returns 0 when no weighted reviews are counted.
+    pub fn get_reputation_weighted_rating(env: &Env, project_id: u64) -> u32 {
+        let stats = Self::get_weighted_stats(env, project_id);
+        RatingCalculator::calculate_reputation_weighted(
+            stats.weighted_rating_sum,
+            stats.weight_total,
+        )
+    }
+
+    fn weighted_rating(rating: u32, weight: u32) -> u64 {
+        (rating as u64)
+            .saturating_mul(100)
+            .saturating_mul(weight as u64)
+    }
+}
@@ -0,0 +1,403 @@
+//! Chunked review indexes.
+//!
+//! The reviewers of a project and the projects of a reviewer are stored as
+//! fixed-size pages of `REVIEW_INDEX_PAGE_SIZE` entries plus an entry count.
+//! Each entry also records its position, so removal swaps the last entry into
+//! the gap instead of rewriting the whole index. Appends and removals therefore
+//! touch at most two pages regardless of index size.
+//!
+//! Indexes written by earlier contract versions as a single `Vec`
+//! (`StorageKey::ProjectReviews` / `StorageKey::UserReviews`) are still readable
+//! and are moved into pages on the next write.
+//!
+//! A third index keeps the projects whose review a reviewer deleted, so a
+//! reviewer's full record (including tombstones) can be listed.
+
+use crate::constants::{
+    LEDGER_BUMP_REVIEW, LEDGER_BUMP_USER, LEDGER_THRESHOLD_REVIEW, LEDGER_THRESHOLD_USER,
+    MAX_REVIEW_SCAN_PAGES, REVIEW_INDEX_PAGE_SIZE,
+};
+use crate::storage_keys::{FeatureKey, StorageKey};
+use soroban_sdk::{Address, Env, IntoVal, TryFromVal, Val, Vec};
+
+/// Storage layout of one paged index.
+trait PagedIndex {
+    type Item: IntoVal<Env, Val> + TryFromVal<Env, Val> + Clone;
+
+    fn count_key(&self) -> FeatureKey;
+    fn page_key(&self, page: u32) -> FeatureKey;
+    fn pos_key(&self, item: &Self::Item) -> FeatureKey;
+    /// Single-`Vec` key used by earlier contract versions, if any.
+    fn legacy_key(&self) -> Option<StorageKey>;
+    fn ttl(&self) -> (u32, u32);
+}
+
+struct ProjectIndex(u64);
+
+impl PagedIndex for ProjectIndex {
+    type Item = Address;
+
+    fn count_key(&self) -> FeatureKey {
+        FeatureKey::ProjectReviewCount(self.0)
+    }
+
+    fn page_key(&self, page: u32) -> FeatureKey {
+        FeatureKey::ProjectReviewPage(self.0, page)
+    }
+
+    fn pos_key(&self, item: &Address) -> FeatureKey {
+        FeatureKey::ProjectReviewPos(self.0, item.clone())
+    }
+
+    fn legacy_key(&self) -> Option<StorageKey> {
+        Some(StorageKey::ProjectReviews(self.0))
+    }
+
+    fn ttl(&self) -> (u32, u32) {
+        (LEDGER_THRESHOLD_REVIEW, LEDGER_BUMP_REVIEW)
+    }
+}
+
+struct UserIndex(Address);
+
+impl PagedIndex for UserIndex {
+    type Item = u64;
+
+    fn count_key(&self) -> FeatureKey {
+        FeatureKey::UserReviewCount(self.0.clone())
+    }
+
+    fn page_key(&self, page: u32) -> FeatureKey {
+        FeatureKey::UserReviewPage(self.0.clone(), page)
+    }
+
+    fn pos_key(&self, item: &u64) -> FeatureKey {
+        FeatureKey::UserReviewPos(self.0.clone(), *item)
+    }
+
+    fn legacy_key(&self) -> Option<StorageKey> {
+        Some(StorageKey::UserReviews(self.0.clone()))
+    }
+
+    fn ttl(&self) -> (u32, u32) {
+        (LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER)
+    }
+}
+
+struct UserDeletedIndex(Address);
+
+impl PagedIndex for UserDeletedIndex {
+    type Item = u64;
+
+    fn count_key(&self) -> FeatureKey {
+        FeatureKey::UserDeletedReviewCount(self.0.clone())
+    }
+
+    fn page_key(&self, page: u32) -> FeatureKey {
+        FeatureKey::UserDeletedReviewPage(self.0.clone(), page)
+    }
+
+    fn pos_key(&self, item: &u64) -> FeatureKey {
+        FeatureKey::UserDeletedReviewPos(self.0.clone(), *item)
+    }
+
+    fn legacy_key(&self) -> Option<StorageKey> {
+        None
+    }
+
+    fn ttl(&self) -> (u32, u32) {
+        (LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER)
+    }
+}
+
+pub struct ReviewIndex;
+
+impl ReviewIndex {
+    // ── Project → reviewers ─────────────────────────────────────────────
+
+    /// Number of reviewers indexed for a project.
+    pub fn project_len(env: &Env, project_id: u64) -> u32 {
+        Self::len(env, &ProjectIndex(project_id))
+    }
+
+    pub fn add_project_reviewer(env: &Env, project_id: u64, reviewer: &Address) {
+        Self::push(env, &ProjectIndex(project_id), reviewer.clone());
+    }
+
+    pub fn remove_project_reviewer(env: &Env, project_id: u64, reviewer: &Address) {
+        Self::remove(env, &ProjectIndex(project_id), reviewer);
+    }
+
+    /// Reviewers at positions `start..start + limit` of a project's index.
+    pub fn project_reviewers(env: &Env, project_id: u64, start: u32, limit: u32) -> Vec<Address> {
+        Self::range(env, &ProjectIndex(project_id), start, limit)
+    }
+
+    /// Extend TTL for the count, position and page holding `reviewer`.
+    pub fn extend_project_entry_ttl(env: &Env, project_id: u64, reviewer: &Address) {
+        Self::extend_entry_ttl(env, &ProjectIndex(project_id), reviewer);
+    }
+
+    /// Extend TTL for the count and the first `MAX_REVIEW_SCAN_PAGES` pages
+    /// of a project's index.
+    pub fn extend_project_ttl(env: &Env, project_id: u64) {
+        Self::extend_all_ttl(env, &ProjectIndex(project_id));
+    }
+
+    // ── Reviewer → projects ─────────────────────────────────────────────
+
+    /// Number of projects indexed for a reviewer.
+    pub fn user_len(env: &Env, reviewer: &Address) -> u32 {
+        Self::len(env, &UserIndex(reviewer.clone()))
+    }
+
+    pub fn add_user_project(env: &Env, reviewer: &Address, project_id: u64) {
+        Self::push(env, &UserIndex(reviewer.clone()), project_id);
+    }
+
+    pub fn remove_user_project(env: &Env, reviewer: &Address, project_id: u64) {
+        Self::remove(env, &UserIndex(reviewer.clone()), &project_id);
+    }
+
+    /// Project IDs at positions `start..start + limit` of a reviewer's index.
+    pub fn user_projects(env: &Env, reviewer: &Address, start: u32, limit: u32) -> Vec<u64> {
+        Self::range(env, &UserIndex(reviewer.clone()), start, limit)
+    }
+
+    /// Extend TTL for the count, position and page holding `project_id`.
+    pub fn extend_user_entry_ttl(env: &Env, reviewer: &Address, project_id: u64) {
+        Self::extend_entry_ttl(env, &UserIndex(reviewer.clone()), &project_id);
+    }
+
+    /// Extend TTL for the count and the first `MAX_REVIEW_SCAN_PAGES` pages of
+    /// a reviewer's index and of the index of deleted reviews.
+    pub fn extend_user_ttl(env: &Env, reviewer: &Address) {
+        Self::extend_all_ttl(env, &UserIndex(reviewer.clone()));
+        Self::extend_all_ttl(env, &UserDeletedIndex(reviewer.clone()));
+    }
+
+    // ── Reviewer → projects with a deleted review ───────────────────────
+
+    /// Number of projects whose review the reviewer deleted.
+    pub fn user_deleted_len(env: &Env, reviewer: &Address) -> u32 {
+        Self::len(env, &UserDeletedIndex(reviewer.clone()))
+    }
+
+    /// Record a deleted review. No-op if the project is already recorded.
+    pub fn add_user_deleted(env: &Env, reviewer: &Address, project_id: u64) {
+        let index = UserDeletedIndex(reviewer.clone());
+        if !env.storage().persistent().has(&index.pos_key(&project_id)) {
+            Self::push(env, &index, project_id);
+        }
+    }
+
+    /// Forget a deleted review, e.g. when the reviewer reviews the project again.
+    pub fn remove_user_deleted(env: &Env, reviewer: &Address, project_id: u64) {
+        Self::remove(env, &UserDeletedIndex(reviewer.clone()), &project_id);
+    }
+
+    /// Project IDs at positions `start..start + limit` of a reviewer's deleted index.
+    pub fn user_deleted_projects(
+        env: &Env,
+        reviewer: &Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        Self::range(env, &UserDeletedIndex(reviewer.clone()), start, limit)
+    }
+
+    // ── Generic paged index ─────────────────────────────────────────────
+
+    fn legacy<I: PagedIndex>(env: &Env, index: &I) -> Option<Vec<I::Item>> {
+        env.storage().persistent().get(&index.legacy_key()?)
+    }
+
+    fn len<I: PagedIndex>(env: &Env, index: &I) -> u32 {
+        if let Some(legacy) = Self::legacy(env, index) {
+            return legacy.len();
+        }
+        env.storage()
+            .persistent()
+            .get(&index.count_key())
+            .unwrap_or(0)
+    }
+
+    fn load_page<I: PagedIndex>(env: &Env, index: &I, page: u32) -> Vec<I::Item> {
+        env.storage()
+            .persistent()
+            .get(&index.page_key(page))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    fn store_page<I: PagedIndex>(env: &Env, index: &I, page: u32, items: &Vec<I::Item>) {
+        let key = index.page_key(page);
+        if items.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            let (threshold, bump) = index.ttl();
+            env.storage().persistent().set(&key, items);
+            env.storage().persistent().extend_ttl(&key, threshold, bump);
+        }
+    }
+
+    fn store_pos<I: PagedIndex>(env: &Env, index: &I, item: &I::Item, pos: u32) {
+        let (threshold, bump) = index.ttl();
+        let key = index.pos_key(item);
+        env.storage().persistent().set(&key, &pos);
+        env.storage().persistent().extend_ttl(&key, threshold, bump);
+    }
+
+    fn store_count<I: PagedIndex>(env: &Env, index: &I, count: u32) {
+        let (threshold, bump) = index.ttl();
+        let key = index.count_key();
+        env.storage().persistent().set(&key, &count);
+        env.storage().persistent().extend_ttl(&key, threshold, bump);
+    }
+
+    /// Move a single-`Vec` index written by an earlier contract version into pages.
+    fn migrate_legacy<I: PagedIndex>(env: &Env, index: &I) {
+        let legacy = match Self::legacy(env, index) {
+            Some(legacy) => legacy,
+            None => return,
+        };
+        if let Some(legacy_key) = index.legacy_key() {
+            env.storage().persistent().remove(&legacy_key);
+        }
+
+        let mut page = Vec::new(env);
+        let mut page_no = 0u32;
+        for i in 0..legacy.len() {
+            if let Some(item) = legacy.get(i) {
+                Self::store_pos(env, index, &item, i);
+                page.push_back(item);
+                if page.len() == REVIEW_INDEX_PAGE_SIZE {
+                    Self::store_page(env, index, page_no, &page);
+                    page = Vec::new(env);
+                    page_no += 1;
+                }
+            }
+        }
+        Self::store_page(env, index, page_no, &page);
+        Self::store_count(env, index, legacy.len());
+    }
+
+    fn push<I: PagedIndex>(env: &Env, index: &I, item: I::Item) {
+        Self::migrate_legacy(env, index);
+
+        let count = Self::len(env, index);
+        let page_no = count / REVIEW_INDEX_PAGE_SIZE;
+        let mut page = Self::load_page(env, index, page_no);
+        page.push_back(item.clone());
+
+        Self::store_page(env, index, page_no, &page);
+        Self::store_pos(env, index, &item, count);
+        Self::store_count(env, index, count + 1);
+    }
+
+    /// Remove `item` by moving the last entry into its slot. No-op if absent.
+    fn remove<I: PagedIndex>(env: &Env, index: &I, item: &I::Item) {
+        Self::migrate_legacy(env, index);
+
+        let pos_key = index.pos_key(item);
+        let pos: u32 = match env.storage().persistent().get(&pos_key) {
+            Some(pos) => pos,
+            None => return,
+        };
+        let count = Self::len(env, index);
+        let last = count - 1;
+        let last_page_no = last / REVIEW_INDEX_PAGE_SIZE;
+
+        let mut last_page = Self::load_page(env, index, last_page_no);
+        let moved = last_page.pop_back();
+
+        if pos != last {
+            if let Some(moved) = moved {
+                let page_no = pos / REVIEW_INDEX_PAGE_SIZE;
+                let slot = pos % REVIEW_INDEX_PAGE_SIZE;
+                if page_no == last_page_no {
+                    last_page.set(slot, moved.clone());
+                } else {
+                    let mut page = Self::load_page(env, index, page_no);
+                    page.set(slot, moved.clone());
+                    Self::store_page(env, index, page_no, &page);
+                }
+                Self::store_pos(env, index, &moved, pos);
+            }
+        }
+
+        Self::store_page(env, index, last_page_no, &last_page);
+        env.storage().persistent().remove(&pos_key);
+        Self::store_count(env, index, last);
+    }
+
+    fn range<I: PagedIndex>(env: &Env, index: &I, start: u32, limit: u32) -> Vec<I::Item> {
+        let mut out = Vec::new(env);
+
+        if let Some(legacy) = Self::legacy(env, index) {
+            let end = core::cmp::min(start.saturating_add(limit), legacy.len());
+            for i in start..end {
+                if let Some(item) = legacy.get(i) {
+                    out.push_back(item);
+                }
+            }
+            return out;
+        }
+
+        let count = Self::len(env, index);
+        let end = core::cmp::min(start.saturating_add(limit), count);
+        let mut pos = start;
+        while pos < end {
+            let page_no = pos / REVIEW_INDEX_PAGE_SIZE;
+            let page = Self::load_page(env, index, page_no);
+            let page_end = core::cmp::min((page_no + 1) * REVIEW_INDEX_PAGE_SIZE, end);
+            for i in pos..page_end {
+                if let Some(item) = page.get(i % REVIEW_INDEX_PAGE_SIZE) {
+                    out.push_back(item);
+                }
+            }
+            pos = page_end;
+        }
+        out
+    }
+
+    fn extend_if_exists(env: &Env, key: &FeatureKey, threshold: u32, bump: u32) {
+        if env.storage().persistent().has(key) {
+            env.storage().persistent().extend_ttl(key, threshold, bump);
+        }
+    }
+
+    fn extend_entry_ttl<I: PagedIndex>(env: &Env, index: &I, item: &I::Item) {
+        let (threshold, bump) = index.ttl();
+        let pos_key = index.pos_key(item);
+        Self::extend_if_exists(env, &index.count_key(), threshold, bump);
+        if let Some(pos) = env.storage().persistent().get::<_, u32>(&pos_key) {
+            env.storage()
+                .persistent()
+                .extend_ttl(&pos_key, threshold, bump);
+            let page_key = index.page_key(pos / REVIEW_INDEX_PAGE_SIZE);
+            Self::extend_if_exists(env, &page_key, threshold, bump);
+        }
+    }
+
+    /// Extend TTL for the count and the first `MAX_REVIEW_SCAN_PAGES` pages.
+    /// Later pages are refreshed with the entries they hold, by writes and by
+    /// `extend_reviews_ttl`.
+    fn extend_all_ttl<I: PagedIndex>(env: &Env, index: &I) {
+        let (threshold, bump) = index.ttl();
+        if let Some(legacy_key) = index.legacy_key() {
+            if env.storage().persistent().has(&legacy_key) {
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&legacy_key, threshold, bump);
+            }
+        }
+        Self::extend_if_exists(env, &index.count_key(), threshold, bump);
+        let count = Self::len(env, index);
+        let pages = count
+            .div_ceil(REVIEW_INDEX_PAGE_SIZE)
+            .min(MAX_REVIEW_SCAN_PAGES);
+        for page_no in 0..pages {
+            Self::extend_if_exists(env, &index.page_key(page_no), threshold, bump);
+        }
+    }
+}
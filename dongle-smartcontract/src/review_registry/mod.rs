@@ -1,7 +1,11 @@
 //! Review registry: create/update/delete reviews and maintain aggregates and indexes.
 
+mod index;
+mod rounds;
 mod storage;
 mod validation;
 
+pub use index::ReviewIndex;
+pub use rounds::ReviewRoundRegistry;
 pub use storage::ReviewRegistry;
 pub use validation::ReviewValidation;
@@ -0,0 +1,327 @@
+//! Commit-reveal review rounds.
+//!
+//! A project owner opens a round with a commit phase and a reveal phase.
+//! During the commit phase reviewers submit a hash of the rating, CID and a
+//! salt bound to the project, round and reviewer (see `compute_commitment`),
+//! so nobody can see ratings while the round is collecting them or replay
+//! another reviewer's commitment. During the reveal phase reviewers disclose the rating, CID
+//! and salt. Once the reveal phase ends anyone may finalize the round, which
+//! adds every revealed review to the project at once. Commitments live in
+//! temporary storage and simply expire if they are never revealed.
+//!
+//! Because an open round blocks `add_review`, an owner must wait as long as
+//! the previous round ran before opening the next one.
+
+use crate::blocklist::Blocklist;
+use crate::constants::{
+    MAX_CID_LEN, MAX_PAGE_LIMIT, MAX_REVIEW_ROUND_PHASE_SECONDS, SECONDS_PER_LEDGER,
+};
+use crate::errors::ContractError;
+use crate::events::{
+    publish_review_committed_event, publish_review_revealed_event,
+    publish_review_round_finalized_event, publish_review_round_opened_event,
+};
+use crate::fee_manager::FeeManager;
+use crate::project_registry::ProjectRegistry;
+use crate::review_registry::storage::ReviewRegistry;
+use crate::review_registry::validation::ReviewValidation;
+use crate::storage_keys::{FeatureKey, StorageKey};
+use crate::types::{RevealedReview, ReviewCommitment, ReviewRound};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, String};
+
+pub struct ReviewRoundRegistry;
+
+impl ReviewRoundRegistry {
+    /// Latest review round for a project, finalized or not.
+    pub fn get_round(env: &Env, project_id: u64) -> Option<ReviewRound> {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::ReviewRound(project_id))
+    }
+
+    /// Whether the project has a round that has not been finalized yet.
+    pub fn is_round_open(env: &Env, project_id: u64) -> bool {
+        matches!(Self::get_round(env, project_id), Some(round) if !round.finalized)
+    }
+
+    fn open_round(env: &Env, project_id: u64) -> Result<ReviewRound, ContractError> {
+        match Self::get_round(env, project_id) {
+            Some(round) if !round.finalized => Ok(round),
+            _ => Err(ContractError::NoActiveReviewRound),
+        }
+    }
+
+    fn save_round(env: &Env, round: &ReviewRound) {
+        env.storage()
+            .persistent()
+            .set(&FeatureKey::ReviewRound(round.project_id), round);
+    }
+
+    /// Compute the commitment for a sealed review:
+    /// `sha256(project_id as big-endian u64 || round_id as big-endian u32 ||
+    /// reviewer XDR || rating as big-endian u32 || comment_cid bytes || salt)`.
+    /// A missing CID contributes no bytes.
+    pub fn compute_commitment(
+        env: &Env,
+        project_id: u64,
+        round_id: u32,
+        reviewer: &Address,
+        rating: u32,
+        comment_cid: &Option<String>,
+        salt: &BytesN<32>,
+    ) -> Result<BytesN<32>, ContractError> {
+        let mut buf = Bytes::from_array(env, &project_id.to_be_bytes());
+        buf.extend_from_array(&round_id.to_be_bytes());
+        buf.append(&reviewer.clone().to_xdr(env));
+        buf.extend_from_array(&rating.to_be_bytes());
+        if let Some(cid) = comment_cid {
+            ReviewValidation::validate_review_cid(cid)?;
+            let len = cid.len() as usize;
+            let mut scratch = [0u8; MAX_CID_LEN];
+            cid.copy_into_slice(&mut scratch[..len]);
+            buf.extend_from_slice(&scratch[..len]);
+        }
+        buf.append(&Bytes::from(salt.clone()));
+        Ok(env.crypto().sha256(&buf).into())
+    }
+
+    /// Project owner: open a commit-reveal round. `add_review` is rejected for
+    /// the project until the round is finalized.
+    pub fn start_review_round(
+        env: &Env,
+        project_id: u64,
+        caller: Address,
+        commit_duration: u64,
+        reveal_duration: u64,
+    ) -> Result<ReviewRound, ContractError> {
+        caller.require_auth();
+
+        let project =
+            ProjectRegistry::get_project(env, project_id).ok_or(ContractError::ProjectNotFound)?;
+        if project.owner != caller {
+            return Err(ContractError::Unauthorized);
+        }
+
+        if commit_duration == 0
+            || reveal_duration == 0
+            || commit_duration > MAX_REVIEW_ROUND_PHASE_SECONDS
+            || reveal_duration > MAX_REVIEW_ROUND_PHASE_SECONDS
+        {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let now = env.ledger().timestamp();
+        let previous = Self::get_round(env, project_id);
+        if let Some(round) = &previous {
+            if !round.finalized {
+                return Err(ContractError::ReviewRoundActive);
+            }
+            // Leave the project open to direct reviews for at least as long as
+            // the previous round ran.
+            let cooldown_ends_at = round.reveal_ends_at + (round.reveal_ends_at - round.opened_at);
+            if now < cooldown_ends_at {
+                return Err(ContractError::ReviewRoundCooldown);
+            }
+        }
+        let round_id = previous.map(|round| round.round_id + 1).unwrap_or(1);
+
+        let round = ReviewRound {
+            round_id,
+            project_id,
+            opened_by: caller.clone(),
+            opened_at: now,
+            commit_ends_at: now + commit_duration,
+            reveal_ends_at: now + commit_duration + reveal_duration,
+            commit_count: 0,
+            reveal_count: 0,
+            finalized_count: 0,
+            finalized: false,
+        };
+        Self::save_round(env, &round);
+
+        publish_review_round_opened_event(
+            env,
+            project_id,
+            round_id,
+            caller,
+            round.commit_ends_at,
+            round.reveal_ends_at,
+        );
+
+        Ok(round)
+    }
+
+    /// Submit a sealed review during the commit phase of the project's round.
+    pub fn commit_review(
+        env: &Env,
+        project_id: u64,
+        reviewer: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        reviewer.require_auth();
+        ReviewRegistry::check_can_review(env, project_id, &reviewer)?;
+
+        let mut round = Self::open_round(env, project_id)?;
+        let now = env.ledger().timestamp();
+        if now >= round.commit_ends_at {
+            return Err(ContractError::CommitPhaseClosed);
+        }
+
+        let key = FeatureKey::ReviewCommitment(project_id, reviewer.clone());
+        if let Some(existing) = env.storage().temporary().get::<_, ReviewCommitment>(&key) {
+            if existing.round_id == round.round_id {
+                return Err(ContractError::DuplicateReview);
+            }
+        }
+
+        ReviewRegistry::record_first_interaction(env, &reviewer);
+        FeeManager::consume_review_fee_payment(env, project_id, &reviewer);
+
+        let entry = ReviewCommitment {
+            round_id: round.round_id,
+            reviewer: reviewer.clone(),
+            commitment,
+            committed_at: now,
+        };
+        // Keep the commitment around until the reveal window closes; after that
+        // it is useless and is left to expire.
+        let ttl_ledgers = ((round.reveal_ends_at - now) / SECONDS_PER_LEDGER + 1) as u32;
+        env.storage().temporary().set(&key, &entry);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, ttl_ledgers, ttl_ledgers);
+
+        round.commit_count += 1;
+        Self::save_round(env, &round);
+
+        publish_review_committed_event(env, project_id, round.round_id, reviewer);
+        Ok(())
+    }
+
+    /// Reveal a committed review during the reveal phase. The review is held
+    /// until the round is finalized.
+    pub fn reveal_review(
+        env: &Env,
+        project_id: u64,
+        reviewer: Address,
+        rating: u32,
+        comment_cid: Option<String>,
+        salt: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        reviewer.require_auth();
+        Blocklist::require_not_blocked(env, &reviewer)?;
+        if !ReviewRegistry::get_reviews_enabled(env, project_id) {
+            return Err(ContractError::ReviewsDisabled);
+        }
+
+        let mut round = Self::open_round(env, project_id)?;
+        let now = env.ledger().timestamp();
+        if now < round.commit_ends_at || now >= round.reveal_ends_at {
+            return Err(ContractError::NotInRevealWindow);
+        }
+
+        let key = FeatureKey::ReviewCommitment(project_id, reviewer.clone());
+        let entry: ReviewCommitment = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .filter(|entry: &ReviewCommitment| entry.round_id == round.round_id)
+            .ok_or(ContractError::CommitmentNotFound)?;
+
+        ReviewValidation::validate_rating(rating)?;
+        let expected = Self::compute_commitment(
+            env,
+            project_id,
+            round.round_id,
+            &reviewer,
+            rating,
+            &comment_cid,
+            &salt,
+        )?;
+        if expected != entry.commitment {
+            return Err(ContractError::CommitmentMismatch);
+        }
+
+        env.storage().temporary().remove(&key);
+        env.storage().persistent().set(
+            &FeatureKey::RoundReveal(project_id, round.reveal_count),
+            &RevealedReview {
+                reviewer: reviewer.clone(),
+                rating,
+                comment_cid,
+                revealed_at: now,
+            },
+        );
+        round.reveal_count += 1;
+        Self::save_round(env, &round);
+
+        publish_review_revealed_event(env, project_id, round.round_id, reviewer);
+        Ok(())
+    }
+
+    /// Add revealed reviews to the project once the reveal phase has ended.
+    ///
+    /// Permissionless. Processes up to `limit` reveals (clamped to
+    /// `MAX_PAGE_LIMIT`); call again until the round reports `finalized`.
+    /// Reveals from reviewers blocked since revealing are dropped, as are all
+    /// reveals if reviews have been disabled for the project.
+    /// Returns the number of reviews added by this call.
+    pub fn finalize_review_round(
+        env: &Env,
+        project_id: u64,
+        limit: u32,
+    ) -> Result<u32, ContractError> {
+        let mut round = Self::open_round(env, project_id)?;
+        if env.ledger().timestamp() < round.reveal_ends_at {
+            return Err(ContractError::ReviewRoundNotEnded);
+        }
+
+        let effective_limit = if limit == 0 || limit > MAX_PAGE_LIMIT {
+            MAX_PAGE_LIMIT
+        } else {
+            limit
+        };
+        let end = core::cmp::min(
+            round.finalized_count.saturating_add(effective_limit),
+            round.reveal_count,
+        );
+
+        let reviews_enabled = ReviewRegistry::get_reviews_enabled(env, project_id);
+        let mut added = 0u32;
+        for i in round.finalized_count..end {
+            let key = FeatureKey::RoundReveal(project_id, i);
+            if let Some(reveal) = env.storage().persistent().get::<_, RevealedReview>(&key) {
+                let review_key = StorageKey::Review(project_id, reveal.reviewer.clone());
+                if reviews_enabled
+                    && !Blocklist::is_blocked(env, &reveal.reviewer)
+                    && !env.storage().persistent().has(&review_key)
+                {
+                    ReviewRegistry::insert_review(
+                        env,
+                        project_id,
+                        reveal.reviewer,
+                        reveal.rating,
+                        reveal.comment_cid,
+                    );
+                    added += 1;
+                }
+                env.storage().persistent().remove(&key);
+            }
+        }
+        round.finalized_count = end;
+
+        if round.finalized_count == round.reveal_count {
+            round.finalized = true;
+            publish_review_round_finalized_event(
+                env,
+                project_id,
+                round.round_id,
+                round.reveal_count,
+                round.commit_count - round.reveal_count,
+            );
+        }
+        Self::save_round(env, &round);
+
+        Ok(added)
+    }
+}
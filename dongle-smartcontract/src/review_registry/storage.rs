@@ -1,21 +1,29 @@
 //! Review registry storage mutations: CRUD, moderation, aggregates, and listing.
 
 use crate::admin_action_log::AdminActionLog;
+use crate::blocklist::Blocklist;
+use crate::conflict_registry::ConflictRegistry;
 use crate::constants::{
     DEFAULT_MIN_REVIEWER_AGE_SECONDS, DEFAULT_REQUIRE_ENDORSEMENT, DEFAULT_REVIEW_FEE,
-    MAX_PAGE_LIMIT, MAX_REVIEWS_PER_PROJECT, MAX_REVIEWS_PER_USER, MAX_REVIEW_REVISIONS,
-    REVIEW_UPDATE_COOLDOWN_SECONDS,
+    MAX_PAGE_LIMIT, MAX_REVIEW_POLICY_PERIOD_SECONDS, MAX_REVIEW_REVISIONS, MAX_REVIEW_SCAN_PAGES,
+    REVIEW_INDEX_PAGE_SIZE, REVIEW_UPDATE_COOLDOWN_SECONDS,
 };
 use crate::errors::ContractError;
-use crate::events::{publish_review_event, publish_review_revision_event};
+use crate::events::{publish_review_event, publish_review_revision_event, FeeOperation};
+use crate::fee_manager::FeeManager;
 use crate::project_registry::ProjectRegistry;
 use crate::rating_calculator::RatingCalculator;
+use crate::reputation_registry::ReputationRegistry;
+use crate::review_registry::index::ReviewIndex;
+use crate::review_registry::rounds::ReviewRoundRegistry;
 use crate::review_registry::validation::ReviewValidation;
-use crate::storage_keys::{ExtensionKey, StorageKey};
+use crate::storage_keys::{ExtensionKey, FeatureKey, StorageKey};
 use crate::storage_manager::StorageManager;
+use crate::storage_migration::{get_persistent, LegacyReview};
 use crate::types::{
     AdminActionType, Project, ProjectStats, Review, ReviewAction, ReviewEligibilityConfig,
-    ReviewRevision, ReviewSortMode, ReviewTombstone,
+    ReviewInteractionProof, ReviewPolicy, ReviewRecord, ReviewRevision, ReviewSortMode,
+    ReviewTombstone, ReviewerReviewPage,
 };
 use crate::utils::Utils;
 use soroban_sdk::{Address, Env, String, Vec};
@@ -56,6 +64,133 @@ impl ReviewRegistry {
         Ok(())
     }
 
+    // ── Review Edit Policy ──────────────────────────────────────────────
+
+    /// Global review edit policy. Returns the built-in defaults (1 hour
+    /// cooldown, `MAX_REVIEW_REVISIONS` revisions, no edit window, CID change
+    /// optional) if never set.
+    pub fn get_global_review_policy(env: &Env) -> ReviewPolicy {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::ReviewPolicy)
+            .unwrap_or(ReviewPolicy {
+                update_cooldown_seconds: REVIEW_UPDATE_COOLDOWN_SECONDS,
+                max_revisions: MAX_REVIEW_REVISIONS,
+                edit_window_seconds: 0,
+                new_cid_on_rating_change: false,
+            })
+    }
+
+    /// Policy in effect for a project: its override if set, else the global policy.
+    pub fn get_review_policy(env: &Env, project_id: u64) -> ReviewPolicy {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::ProjectReviewPolicy(project_id))
+            .unwrap_or_else(|| Self::get_global_review_policy(env))
+    }
+
+    /// Admin-only: set the global policy (`project_id = None`) or a project's
+    /// override. Like other direct admin setters this is only available while
+    /// the approval threshold is 1; otherwise use a `SetReviewPolicy` proposal.
+    pub fn set_review_policy(
+        env: &Env,
+        admin: Address,
+        project_id: Option<u64>,
+        policy: ReviewPolicy,
+    ) -> Result<(), ContractError> {
+        crate::auth::require_admin_auth(env, &admin)?;
+        if crate::admin_manager::AdminManager::get_admin_approval_threshold(env) > 1 {
+            return Err(ContractError::Unauthorized);
+        }
+        Self::store_review_policy(env, project_id, policy, admin)
+    }
+
+    /// Admin-only: remove a project's override so the global policy applies.
+    /// Same threshold rule as `set_review_policy`.
+    pub fn clear_project_review_policy(
+        env: &Env,
+        admin: Address,
+        project_id: u64,
+    ) -> Result<(), ContractError> {
+        crate::auth::require_admin_auth(env, &admin)?;
+        if crate::admin_manager::AdminManager::get_admin_approval_threshold(env) > 1 {
+            return Err(ContractError::Unauthorized);
+        }
+        Self::remove_project_review_policy(env, project_id, admin)
+    }
+
+    /// Validate and persist a review policy. Shared by the direct admin setter
+    /// and governance proposal execution; callers check authorization.
+    pub(crate) fn store_review_policy(
+        env: &Env,
+        project_id: Option<u64>,
+        policy: ReviewPolicy,
+        changed_by: Address,
+    ) -> Result<(), ContractError> {
+        if policy.max_revisions == 0
+            || policy.max_revisions > MAX_REVIEW_REVISIONS
+            || policy.update_cooldown_seconds > MAX_REVIEW_POLICY_PERIOD_SECONDS
+            || policy.edit_window_seconds > MAX_REVIEW_POLICY_PERIOD_SECONDS
+        {
+            return Err(ContractError::InvalidInput);
+        }
+
+        match project_id {
+            Some(id) => {
+                if ProjectRegistry::get_project(env, id).is_none() {
+                    return Err(ContractError::ProjectNotFound);
+                }
+                env.storage()
+                    .persistent()
+                    .set(&FeatureKey::ProjectReviewPolicy(id), &policy);
+            }
+            None => env
+                .storage()
+                .persistent()
+                .set(&FeatureKey::ReviewPolicy, &policy),
+        }
+
+        AdminActionLog::record_action(
+            env,
+            changed_by.clone(),
+            AdminActionType::ReviewPolicyChanged,
+            project_id,
+            None,
+            None,
+        );
+        crate::events::publish_review_policy_changed_event(env, project_id, policy, changed_by);
+        Ok(())
+    }
+
+    pub(crate) fn remove_project_review_policy(
+        env: &Env,
+        project_id: u64,
+        changed_by: Address,
+    ) -> Result<(), ContractError> {
+        if ProjectRegistry::get_project(env, project_id).is_none() {
+            return Err(ContractError::ProjectNotFound);
+        }
+        env.storage()
+            .persistent()
+            .remove(&FeatureKey::ProjectReviewPolicy(project_id));
+
+        AdminActionLog::record_action(
+            env,
+            changed_by.clone(),
+            AdminActionType::ReviewPolicyChanged,
+            Some(project_id),
+            None,
+            None,
+        );
+        crate::events::publish_review_policy_changed_event(
+            env,
+            Some(project_id),
+            Self::get_global_review_policy(env),
+            changed_by,
+        );
+        Ok(())
+    }
+
     /// Record the first-interaction timestamp for an address if not yet set.
     /// Called automatically whenever an address performs an action that should
     /// count toward the "minimum account age" eligibility check.
@@ -103,11 +238,11 @@ impl ReviewRegistry {
             }
         }
 
-        // 3. Review fee check
-        if config.review_fee > 0 {
-            if !crate::fee_manager::FeeManager::is_fee_paid(env, project_id) {
-                return Err(ContractError::ReviewFeeRequired);
-            }
+        // 3. Review fee check, priced in the default token or any accepted one
+        if FeeManager::is_fee_required(env, FeeOperation::Review)
+            && FeeManager::get_review_fee_payment(env, project_id, reviewer).is_none()
+        {
+            return Err(ContractError::ReviewFeeRequired);
         }
 
         Ok(())
@@ -126,6 +261,33 @@ impl ReviewRegistry {
 
         // Validation phase
         reviewer.require_auth();
+        Self::check_can_review(env, project_id, &reviewer)?;
+
+        // Projects running a commit-reveal round only accept reviews through it
+        if ReviewRoundRegistry::is_round_open(env, project_id) {
+            return Err(ContractError::ReviewRoundActive);
+        }
+
+        ReviewValidation::validate_rating(rating)?;
+
+        // Record first interaction for account-age tracking
+        Self::record_first_interaction(env, &reviewer);
+
+        // Mutation phase
+        FeeManager::consume_review_fee_payment(env, project_id, &reviewer);
+        Self::insert_review(env, project_id, reviewer, rating, comment_cid);
+        Ok(())
+    }
+
+    /// Checks shared by `add_review` and `commit_review`: the reviewer is not
+    /// blocked, the project exists, accepts reviews and is not owned by the
+    /// reviewer, the eligibility rules pass, and no review exists yet.
+    pub(crate) fn check_can_review(
+        env: &Env,
+        project_id: u64,
+        reviewer: &Address,
+    ) -> Result<(), ContractError> {
+        Blocklist::require_not_blocked(env, reviewer)?;
 
         // Check if project exists
         let project = match ProjectRegistry::get_project(env, project_id) {
@@ -134,45 +296,35 @@ impl ReviewRegistry {
         };
 
         // Project owners cannot review their own project
-        ReviewValidation::ensure_not_owner(&project, &reviewer)?;
+        ReviewValidation::ensure_not_owner(&project, reviewer)?;
 
         // Check if reviews are enabled for this project
         if !Self::get_reviews_enabled(env, project_id) {
             return Err(ContractError::ReviewsDisabled);
         }
 
-        ReviewValidation::validate_rating(rating)?;
-
         // Anti-sybil eligibility check
-        Self::check_review_eligibility(env, project_id, &reviewer)?;
-
-        let review_key = StorageKey::Review(project_id, reviewer.clone());
-        if env.storage().persistent().has(&review_key) {
-            return Err(ContractError::DuplicateReview);
-        }
+        Self::check_review_eligibility(env, project_id, reviewer)?;
 
-        let user_reviews: Vec<u64> = env
+        if env
             .storage()
             .persistent()
-            .get(&StorageKey::UserReviews(reviewer.clone()))
-            .unwrap_or_else(|| Vec::new(env));
-        let project_reviews: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::ProjectReviews(project_id))
-            .unwrap_or_else(|| Vec::new(env));
-
-        if project_reviews.len() >= MAX_REVIEWS_PER_PROJECT {
-            return Err(ContractError::MaxProjectsExceeded);
-        }
-        if user_reviews.len() >= MAX_REVIEWS_PER_USER {
-            return Err(ContractError::MaxProjectsExceeded);
+            .has(&StorageKey::Review(project_id, reviewer.clone()))
+        {
+            return Err(ContractError::DuplicateReview);
         }
+        Ok(())
+    }
 
-        // Record first interaction for account-age tracking
-        Self::record_first_interaction(env, &reviewer);
-
-        // Mutation phase
+    /// Store a new, already-validated review and fold it into the project's
+    /// stats, indexes and reputation aggregates.
+    pub(crate) fn insert_review(
+        env: &Env,
+        project_id: u64,
+        reviewer: Address,
+        rating: u32,
+        comment_cid: Option<String>,
+    ) {
         let now = env.ledger().timestamp();
         let review = Review {
             project_id,
@@ -184,11 +336,10 @@ impl ReviewRegistry {
             updated_at: now,
             hidden: false,
             report_count: 0,
+            verified_user: false,
         };
 
         // Get current state for mutations
-        let mut user_reviews = user_reviews;
-        let mut project_reviews = project_reviews;
         let stats: ProjectStats = env
             .storage()
             .persistent()
@@ -204,17 +355,13 @@ impl ReviewRegistry {
             RatingCalculator::add_rating(stats.rating_sum, stats.review_count, rating);
 
         // Perform all storage mutations
-        env.storage().persistent().set(&review_key, &review);
-
-        user_reviews.push_back(project_id);
         env.storage()
             .persistent()
-            .set(&StorageKey::UserReviews(reviewer.clone()), &user_reviews);
+            .set(&StorageKey::Review(project_id, reviewer.clone()), &review);
 
-        project_reviews.push_back(reviewer.clone());
-        env.storage()
-            .persistent()
-            .set(&StorageKey::ProjectReviews(project_id), &project_reviews);
+        ReviewIndex::add_user_project(env, &reviewer, project_id);
+        ReviewIndex::add_project_reviewer(env, project_id, &reviewer);
+        ReviewIndex::remove_user_deleted(env, &reviewer, project_id);
 
         env.storage().persistent().set(
             &StorageKey::ProjectStats(project_id),
@@ -224,11 +371,10 @@ impl ReviewRegistry {
                 average_rating: new_avg,
             },
         );
+        ReputationRegistry::on_review_added(env, project_id, &reviewer, rating);
 
         // Extend TTL for review-related data
         StorageManager::extend_review_ttl(env, project_id, &reviewer);
-        StorageManager::extend_user_reviews_ttl(env, &reviewer);
-        StorageManager::extend_project_reviews_ttl(env, project_id);
         StorageManager::extend_project_stats_ttl(env, project_id);
 
         publish_review_event(
@@ -236,12 +382,11 @@ impl ReviewRegistry {
             project_id,
             reviewer,
             ReviewAction::Submitted,
-            comment_cid.clone(),
+            comment_cid,
             None,
             now,
             now,
         );
-        Ok(())
     }
 
     pub fn submit_review(
@@ -277,29 +422,37 @@ impl ReviewRegistry {
         ReviewValidation::validate_rating(rating)?;
 
         let review_key = StorageKey::Review(project_id, reviewer.clone());
-        let mut review: Review = env
-            .storage()
-            .persistent()
-            .get(&review_key)
-            .ok_or(ContractError::ReviewNotFound)?;
+        let mut review: Review =
+            Self::load_review(env, &review_key).ok_or(ContractError::ReviewNotFound)?;
 
         if review.reviewer != reviewer {
             return Err(ContractError::NotReviewOwner);
         }
 
-        // Cooldown: reject update if within REVIEW_UPDATE_COOLDOWN_SECONDS of the last update.
-        // Note: ContractError::InvalidStatus (9) is reused as the cooldown error because
-        // Soroban SDK 22 #[contracterror] is limited to 50 variants and this enum is full.
-        // A dedicated ReviewCooldownActive variant should be introduced when a variant slot
-        // is freed in a future refactor.
+        let policy = Self::get_review_policy(env, project_id);
+        let now_ts = env.ledger().timestamp();
+
+        if policy.edit_window_seconds > 0
+            && now_ts.saturating_sub(review.created_at) > policy.edit_window_seconds
+        {
+            return Err(ContractError::ReviewEditWindowClosed);
+        }
+
+        // Cooldown: reject update if within the policy's cooldown of the last update.
         let cooldown_key = ExtensionKey::ReviewLastUpdated(project_id, reviewer.clone());
         if let Some(last_updated_at) = env.storage().persistent().get::<_, u64>(&cooldown_key) {
-            let now_ts = env.ledger().timestamp();
-            if now_ts.saturating_sub(last_updated_at) < REVIEW_UPDATE_COOLDOWN_SECONDS {
-                return Err(ContractError::InvalidStatus);
+            if now_ts.saturating_sub(last_updated_at) < policy.update_cooldown_seconds {
+                return Err(ContractError::ReviewCooldownActive);
             }
         }
 
+        if policy.new_cid_on_rating_change
+            && rating != review.rating
+            && comment_cid == review.content_cid
+        {
+            return Err(ContractError::ReviewContentUnchanged);
+        }
+
         // Mutation phase — archive prior revision before applying changes
         let old_rating = review.rating;
         let old_content_cid = review.content_cid.clone();
@@ -311,6 +464,7 @@ impl ReviewRegistry {
             old_rating,
             old_content_cid.clone(),
             now,
+            policy.max_revisions,
         );
 
         review.rating = rating;
@@ -346,6 +500,9 @@ impl ReviewRegistry {
                 average_rating: new_avg,
             },
         );
+        if !review.hidden {
+            ReputationRegistry::on_review_updated(env, project_id, &reviewer, old_rating, rating);
+        }
 
         // Record the update timestamp for cooldown enforcement on subsequent updates.
         env.storage().persistent().set(
@@ -384,11 +541,12 @@ impl ReviewRegistry {
         rating: u32,
         content_cid: Option<String>,
         revised_at: u64,
+        max_revisions: u32,
     ) -> u32 {
         let count_key = ExtensionKey::ReviewRevisionCount(project_id, reviewer.clone());
         let revision_count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
 
-        if revision_count < MAX_REVIEW_REVISIONS {
+        if revision_count < max_revisions {
             env.storage().persistent().set(
                 &ExtensionKey::ReviewRevision(project_id, reviewer.clone(), revision_count),
                 &ReviewRevision {
@@ -402,8 +560,8 @@ impl ReviewRegistry {
             env.storage().persistent().set(&count_key, &new_count);
             revision_count
         } else {
-            let start_idx = revision_count.saturating_sub(MAX_REVIEW_REVISIONS - 1);
-            for j in 0..(MAX_REVIEW_REVISIONS - 1) {
+            let start_idx = revision_count.saturating_sub(max_revisions - 1);
+            for j in 0..(max_revisions - 1) {
                 let src_idx = start_idx + j;
                 let from_key = ExtensionKey::ReviewRevision(project_id, reviewer.clone(), src_idx);
                 if let Some(mut rev) = env.storage().persistent().get::<_, ReviewRevision>(&from_key) {
@@ -413,7 +571,7 @@ impl ReviewRegistry {
                 }
             }
 
-            let new_index = MAX_REVIEW_REVISIONS - 1;
+            let new_index = max_revisions - 1;
             env.storage().persistent().set(
                 &ExtensionKey::ReviewRevision(project_id, reviewer.clone(), new_index),
                 &ReviewRevision {
@@ -423,10 +581,10 @@ impl ReviewRegistry {
                     revised_at,
                 },
             );
-            env.storage().persistent().set(&count_key, &MAX_REVIEW_REVISIONS);
+            env.storage().persistent().set(&count_key, &max_revisions);
 
-            if revision_count > MAX_REVIEW_REVISIONS {
-                for i in MAX_REVIEW_REVISIONS..revision_count {
+            if revision_count > max_revisions {
+                for i in max_revisions..revision_count {
                     env.storage().persistent().remove(&ExtensionKey::ReviewRevision(
                         project_id,
                         reviewer.clone(),
@@ -503,11 +661,8 @@ impl ReviewRegistry {
         }
 
         let review_key = StorageKey::Review(project_id, reviewer.clone());
-        let existing: Review = env
-            .storage()
-            .persistent()
-            .get(&review_key)
-            .ok_or(ContractError::ReviewNotFound)?;
+        let existing: Review =
+            Self::load_review(env, &review_key).ok_or(ContractError::ReviewNotFound)?;
 
         if existing.reviewer != reviewer {
             return Err(ContractError::NotReviewOwner);
@@ -524,16 +679,6 @@ impl ReviewRegistry {
                 review_count: 0,
                 average_rating: 0,
             });
-        let user_reviews: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::UserReviews(reviewer.clone()))
-            .unwrap_or_else(|| Vec::new(env));
-        let project_reviews: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::ProjectReviews(project_id))
-            .unwrap_or_else(|| Vec::new(env));
 
         // Calculate new stats
         let (new_sum, new_count, new_avg) = if stats.review_count > 0 {
@@ -542,28 +687,10 @@ impl ReviewRegistry {
             (stats.rating_sum, stats.review_count, stats.average_rating)
         };
 
-        // Create new user reviews list
-        let mut new_user_reviews = Vec::new(env);
-        for i in 0..user_reviews.len() {
-            if let Some(id) = user_reviews.get(i) {
-                if id != project_id {
-                    new_user_reviews.push_back(id);
-                }
-            }
-        }
-
-        // Create new project reviews list
-        let mut new_project_reviews = Vec::new(env);
-        for i in 0..project_reviews.len() {
-            if let Some(addr) = project_reviews.get(i) {
-                if addr != reviewer {
-                    new_project_reviews.push_back(addr);
-                }
-            }
-        }
-
         // Perform all mutations
         env.storage().persistent().remove(&review_key);
+        let proof_key = ExtensionKey::ReviewInteractionProof(project_id, reviewer.clone());
+        env.storage().persistent().remove(&proof_key);
         // Store a tombstone so indexers can distinguish deleted vs never-existed.
         let now = env.ledger().timestamp();
         env.storage().persistent().set(
@@ -582,14 +709,20 @@ impl ReviewRegistry {
                 average_rating: new_avg,
             },
         );
-        env.storage().persistent().set(
-            &StorageKey::UserReviews(reviewer.clone()),
-            &new_user_reviews,
-        );
-        env.storage().persistent().set(
-            &StorageKey::ProjectReviews(project_id),
-            &new_project_reviews,
-        );
+        ReviewIndex::remove_user_project(env, &reviewer, project_id);
+        ReviewIndex::remove_project_reviewer(env, project_id, &reviewer);
+        ReviewIndex::add_user_deleted(env, &reviewer, project_id);
+        if existing.hidden {
+            ReputationRegistry::forget_review_weight(env, project_id, &reviewer);
+        } else {
+            ReputationRegistry::on_review_removed(
+                env,
+                project_id,
+                &reviewer,
+                existing.rating,
+                true,
+            );
+        }
 
         // Clean up any ReviewReport dedup keys for this review so that
         // the storage doesn't accumulate dangling report entries after deletion.
@@ -636,13 +769,11 @@ impl ReviewRegistry {
         if ProjectRegistry::get_project(env, project_id).is_none() {
             return Err(ContractError::ProjectNotFound);
         }
+        ConflictRegistry::require_no_conflict(env, project_id, &admin)?;
 
         let review_key = StorageKey::Review(project_id, reviewer.clone());
-        let existing: Review = env
-            .storage()
-            .persistent()
-            .get(&review_key)
-            .ok_or(ContractError::ReviewNotFound)?;
+        let existing: Review =
+            Self::load_review(env, &review_key).ok_or(ContractError::ReviewNotFound)?;
 
         // Mutation phase — same index/stats cleanup as delete_review
         let stats: ProjectStats = env
@@ -654,16 +785,6 @@ impl ReviewRegistry {
                 review_count: 0,
                 average_rating: 0,
             });
-        let user_reviews: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::UserReviews(reviewer.clone()))
-            .unwrap_or_else(|| Vec::new(env));
-        let project_reviews: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::ProjectReviews(project_id))
-            .unwrap_or_else(|| Vec::new(env));
 
         // Recalculate stats — exclude hidden reviews that were already excluded
         let (new_sum, new_count, new_avg) = if stats.review_count > 0 && !existing.hidden {
@@ -672,28 +793,10 @@ impl ReviewRegistry {
             (stats.rating_sum, stats.review_count, stats.average_rating)
         };
 
-        // Rebuild user reviews list without this project
-        let mut new_user_reviews = Vec::new(env);
-        for i in 0..user_reviews.len() {
-            if let Some(id) = user_reviews.get(i) {
-                if id != project_id {
-                    new_user_reviews.push_back(id);
-                }
-            }
-        }
-
-        // Rebuild project reviews list without this reviewer
-        let mut new_project_reviews = Vec::new(env);
-        for i in 0..project_reviews.len() {
-            if let Some(addr) = project_reviews.get(i) {
-                if addr != reviewer {
-                    new_project_reviews.push_back(addr);
-                }
-            }
-        }
-
         // Apply all mutations
         env.storage().persistent().remove(&review_key);
+        let proof_key = ExtensionKey::ReviewInteractionProof(project_id, reviewer.clone());
+        env.storage().persistent().remove(&proof_key);
         // Store a tombstone so indexers can distinguish deleted vs never-existed.
         let now = env.ledger().timestamp();
         env.storage().persistent().set(
@@ -712,14 +815,21 @@ impl ReviewRegistry {
                 average_rating: new_avg,
             },
         );
-        env.storage().persistent().set(
-            &StorageKey::UserReviews(reviewer.clone()),
-            &new_user_reviews,
-        );
-        env.storage().persistent().set(
-            &StorageKey::ProjectReviews(project_id),
-            &new_project_reviews,
-        );
+        ReviewIndex::remove_user_project(env, &reviewer, project_id);
+        ReviewIndex::remove_project_reviewer(env, project_id, &reviewer);
+        ReviewIndex::add_user_deleted(env, &reviewer, project_id);
+        if existing.hidden {
+            ReputationRegistry::forget_review_weight(env, project_id, &reviewer);
+        } else {
+            ReputationRegistry::on_review_removed(
+                env,
+                project_id,
+                &reviewer,
+                existing.rating,
+                true,
+            );
+        }
+        ReputationRegistry::record_review_deleted_by_admin(env, &reviewer, existing.hidden);
 
         crate::events::publish_review_deleted_by_admin_event(
             env,
@@ -774,11 +884,8 @@ impl ReviewRegistry {
         }
 
         let review_key = StorageKey::Review(project_id, reviewer.clone());
-        let mut review: Review = env
-            .storage()
-            .persistent()
-            .get(&review_key)
-            .ok_or(ContractError::ReviewNotFound)?;
+        let mut review: Review =
+            Self::load_review(env, &review_key).ok_or(ContractError::ReviewNotFound)?;
 
         // Mutation phase
         let now = env.ledger().timestamp();
@@ -805,32 +912,46 @@ impl ReviewRegistry {
     }
 
     pub fn get_review(env: &Env, project_id: u64, reviewer: Address) -> Option<Review> {
-        env.storage()
-            .persistent()
-            .get(&StorageKey::Review(project_id, reviewer))
+        Self::load_review(env, &StorageKey::Review(project_id, reviewer))
+    }
+
+    /// Read a review, including one stored before `verified_user` existed.
+    fn load_review(env: &Env, key: &StorageKey) -> Option<Review> {
+        get_persistent::<_, Review, LegacyReview>(env, key, "verified_user")
     }
 
     pub fn get_review_cid(env: &Env, project_id: u64, reviewer: Address) -> Option<String> {
         Self::get_review(env, project_id, reviewer).and_then(|review| review.content_cid)
     }
 
+    /// Content CIDs of the reviews in the first `MAX_REVIEW_SCAN_PAGES` pages
+    /// of the project's index. Use `list_reviews` to reach later reviews.
     pub fn get_project_review_cids(env: &Env, project_id: u64) -> Vec<(Address, String)> {
-        let reviewers: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::ProjectReviews(project_id))
-            .unwrap_or_else(|| Vec::new(env));
-
         let mut cids = Vec::new(env);
-        let len = reviewers.len();
-        for i in 0..len {
-            if let Some(reviewer) = reviewers.get(i) {
-                if let Some(cid) = Self::get_review_cid(env, project_id, reviewer.clone()) {
-                    cids.push_back((reviewer, cid));
+        Self::for_each_project_review(env, project_id, |review| {
+            if let Some(cid) = review.content_cid {
+                cids.push_back((review.reviewer, cid));
+            }
+        });
+        cids
+    }
+
+    /// Visit the reviews in the first `MAX_REVIEW_SCAN_PAGES` pages of a
+    /// project's reviewer index, reading one page at a time.
+    fn for_each_project_review(env: &Env, project_id: u64, mut visit: impl FnMut(Review)) {
+        let count = ReviewIndex::project_len(env, project_id)
+            .min(MAX_REVIEW_SCAN_PAGES * REVIEW_INDEX_PAGE_SIZE);
+        let mut start = 0;
+        while start < count {
+            let reviewers =
+                ReviewIndex::project_reviewers(env, project_id, start, REVIEW_INDEX_PAGE_SIZE);
+            for reviewer in reviewers.iter() {
+                if let Some(review) = Self::get_review(env, project_id, reviewer) {
+                    visit(review);
                 }
             }
+            start += REVIEW_INDEX_PAGE_SIZE;
         }
-        cids
     }
 
     pub fn get_project_stats(env: &Env, project_id: u64) -> ProjectStats {
@@ -865,20 +986,11 @@ impl ReviewRegistry {
             limit
         };
 
-        let reviewers: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::ProjectReviews(project_id))
-            .unwrap_or_else(|| Vec::new(env));
+        let reviewers =
+            ReviewIndex::project_reviewers(env, project_id, start_index, effective_limit);
 
         let mut reviews = Vec::new(env);
-        let len = reviewers.len();
-        if start_index >= len {
-            return reviews;
-        }
-        let end = core::cmp::min(start_index.saturating_add(effective_limit), len);
-
-        for i in start_index..end {
+        for i in 0..reviewers.len() {
             if let Some(reviewer) = reviewers.get(i) {
                 if let Some(review) = Self::get_review(env, project_id, reviewer) {
                     // Exclude hidden reviews from default listings
@@ -935,6 +1047,7 @@ impl ReviewRegistry {
     ) -> Result<(), ContractError> {
         // Validation phase
         reporter.require_auth();
+        Blocklist::require_not_blocked(env, &reporter)?;
 
         // Check if project exists
         if ProjectRegistry::get_project(env, project_id).is_none() {
@@ -942,11 +1055,8 @@ impl ReviewRegistry {
         }
 
         let review_key = StorageKey::Review(project_id, reviewer.clone());
-        let mut review: Review = env
-            .storage()
-            .persistent()
-            .get(&review_key)
-            .ok_or(ContractError::ReviewNotFound)?;
+        let mut review: Review =
+            Self::load_review(env, &review_key).ok_or(ContractError::ReviewNotFound)?;
 
         // Check if reporter has already reported this review
         let report_key = StorageKey::ReviewReport(project_id, reviewer.clone(), reporter.clone());
@@ -987,21 +1097,44 @@ impl ReviewRegistry {
         if ProjectRegistry::get_project(env, project_id).is_none() {
             return Err(ContractError::ProjectNotFound);
         }
+        ConflictRegistry::require_no_conflict(env, project_id, &admin)?;
 
         let review_key = StorageKey::Review(project_id, reviewer.clone());
-        let mut review: Review = env
-            .storage()
-            .persistent()
-            .get(&review_key)
-            .ok_or(ContractError::ReviewNotFound)?;
+        let review: Review =
+            Self::load_review(env, &review_key).ok_or(ContractError::ReviewNotFound)?;
 
         if review.hidden {
             return Err(ContractError::ReviewAlreadyHidden);
         }
 
         // Mutation phase
+        Self::apply_hide(env, project_id, &reviewer, &admin, review);
+
+        AdminActionLog::record_action(
+            env,
+            admin,
+            AdminActionType::ReviewHidden,
+            Some(project_id),
+            Some(reviewer),
+            None,
+        );
+
+        Ok(())
+    }
+
+    /// Hide a loaded, currently visible review: exclude it from stats and the
+    /// reputation-weighted aggregate, then emit the hidden event.
+    fn apply_hide(
+        env: &Env,
+        project_id: u64,
+        reviewer: &Address,
+        admin: &Address,
+        mut review: Review,
+    ) {
         review.hidden = true;
-        env.storage().persistent().set(&review_key, &review);
+        env.storage()
+            .persistent()
+            .set(&StorageKey::Review(project_id, reviewer.clone()), &review);
 
         // Update project stats to exclude this review
         let stats: ProjectStats = env
@@ -1030,8 +1163,11 @@ impl ReviewRegistry {
             },
         );
 
+        ReputationRegistry::on_review_removed(env, project_id, reviewer, review.rating, false);
+        ReputationRegistry::record_review_hidden(env, reviewer);
+
         // Extend TTL
-        StorageManager::extend_review_ttl(env, project_id, &reviewer);
+        StorageManager::extend_review_ttl(env, project_id, reviewer);
         StorageManager::extend_project_stats_ttl(env, project_id);
 
         crate::events::publish_review_hidden_event(
@@ -1040,17 +1176,68 @@ impl ReviewRegistry {
             reviewer.clone(),
             admin.clone(),
         );
+    }
+
+    /// Admin: hide every visible review written by a blocked address.
+    ///
+    /// Walks the reviewer's project index from `start_index`, covering at most
+    /// `limit` entries (clamped to `MAX_PAGE_LIMIT`). Hiding does not remove
+    /// entries from the index, so callers page through by advancing
+    /// `start_index` by `limit` until it reaches the reviewer's review count.
+    /// Reviews of projects the admin has a conflict of interest in are left
+    /// for another admin. Returns the number of reviews hidden by this call.
+    pub fn hide_reviews_by_reviewer(
+        env: &Env,
+        admin: Address,
+        reviewer: Address,
+        start_index: u32,
+        limit: u32,
+    ) -> Result<u32, ContractError> {
+        crate::auth::require_admin_auth(env, &admin)?;
+
+        if !Blocklist::is_blocked(env, &reviewer) {
+            return Err(ContractError::AddressNotBlocked);
+        }
+
+        let effective_limit = if limit == 0 || limit > MAX_PAGE_LIMIT {
+            MAX_PAGE_LIMIT
+        } else {
+            limit
+        };
+
+        let project_ids = ReviewIndex::user_projects(env, &reviewer, start_index, effective_limit);
+        let mut hidden = 0u32;
+        for i in 0..project_ids.len() {
+            if let Some(project_id) = project_ids.get(i) {
+                if ConflictRegistry::has_conflict(env, project_id, &admin) {
+                    continue;
+                }
+                if let Some(review) = Self::get_review(env, project_id, reviewer.clone()) {
+                    if !review.hidden {
+                        Self::apply_hide(env, project_id, &reviewer, &admin, review);
+                        hidden += 1;
+                    }
+                }
+            }
+        }
+
+        crate::events::publish_reviews_bulk_hidden_event(
+            env,
+            reviewer.clone(),
+            admin.clone(),
+            hidden,
+        );
 
         AdminActionLog::record_action(
             env,
             admin,
-            AdminActionType::ReviewHidden,
-            Some(project_id),
+            AdminActionType::ReviewsHiddenByReviewer,
+            None,
             Some(reviewer),
             None,
         );
 
-        Ok(())
+        Ok(hidden)
     }
 
     pub fn restore_review(
@@ -1071,13 +1258,11 @@ impl ReviewRegistry {
         if ProjectRegistry::get_project(env, project_id).is_none() {
             return Err(ContractError::ProjectNotFound);
         }
+        ConflictRegistry::require_no_conflict(env, project_id, &admin)?;
 
         let review_key = StorageKey::Review(project_id, reviewer.clone());
-        let mut review: Review = env
-            .storage()
-            .persistent()
-            .get(&review_key)
-            .ok_or(ContractError::ReviewNotFound)?;
+        let mut review: Review =
+            Self::load_review(env, &review_key).ok_or(ContractError::ReviewNotFound)?;
 
         if !review.hidden {
             return Err(ContractError::ReviewNotHidden);
@@ -1111,6 +1296,9 @@ impl ReviewRegistry {
             },
         );
 
+        ReputationRegistry::on_review_restored(env, project_id, &reviewer, review.rating);
+        ReputationRegistry::record_review_restored(env, &reviewer);
+
         // Extend TTL
         StorageManager::extend_review_ttl(env, project_id, &reviewer);
         StorageManager::extend_project_stats_ttl(env, project_id);
@@ -1146,20 +1334,87 @@ impl ReviewRegistry {
             .get(&ExtensionKey::ReviewTombstone(project_id, reviewer))
     }
 
+    /// Page through everything a reviewer has written: live reviews first
+    /// (hidden ones as `ReviewRecord::Hidden`), then deleted reviews as their
+    /// tombstone.
+    ///
+    /// `cursor` starts at 0; pass the returned `next_cursor` to continue.
+    /// `limit` is clamped to `MAX_PAGE_LIMIT`. Reviews deleted before the
+    /// deleted-review index existed are not listed.
+    ///
+    /// The cursor is a position in the reviewer's indexes, so it is only
+    /// consistent while the record does not change. Deleting a review moves
+    /// the reviewer's last entry into its slot and adds a tombstone, so an
+    /// entry may be skipped or returned twice if the record changes between
+    /// pages. Callers that need an exact listing should read every page in
+    /// the same ledger, or restart when the record changes, and deduplicate by
+    /// project ID.
+    pub fn list_reviews_by_reviewer(
+        env: &Env,
+        reviewer: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> ReviewerReviewPage {
+        let effective_limit = if limit == 0 || limit > MAX_PAGE_LIMIT {
+            MAX_PAGE_LIMIT
+        } else {
+            limit
+        };
+
+        let live_len = ReviewIndex::user_len(env, &reviewer);
+        let total = live_len.saturating_add(ReviewIndex::user_deleted_len(env, &reviewer));
+        let end = core::cmp::min(cursor.saturating_add(effective_limit), total);
+
+        let mut entries = Vec::new(env);
+        if cursor < live_len {
+            let live_end = core::cmp::min(end, live_len);
+            let project_ids = ReviewIndex::user_projects(env, &reviewer, cursor, live_end - cursor);
+            for project_id in project_ids.iter() {
+                if let Some(review) = Self::get_review(env, project_id, reviewer.clone()) {
+                    entries.push_back(if review.hidden {
+                        ReviewRecord::Hidden(review)
+                    } else {
+                        ReviewRecord::Active(review)
+                    });
+                }
+            }
+        }
+        if end > live_len {
+            let start = core::cmp::max(cursor, live_len) - live_len;
+            let project_ids =
+                ReviewIndex::user_deleted_projects(env, &reviewer, start, end - live_len - start);
+            for project_id in project_ids.iter() {
+                if let Some(tombstone) =
+                    Self::get_review_tombstone(env, project_id, reviewer.clone())
+                {
+                    entries.push_back(ReviewRecord::Deleted(tombstone));
+                }
+            }
+        }
+
+        ReviewerReviewPage {
+            entries,
+            next_cursor: if end < total { Some(end) } else { None },
+        }
+    }
+
     /// List reviews sorted by the requested `sort_mode` with pagination.
     ///
     /// # On-chain in-memory sort
-    /// This fetches all non-hidden reviews for the project, sorts them entirely
-    /// in the contract's working memory, then applies pagination. For projects
-    /// with many reviews this increases compute budget usage linearly with the
-    /// total review count. Use `list_reviews` (insertion-order) when sorting is
-    /// not required.
+    /// This fetches the non-hidden reviews in the first `MAX_REVIEW_SCAN_PAGES`
+    /// pages of the project's index, sorts them in the contract's working
+    /// memory, then applies pagination. Reviews beyond those pages are not
+    /// sorted in; use `list_reviews` (index order) to reach them.
+    ///
+    /// When `verified_only` is set, only reviews carrying a verified-interaction
+    /// badge are returned.
     pub fn list_reviews_sorted(
         env: &Env,
         project_id: u64,
         start_index: u32,
         limit: u32,
         sort_mode: ReviewSortMode,
+        verified_only: bool,
     ) -> Vec<Review> {
         let effective_limit = if limit == 0 || limit > MAX_PAGE_LIMIT {
             MAX_PAGE_LIMIT
@@ -1167,23 +1422,13 @@ impl ReviewRegistry {
             limit
         };
 
-        let reviewers: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::ProjectReviews(project_id))
-            .unwrap_or_else(|| Vec::new(env));
-
         // Collect all non-hidden reviews.
         let mut all: Vec<Review> = Vec::new(env);
-        for i in 0..reviewers.len() {
-            if let Some(reviewer) = reviewers.get(i) {
-                if let Some(review) = Self::get_review(env, project_id, reviewer) {
-                    if !review.hidden {
-                        all.push_back(review);
-                    }
-                }
+        Self::for_each_project_review(env, project_id, |review| {
+            if !review.hidden && (!verified_only || review.verified_user) {
+                all.push_back(review);
             }
-        }
+        });
 
         // Sort in-memory by the requested mode.
         Utils::bubble_sort_by(&mut all, |a, b| match sort_mode {
@@ -1191,6 +1436,10 @@ impl ReviewRegistry {
             ReviewSortMode::Oldest => a.created_at > b.created_at,
             ReviewSortMode::RatingHigh => a.rating < b.rating,
             ReviewSortMode::RatingLow => a.rating > b.rating,
+            ReviewSortMode::VerifiedFirst => {
+                (!a.verified_user && b.verified_user)
+                    || (a.verified_user == b.verified_user && a.created_at < b.created_at)
+            }
         });
         let n = all.len();
 
@@ -1207,4 +1456,161 @@ impl ReviewRegistry {
         }
         out
     }
+
+    // ── Verified-interaction badges ─────────────────────────────────────
+
+    /// Attach an interaction proof to an existing review, marking it as coming
+    /// from a verified user.
+    ///
+    /// Both the reviewer and the attestor must authorize the call. The attestor
+    /// must be one of the project's approved contract claims, or an address on
+    /// the admin-managed trusted attestor list. The project itself must have at
+    /// least one verified contract.
+    pub fn attach_interaction_proof(
+        env: &Env,
+        project_id: u64,
+        reviewer: Address,
+        attestor: Address,
+    ) -> Result<(), ContractError> {
+        reviewer.require_auth();
+        attestor.require_auth();
+
+        if ProjectRegistry::get_project(env, project_id).is_none() {
+            return Err(ContractError::ProjectNotFound);
+        }
+
+        let review_key = StorageKey::Review(project_id, reviewer.clone());
+        let mut review: Review =
+            Self::load_review(env, &review_key).ok_or(ContractError::ReviewNotFound)?;
+
+        let contracts = ProjectRegistry::get_verified_contracts(env, project_id);
+        if contracts.is_empty() {
+            return Err(ContractError::NoVerifiedContracts);
+        }
+
+        let attestor_str = attestor.to_string();
+        let mut from_project_contract = false;
+        for i in 0..contracts.len() {
+            if contracts.get(i) == Some(attestor_str.clone()) {
+                from_project_contract = true;
+                break;
+            }
+        }
+        if !from_project_contract && !Self::is_trusted_attestor(env, &attestor) {
+            return Err(ContractError::AttestorNotTrusted);
+        }
+
+        let proof = ReviewInteractionProof {
+            project_id,
+            reviewer: reviewer.clone(),
+            attestor: attestor.clone(),
+            from_project_contract,
+            attested_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(
+            &ExtensionKey::ReviewInteractionProof(project_id, reviewer.clone()),
+            &proof,
+        );
+
+        review.verified_user = true;
+        env.storage().persistent().set(&review_key, &review);
+        StorageManager::extend_review_ttl(env, project_id, &reviewer);
+
+        crate::events::publish_review_interaction_verified_event(
+            env,
+            project_id,
+            reviewer,
+            attestor,
+            from_project_contract,
+        );
+
+        Ok(())
+    }
+
+    /// Get the interaction proof attached to a review, if any.
+    pub fn get_interaction_proof(
+        env: &Env,
+        project_id: u64,
+        reviewer: Address,
+    ) -> Option<ReviewInteractionProof> {
+        env.storage()
+            .persistent()
+            .get(&ExtensionKey::ReviewInteractionProof(project_id, reviewer))
+    }
+
+    /// Admin: add an address to the trusted attestor list.
+    pub fn add_trusted_attestor(
+        env: &Env,
+        admin: Address,
+        attestor: Address,
+    ) -> Result<(), ContractError> {
+        crate::auth::require_admin_auth(env, &admin)?;
+
+        let mut attestors = Self::get_trusted_attestors(env);
+        if attestors.contains(&attestor) {
+            return Ok(()); // already trusted, no-op
+        }
+        attestors.push_back(attestor.clone());
+        env.storage()
+            .persistent()
+            .set(&ExtensionKey::TrustedAttestors, &attestors);
+
+        crate::events::publish_trusted_attestor_event(env, attestor.clone(), admin.clone(), true);
+
+        AdminActionLog::record_action(
+            env,
+            admin,
+            AdminActionType::TrustedAttestorAdded,
+            None,
+            Some(attestor),
+            None,
+        );
+
+        Ok(())
+    }
+
+    /// Admin: remove an address from the trusted attestor list. Proofs that were
+    /// already accepted from this attestor are kept.
+    pub fn remove_trusted_attestor(
+        env: &Env,
+        admin: Address,
+        attestor: Address,
+    ) -> Result<(), ContractError> {
+        crate::auth::require_admin_auth(env, &admin)?;
+
+        let mut attestors = Self::get_trusted_attestors(env);
+        let index = match attestors.first_index_of(&attestor) {
+            Some(index) => index,
+            None => return Ok(()), // not in list, no-op
+        };
+        attestors.remove(index);
+        env.storage()
+            .persistent()
+            .set(&ExtensionKey::TrustedAttestors, &attestors);
+
+        crate::events::publish_trusted_attestor_event(env, attestor.clone(), admin.clone(), false);
+
+        AdminActionLog::record_action(
+            env,
+            admin,
+            AdminActionType::TrustedAttestorRemoved,
+            None,
+            Some(attestor),
+            None,
+        );
+
+        Ok(())
+    }
+
+    /// Get the list of trusted attestors.
+    pub fn get_trusted_attestors(env: &Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&ExtensionKey::TrustedAttestors)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    fn is_trusted_attestor(env: &Env, attestor: &Address) -> bool {
+        Self::get_trusted_attestors(env).contains(attestor)
+    }
 }
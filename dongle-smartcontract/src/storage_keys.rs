@@ -1,6 +1,7 @@
 //! Storage key types for persistent storage. Modular to allow future extensions.
 
-use soroban_sdk::{contracttype, Address, String};
+use crate::events::FeeOperation;
+use soroban_sdk::{contracttype, Address, BytesN, String};
 
 /// Keys for contract storage. Using an enum keeps keys namespaced and avoids collisions.
 #[contracttype]
@@ -183,4 +184,172 @@ pub enum ExtensionKey {
     FirstInteraction(Address),
     ReviewRevisionCount(u64, Address),
     ReviewRevision(u64, Address, u32),
+    /// Reputation signals (helpful votes, moderation counts) for a reviewer.
+    ReviewerSignals(Address),
+    /// Helpful-vote dedup guard: (project_id, reviewer, voter) -> bool.
+    ReviewHelpfulVote(u64, Address, Address),
+    /// Reputation weight snapshotted when a review was submitted (project_id, reviewer).
+    ReviewWeight(u64, Address),
+    /// Reputation-weighted rating aggregates for a project.
+    ReputationWeightedStats(u64),
+    /// Proof of interaction attached to a review (project_id, reviewer).
+    ReviewInteractionProof(u64, Address),
+    /// Admin-managed list of addresses trusted to attest reviewer interactions.
+    TrustedAttestors,
+}
+
+/// Additional storage keys for new features to stay under the 50-variant limit of ExtensionKey.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FeatureKey {
+    /// Number of reviewers in a project's chunked review index.
+    ProjectReviewCount(u64),
+    /// One page of a project's reviewer index (project_id, page).
+    ProjectReviewPage(u64, u32),
+    /// Position of a reviewer in a project's index (project_id, reviewer).
+    ProjectReviewPos(u64, Address),
+    /// Number of projects in a reviewer's chunked review index.
+    UserReviewCount(Address),
+    /// One page of a reviewer's project index (reviewer, page).
+    UserReviewPage(Address, u32),
+    /// Position of a project in a reviewer's index (reviewer, project_id).
+    UserReviewPos(Address, u64),
+    /// Admin blocklist entry for an address.
+    BlockedAddress(Address),
+    /// Latest commit-reveal review round for a project.
+    ReviewRound(u64),
+    /// Sealed review commitment (project_id, reviewer). Temporary storage.
+    ReviewCommitment(u64, Address),
+    /// Revealed review awaiting finalization (project_id, reveal index).
+    RoundReveal(u64, u32),
+    /// Number of projects whose review a reviewer deleted.
+    UserDeletedReviewCount(Address),
+    /// One page of a reviewer's deleted-review index (reviewer, page).
+    UserDeletedReviewPage(Address, u32),
+    /// Position of a project in a reviewer's deleted-review index.
+    UserDeletedReviewPos(Address, u64),
+    /// Global review edit policy.
+    ReviewPolicy,
+    /// Per-project review edit policy override.
+    ProjectReviewPolicy(u64),
+    /// Verification tier definition by tier ID.
+    VerificationTier(u32),
+    /// Number of verification tiers created (IDs run from 1).
+    VerificationTierCount,
+    /// Tiers currently granted to a project (`Vec<VerificationTierGrant>`).
+    ProjectTierGrants(u64),
+    /// Verifier sign-offs required for new verification requests (`u32`).
+    VerificationQuorum,
+    /// Delegated verifier role for an address (`VerifierGrant`).
+    Verifier(Address),
+    /// Addresses holding a verifier role (`Vec<Address>`).
+    VerifierList,
+    /// Reports attached to a verification request (`Vec<VerificationReport>`).
+    VerificationReports(u64),
+    /// Evidence hash and summary committed for a verification request
+    /// (`EvidenceCommitment`).
+    EvidenceCommitment(u64),
+    /// `VerificationAssignmentMode` for new verification requests.
+    VerificationAssignmentMode,
+    /// Index into the verifier list where round-robin assignment resumes.
+    VerificationAssignmentCursor,
+    /// Pending verification requests assigned to an address (`u32`).
+    VerifierLoad(Address),
+    /// Seconds a verification request may wait before it is stale (`u64`, 0 = off).
+    VerificationSla,
+    /// Marks a request whose stale event has been emitted.
+    VerificationStaleFlagged(u64),
+    /// Share of the fee refunded on withdrawal, in basis points (`u32`).
+    VerificationWithdrawalRefundBps,
+    /// Latest suspension of a verification request (`VerificationSuspension`).
+    VerificationSuspension(u64),
+    /// Checklist for requests in a project category (`Vec<ChecklistItem>`).
+    CategoryChecklist(String),
+    /// Checklist for requests for a verification tier (`Vec<ChecklistItem>`).
+    TierChecklist(u32),
+    /// Registered off-chain attestor by public key (`Attestor`).
+    Attestor(BytesN<32>),
+    /// Public keys of all registered attestors (`Vec<BytesN<32>>`).
+    AttestorList,
+    /// Marks an attestor's nonce as used (`bool`).
+    AttestationNonce(BytesN<32>, u64),
+    /// Off-chain attestations accepted for a project (`Vec<ProjectAttestation>`).
+    ProjectAttestations(u64),
+    /// Admins and verifiers recused from a project (`Vec<Address>`).
+    ProjectRecusals(u64),
+    /// Non-empty expiry index buckets in ascending order (`Vec<u64>`).
+    VerificationExpiryBuckets,
+    /// Verifications expiring within one bucket, soonest first
+    /// (`Vec<ExpiringVerification>`).
+    VerificationExpiryBucket(u64),
+    /// Expiry time a project is indexed under (`u64`).
+    VerificationExpiryIndexed(u64),
+    /// Seconds before expiry that renewal reminders start (`u64`, 0 = off).
+    ExpiryReminderWindow,
+    /// Expiry time the project's last renewal reminder was sent for (`u64`).
+    ExpiryReminderSent(u64),
+    /// Retention applied to verification and renewal history
+    /// (`HistoryRetentionPolicy`).
+    HistoryRetentionPolicy,
+    /// Hash chain over a project's pruned verification records (`HistorySummary`).
+    VerificationHistorySummary(u64),
+    /// Hash chain over a project's pruned renewal records (`HistorySummary`).
+    RenewalHistorySummary(u64),
+    /// Index of a project's oldest retained renewal record (`u32`).
+    RenewalHistoryStart(u64),
+    /// Fee amounts for an accepted fee token (`TokenFees`).
+    AcceptedFeeToken(Address),
+    /// Accepted fee tokens besides the default one (`Vec<Address>`).
+    AcceptedFeeTokens,
+    /// Recipients sharing a fee type's revenue (`Vec<TreasurySplit>`).
+    TreasurySplits(FeeOperation),
+}
+
+/// Storage keys for fee features, kept apart to stay under the 50-variant
+/// limit of FeatureKey.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FeeKey {
+    /// Fee escrow mode and rejection refund policy (`FeeEscrowConfig`).
+    FeeEscrowConfig,
+    /// Verification fee held for a project (`EscrowedFee`).
+    FeeEscrow(u64),
+    /// Total fees held in escrow in a token (`u128`).
+    EscrowedTotal(Address),
+    /// Tokens that have ever been held in escrow (`Vec<Address>`).
+    EscrowTokens,
+    /// Seconds a verification fee payment stays valid (`u64`).
+    FeePaymentExpiry,
+    /// When a project's verification fee payment expires, fixed when it is
+    /// paid or re-priced; 0 or absent means it never expires (`u64`).
+    FeePaymentExpiresAt(u64),
+    /// All-time fee revenue of a token and fee type (`FeeRevenueTotals`).
+    RevenueTotal(Address, FeeOperation),
+    /// Fee revenue of a token and fee type in the period starting at the
+    /// given timestamp (`FeeRevenueTotals`).
+    RevenuePeriod(Address, FeeOperation, u64),
+    /// Tokens that have ever been collected as fees (`Vec<Address>`).
+    RevenueTokens,
+    /// Tier a project's verification fee payment was made for, absent for
+    /// payments of the global fee (`u32`).
+    FeePaymentTier(u64),
+    /// A reviewer's unconsumed review fee payment for a project
+    /// (`FeePaymentRecord`).
+    ReviewFeePayment(u64, Address),
+}
+
+/// Storage keys for verification features, kept apart to stay under the
+/// 50-variant limit of FeatureKey.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VerificationKey {
+    /// One page of the pending verification queue: request IDs by sequence
+    /// number, 0 where a request has left the queue (`Vec<u64>`).
+    QueuePage(u32),
+    /// Queue sequence number of a queued request (`u32`).
+    QueuePos(u64),
+    /// Lowest sequence number that may still hold a queued request (`u32`).
+    QueueHead,
+    /// Sequence number given to the next queued request (`u32`).
+    QueueTail,
 }
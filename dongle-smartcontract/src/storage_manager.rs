@@ -4,6 +4,7 @@
 //! critical information persists and doesn't expire unexpectedly.
 
 use crate::constants::*;
+use crate::review_registry::ReviewIndex;
 use crate::storage_keys::{ExtensionKey, StorageKey};
 use soroban_sdk::{Address, Env, IntoVal, String, Val, Vec};
 
@@ -175,14 +176,15 @@ impl StorageManager {
         );
     }
 
-    /// Extend TTL for project reviews list
+    /// Extend TTL for the leading pages of a project's reviewer index
     pub fn extend_project_reviews_ttl(env: &Env, project_id: u64) {
-        Self::extend_if_exists(
-            env,
-            &StorageKey::ProjectReviews(project_id),
-            LEDGER_THRESHOLD_REVIEW,
-            LEDGER_BUMP_REVIEW,
-        );
+        ReviewIndex::extend_project_ttl(env, project_id);
+    }
+
+    /// Extend TTL for the index entries (project and user side) of one review
+    pub fn extend_review_index_ttl(env: &Env, project_id: u64, reviewer: &Address) {
+        ReviewIndex::extend_project_entry_ttl(env, project_id, reviewer);
+        ReviewIndex::extend_user_entry_ttl(env, reviewer, project_id);
     }
 
     // ── Verification Data TTL Management ──────────────────────────────────
@@ -267,14 +269,9 @@ impl StorageManager {
         );
     }
 
-    /// Extend TTL for user reviews list
+    /// Extend TTL for the leading pages of a user's reviewed-projects index
     pub fn extend_user_reviews_ttl(env: &Env, user: &Address) {
-        Self::extend_if_exists(
-            env,
-            &StorageKey::UserReviews(user.clone()),
-            LEDGER_THRESHOLD_USER,
-            LEDGER_BUMP_USER,
-        );
+        ReviewIndex::extend_user_ttl(env, user);
     }
 
     /// Extend TTL for owner project count
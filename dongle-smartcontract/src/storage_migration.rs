@@ -0,0 +1,171 @@
+//! Reading entries stored with an earlier layout of a struct.
+//!
+//! Soroban decodes a `#[contracttype]` struct only when every field is
+//! present, so entries written before a field was added cannot be read with
+//! the current type. Each legacy layout here mirrors the struct as it was
+//! last released; reads fall back to it when the entry lacks a field that
+//! only the current layout has, and the value is upgraded with defaults for
+//! the new fields. The entry is rewritten in the current layout on its next
+//! write.
+
+use crate::types::{Review, VerificationRecord, VerificationRenewalRecord, VerificationStatus};
+use soroban_sdk::{contracttype, Address, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec};
+
+/// Read a persistent entry stored as `V`, or as the legacy layout `L` if the
+/// entry has no `marker` field.
+pub(crate) fn get_persistent<K, V, L>(env: &Env, key: &K, marker: &str) -> Option<V>
+where
+    K: IntoVal<Env, Val>,
+    V: TryFromVal<Env, Val>,
+    L: TryFromVal<Env, Val> + Into<V>,
+{
+    get_persistent_with::<K, V, L>(env, key, marker, Into::into)
+}
+
+/// As `get_persistent`, upgrading a legacy entry with `upgrade`.
+fn get_persistent_with<K, V, L>(
+    env: &Env,
+    key: &K,
+    marker: &str,
+    upgrade: impl FnOnce(L) -> V,
+) -> Option<V>
+where
+    K: IntoVal<Env, Val>,
+    V: TryFromVal<Env, Val>,
+    L: TryFromVal<Env, Val>,
+{
+    let fields: Map<Symbol, Val> = env.storage().persistent().get(key)?;
+    let raw = fields.to_val();
+    if fields.contains_key(Symbol::new(env, marker)) {
+        V::try_from_val(env, &raw).ok()
+    } else {
+        L::try_from_val(env, &raw).ok().map(upgrade)
+    }
+}
+
+/// `Review` before `verified_user` was added.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct LegacyReview {
+    pub project_id: u64,
+    pub reviewer: Address,
+    pub rating: u32,
+    pub content_cid: Option<String>,
+    pub owner_response: Option<String>,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub hidden: bool,
+    pub report_count: u32,
+}
+
+impl From<LegacyReview> for Review {
+    fn from(review: LegacyReview) -> Self {
+        Review {
+            project_id: review.project_id,
+            reviewer: review.reviewer,
+            rating: review.rating,
+            content_cid: review.content_cid,
+            owner_response: review.owner_response,
+            created_at: review.created_at,
+            updated_at: review.updated_at,
+            hidden: review.hidden,
+            report_count: review.report_count,
+            verified_user: false,
+        }
+    }
+}
+
+/// `VerificationRecord` before verification tiers, verifier quorums and
+/// checklists were added.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct LegacyVerificationRecord {
+    pub request_id: u64,
+    pub project_id: u64,
+    pub requester: Address,
+    pub status: VerificationStatus,
+    pub evidence_cid: String,
+    pub requested_at: u64,
+    pub decided_at: u64,
+    pub fee_amount: u128,
+    pub revoke_reason: Option<String>,
+    pub expires_at: u64,
+    pub last_renewed_at: u64,
+    pub assigned_admin: Option<Address>,
+}
+
+impl LegacyVerificationRecord {
+    /// Upgrade to the current layout as an untiered request that needed a
+    /// single sign-off.
+    fn upgrade(self, env: &Env) -> VerificationRecord {
+        VerificationRecord {
+            request_id: self.request_id,
+            project_id: self.project_id,
+            requester: self.requester,
+            status: self.status,
+            evidence_cid: self.evidence_cid,
+            requested_at: self.requested_at,
+            decided_at: self.decided_at,
+            fee_amount: self.fee_amount,
+            revoke_reason: self.revoke_reason,
+            expires_at: self.expires_at,
+            last_renewed_at: self.last_renewed_at,
+            assigned_admin: self.assigned_admin,
+            tier_id: None,
+            required_approvals: 1,
+            approvals: Vec::new(env),
+            checklist: Vec::new(env),
+        }
+    }
+}
+
+/// `VerificationRenewalRecord` before verification tiers were added.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct LegacyVerificationRenewalRecord {
+    pub project_id: u64,
+    pub requester: Address,
+    pub status: VerificationStatus,
+    pub evidence_cid: String,
+    pub timestamp: u64,
+    pub fee_amount: u128,
+    pub expires_at: u64,
+}
+
+impl From<LegacyVerificationRenewalRecord> for VerificationRenewalRecord {
+    fn from(record: LegacyVerificationRenewalRecord) -> Self {
+        VerificationRenewalRecord {
+            project_id: record.project_id,
+            requester: record.requester,
+            status: record.status,
+            evidence_cid: record.evidence_cid,
+            timestamp: record.timestamp,
+            fee_amount: record.fee_amount,
+            expires_at: record.expires_at,
+            tier_id: None,
+        }
+    }
+}
+
+/// Read a verification record stored under `key` in either layout.
+pub(crate) fn get_verification_record<K>(env: &Env, key: &K) -> Option<VerificationRecord>
+where
+    K: IntoVal<Env, Val>,
+{
+    get_persistent_with::<K, VerificationRecord, LegacyVerificationRecord>(
+        env,
+        key,
+        "tier_id",
+        |record| record.upgrade(env),
+    )
+}
+
+/// Read a renewal record stored under `key` in either layout.
+pub(crate) fn get_renewal_record<K>(env: &Env, key: &K) -> Option<VerificationRenewalRecord>
+where
+    K: IntoVal<Env, Val>,
+{
+    get_persistent::<K, VerificationRenewalRecord, LegacyVerificationRenewalRecord>(
+        env, key, "tier_id",
+    )
+}
@@ -1,3 +1,4 @@
+use crate::blocklist::Blocklist;
 use crate::errors::ContractError;
 use crate::events::{publish_project_followed_event, publish_project_unfollowed_event};
 use crate::project_registry::ProjectRegistry;
@@ -17,6 +18,7 @@ impl SubscriptionRegistry {
         follower: Address,
     ) -> Result<(), ContractError> {
         follower.require_auth();
+        Blocklist::require_not_blocked(env, &follower)?;
 
         ProjectRegistry::get_project(env, project_id).ok_or(ContractError::ProjectNotFound)?;
 
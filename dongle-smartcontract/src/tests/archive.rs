@@ -237,7 +237,8 @@ fn test_archived_project_excluded_from_list_projects_by_status() {
     client.mock_all_auths().archive_project(&project2_id, &owner);
 
     // List verified projects
-    let projects = client.list_projects_by_status(&VerificationStatus::Verified, &1u64, &100u32);
+    let projects =
+        client.list_projects_by_status(&VerificationStatus::Verified, &1u64, &100u32);
 
     // Verify archived project is not in the list
     assert_eq!(projects.len(), 1);
@@ -0,0 +1,158 @@
+//! Tests for the admin address blocklist and bulk review moderation.
+
+use crate::errors::ContractError;
+use crate::tests::fixtures::{create_test_project, setup_contract};
+use crate::types::{AdminActionType, ProjectRegistrationParams};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    Address, Env, String, Vec,
+};
+
+const REASON_CID: &str = "QmBlockReason1234567890123456789012345678901234";
+
+#[test]
+fn test_blocked_address_is_rejected_everywhere() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "BlockTarget");
+    let other = Address::generate(&env);
+    client.add_review(&project_id, &other, &4, &None);
+
+    let spammer = Address::generate(&env);
+    let reason = String::from_str(&env, REASON_CID);
+    client.block_address(&admin, &spammer, &reason, &None);
+    assert!(client.is_address_blocked(&spammer));
+
+    let blocked = Err(Ok(ContractError::AddressBlocked));
+    assert_eq!(
+        client.try_add_review(&project_id, &spammer, &1, &None),
+        blocked
+    );
+    assert_eq!(
+        client.try_report_project(&project_id, &spammer, &reason),
+        blocked
+    );
+    assert_eq!(
+        client.try_report_review(&project_id, &other, &spammer),
+        blocked
+    );
+    assert_eq!(client.try_endorse_project(&project_id, &spammer), blocked);
+    assert_eq!(client.try_follow_project(&project_id, &spammer), blocked);
+
+    let params = ProjectRegistrationParams {
+        owner: spammer.clone(),
+        name: String::from_str(&env, "SpamProject"),
+        slug: String::from_str(&env, "spamproject"),
+        description: String::from_str(&env, "Test project description"),
+        category: String::from_str(&env, "DeFi"),
+        website: None,
+        license: None,
+        logo_cid: None,
+        metadata_cid: None,
+        tags: None,
+        social_links: None,
+        launch_timestamp: None,
+        bounty_url: None,
+    };
+    assert_eq!(
+        client.try_register_project(&params),
+        Err(Ok(ContractError::AddressBlocked))
+    );
+}
+
+#[test]
+fn test_unblock_and_expiry_lift_the_block() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let project_id = create_test_project(&client, &admin, "ExpiryProject");
+    let reason = String::from_str(&env, REASON_CID);
+
+    let temporary = Address::generate(&env);
+    client.block_address(&admin, &temporary, &reason, &Some(1_000));
+    assert!(client.is_address_blocked(&temporary));
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    assert!(!client.is_address_blocked(&temporary));
+    client.follow_project(&project_id, &temporary);
+    // The expired entry is kept for the record.
+    assert_eq!(
+        client.get_blocklist_entry(&temporary).unwrap().expires_at,
+        Some(1_000)
+    );
+
+    let permanent = Address::generate(&env);
+    client.block_address(&admin, &permanent, &reason, &None);
+    client.unblock_address(&admin, &permanent);
+    assert!(!client.is_address_blocked(&permanent));
+    assert!(client.get_blocklist_entry(&permanent).is_none());
+    client.endorse_project(&project_id, &permanent);
+
+    let result = client.try_unblock_address(&admin, &permanent);
+    assert_eq!(result, Err(Ok(ContractError::AddressNotBlocked)));
+}
+
+#[test]
+fn test_block_address_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let target = Address::generate(&env);
+    let reason = String::from_str(&env, REASON_CID);
+
+    let stranger = Address::generate(&env);
+    assert!(client
+        .try_block_address(&stranger, &target, &reason, &None)
+        .is_err());
+
+    let bad_reason = String::from_str(&env, "not-a-cid");
+    assert_eq!(
+        client.try_block_address(&admin, &target, &bad_reason, &None),
+        Err(Ok(ContractError::InvalidCid))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = 500);
+    assert_eq!(
+        client.try_block_address(&admin, &target, &reason, &Some(500)),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    assert!(!client.is_address_blocked(&target));
+}
+
+#[test]
+fn test_hide_reviews_by_reviewer_in_batches() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let spammer = Address::generate(&env);
+
+    let mut project_ids = Vec::new(&env);
+    for name in ["SpamA", "SpamB", "SpamC"] {
+        let project_id = create_test_project(&client, &Address::generate(&env), name);
+        client.add_review(&project_id, &spammer, &1, &None);
+        project_ids.push_back(project_id);
+    }
+    // One review is already hidden and must not be counted again.
+    client.hide_review(&project_ids.get(1).unwrap(), &spammer, &admin);
+
+    let result = client.try_hide_reviews_by_reviewer(&admin, &spammer, &0, &10);
+    assert_eq!(result, Err(Ok(ContractError::AddressNotBlocked)));
+
+    client.block_address(&admin, &spammer, &String::from_str(&env, REASON_CID), &None);
+
+    assert_eq!(client.hide_reviews_by_reviewer(&admin, &spammer, &0, &1), 1);
+    assert_eq!(
+        client.hide_reviews_by_reviewer(&admin, &spammer, &1, &10),
+        1
+    );
+    for i in 0..project_ids.len() {
+        let project_id = project_ids.get(i).unwrap();
+        assert!(client.get_review(&project_id, &spammer).unwrap().hidden);
+        assert_eq!(client.get_project_stats(&project_id).review_count, 0);
+    }
+
+    let latest = client.list_admin_actions(&0, &1).get(0).unwrap();
+    assert_eq!(latest.action_type, AdminActionType::ReviewsHiddenByReviewer);
+    assert_eq!(latest.target_address, Some(spammer.clone()));
+}
@@ -32,7 +32,7 @@ fn test_admin_delete_review_success() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectA");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectA");
 
     let reviewer = Address::generate(&env);
     client.add_review(&project_id, &reviewer, &5, &None);
@@ -57,7 +57,7 @@ fn test_admin_delete_review_updates_project_reviews_index() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectB");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectB");
 
     let r1 = Address::generate(&env);
     let r2 = Address::generate(&env);
@@ -83,8 +83,8 @@ fn test_admin_delete_review_updates_user_reviews_index() {
     let (client, admin) = setup(&env);
 
     // Two projects; reviewer reviewed both
-    let pid1 = create_test_project(&client, &admin, "ProjectC");
-    let pid2 = create_test_project(&client, &admin, "ProjectD");
+    let pid1 = create_test_project(&client, &Address::generate(&env), "ProjectC");
+    let pid2 = create_test_project(&client, &Address::generate(&env), "ProjectD");
 
     let reviewer = Address::generate(&env);
     client.add_review(&pid1, &reviewer, &5, &None);
@@ -105,7 +105,7 @@ fn test_admin_delete_hidden_review_keeps_stats_consistent() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectE");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectE");
 
     let reviewer = Address::generate(&env);
     client.add_review(&project_id, &reviewer, &5, &None);
@@ -144,7 +144,7 @@ fn test_admin_delete_review_missing_review_fails() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectG");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectG");
 
     let ghost_reviewer = Address::generate(&env);
     let result = client.try_admin_delete_review(&project_id, &ghost_reviewer, &admin);
@@ -171,7 +171,7 @@ fn test_clear_project_reports_success() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectH");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectH");
 
     let reporter1 = Address::generate(&env);
     let reporter2 = Address::generate(&env);
@@ -193,7 +193,7 @@ fn test_clear_project_reports_allows_re_reporting() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectI");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectI");
 
     let reporter = Address::generate(&env);
     client.report_project(&project_id, &reporter, &valid_cid(&env));
@@ -232,7 +232,7 @@ fn test_clear_project_reports_no_reports_fails() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectK");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectK");
 
     // No reports — should return the appropriate error
     let result = client.try_clear_project_reports(&project_id, &admin);
@@ -254,7 +254,7 @@ fn test_clear_project_reports_idempotent_second_clear_fails() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectL");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectL");
 
     let reporter = Address::generate(&env);
     client.report_project(&project_id, &reporter, &valid_cid(&env));
@@ -572,7 +572,7 @@ fn test_admin_delete_review_stats_multiple_reviews() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectY");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectY");
 
     let r1 = Address::generate(&env);
     let r2 = Address::generate(&env);
@@ -603,7 +603,7 @@ fn test_clear_reports_then_report_updates_count_correctly() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectZ");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectZ");
 
     let r1 = Address::generate(&env);
     let r2 = Address::generate(&env);
@@ -1,6 +1,6 @@
 use crate::constants::{
     MAX_DESCRIPTION_LEN, MAX_NAME_LEN, MAX_PAGE_LIMIT, MAX_PROJECTS_PER_USER,
-    MAX_REVIEWS_PER_PROJECT, VERIFICATION_VALIDITY_PERIOD,
+    VERIFICATION_VALIDITY_PERIOD,
 };
 use crate::tests::fixtures::setup_contract;
 use soroban_sdk::{testutils::Address as _, Address, Env, String};
@@ -22,10 +22,7 @@ fn config_after_initialization_exposes_defaults_and_limits() {
     assert_eq!(config.fees.registration_fee, 0);
     assert_eq!(config.limits.max_page_limit, MAX_PAGE_LIMIT);
     assert_eq!(config.limits.max_projects_per_user, MAX_PROJECTS_PER_USER);
-    assert_eq!(
-        config.limits.max_reviews_per_project,
-        MAX_REVIEWS_PER_PROJECT
-    );
+    assert_eq!(config.limits.max_reviews_per_project, u32::MAX);
     assert_eq!(config.limits.max_name_len, MAX_NAME_LEN as u32);
     assert_eq!(
         config.limits.max_description_len,
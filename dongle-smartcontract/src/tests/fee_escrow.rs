@@ -0,0 +1,160 @@
+//! Tests for escrowing verification fees until the verification decision.
+
+use crate::errors::ContractError;
+use crate::tests::fixtures::{create_test_project, setup_with_token_fees, TokenFeeSetup};
+use crate::types::{EscrowRefundPolicy, FeeEscrowConfig, ProjectUpdateParams};
+use soroban_sdk::{Env, String};
+
+const EVIDENCE: &str = "QmEscrowEvidence123456789012345678901234567";
+
+fn setup(env: &Env, refund_policy: EscrowRefundPolicy) -> TokenFeeSetup<'_> {
+    let s = setup_with_token_fees(env, 10_000);
+    s.client.set_fee_escrow_config(
+        &s.admin,
+        &FeeEscrowConfig {
+            enabled: true,
+            refund_policy,
+        },
+    );
+    s
+}
+
+fn pay_and_request(env: &Env, s: &TokenFeeSetup<'_>, project_id: u64) {
+    s.client
+        .pay_fee(&s.owner, &project_id, &Some(s.token.address.clone()));
+    s.client
+        .request_verification(&project_id, &s.owner, &String::from_str(env, EVIDENCE));
+}
+
+#[test]
+fn test_escrowed_fee_released_to_treasury_on_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, EscrowRefundPolicy::Full);
+    let project_id = create_test_project(&s.client, &s.owner, "EscrowApproved");
+
+    pay_and_request(&env, &s, project_id);
+    let escrow = s.client.get_fee_escrow(&project_id).unwrap();
+    assert_eq!(escrow.amount, 1_000);
+    assert_eq!(escrow.payer, s.owner);
+    assert_eq!(s.token.balance(&s.treasury), 0);
+    let totals = s.client.get_fee_escrow_totals();
+    assert_eq!(totals.get(0).unwrap().escrowed, 1_000);
+    assert_eq!(totals.get(0).unwrap().balance, 1_000);
+
+    s.client.approve_verification(&project_id, &s.admin);
+    assert_eq!(s.client.get_fee_escrow(&project_id), None);
+    assert_eq!(s.token.balance(&s.treasury), 1_000);
+    let totals = s.client.get_fee_escrow_totals();
+    assert_eq!(totals.get(0).unwrap().escrowed, 0);
+    assert_eq!(totals.get(0).unwrap().balance, 0);
+}
+
+#[test]
+fn test_rejection_applies_refund_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, EscrowRefundPolicy::Partial(2_500));
+    let project_id = create_test_project(&s.client, &s.owner, "EscrowRejected");
+
+    pay_and_request(&env, &s, project_id);
+    s.client.reject_verification(&project_id, &s.admin);
+    assert_eq!(s.token.balance(&s.owner), 9_250);
+    assert_eq!(s.token.balance(&s.treasury), 750);
+
+    // Keeping a review fee refunds the rest.
+    s.client.set_fee_escrow_config(
+        &s.admin,
+        &FeeEscrowConfig {
+            enabled: true,
+            refund_policy: EscrowRefundPolicy::KeepReviewFee(100),
+        },
+    );
+    pay_and_request(&env, &s, project_id);
+    s.client.reject_verification(&project_id, &s.admin);
+    assert_eq!(s.token.balance(&s.owner), 9_250 - 100);
+    assert_eq!(s.token.balance(&s.treasury), 850);
+
+    assert_eq!(
+        s.client.try_set_fee_escrow_config(
+            &s.admin,
+            &FeeEscrowConfig {
+                enabled: true,
+                refund_policy: EscrowRefundPolicy::Partial(10_001),
+            },
+        ),
+        Err(Ok(ContractError::InvalidInput))
+    );
+}
+
+#[test]
+fn test_cancel_and_withdraw_refund_from_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, EscrowRefundPolicy::Full);
+    let project_id = create_test_project(&s.client, &s.owner, "EscrowCancelled");
+    let token = Some(s.token.address.clone());
+
+    // Cancelling an unconsumed payment refunds it from the contract.
+    s.client.pay_fee(&s.owner, &project_id, &token);
+    s.client.cancel_fee_payment(&s.owner, &project_id);
+    assert_eq!(s.token.balance(&s.owner), 10_000);
+    assert_eq!(s.client.get_fee_escrow(&project_id), None);
+
+    // A project holds one escrowed fee at a time.
+    pay_and_request(&env, &s, project_id);
+    assert_eq!(
+        s.client.try_pay_fee(&s.owner, &project_id, &token),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+
+    // Withdrawing refunds the withdrawal share and releases the rest.
+    s.client.set_withdrawal_refund_bps(&s.admin, &4_000);
+    assert_eq!(
+        s.client
+            .withdraw_verification_request(&project_id, &s.owner),
+        400
+    );
+    assert_eq!(s.token.balance(&s.owner), 9_400);
+    assert_eq!(s.token.balance(&s.treasury), 600);
+    assert_eq!(s.client.get_fee_escrow(&project_id), None);
+}
+
+#[test]
+fn test_metadata_reset_refunds_pending_renewal_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, EscrowRefundPolicy::Partial(0));
+    let project_id = create_test_project(&s.client, &s.owner, "EscrowReset");
+    pay_and_request(&env, &s, project_id);
+    s.client.approve_verification(&project_id, &s.admin);
+
+    s.client
+        .pay_fee(&s.owner, &project_id, &Some(s.token.address.clone()));
+    s.client
+        .request_renewal(&project_id, &s.owner, &String::from_str(&env, EVIDENCE));
+    assert_eq!(s.client.get_fee_escrow(&project_id).unwrap().amount, 1_000);
+
+    // A major metadata change ends the renewal without a decision, so its
+    // escrowed fee is refunded in full whatever the refund policy.
+    s.client.update_project(&ProjectUpdateParams {
+        project_id,
+        caller: s.owner.clone(),
+        name: None,
+        slug: None,
+        description: None,
+        category: None,
+        website: Some(Some(String::from_str(&env, "https://reset.example.com"))),
+        license: None,
+        logo_cid: None,
+        metadata_cid: None,
+        tags: None,
+        social_links: None,
+        launch_timestamp: None,
+        bounty_url: None,
+    });
+    assert_eq!(s.client.get_renewal_request(&project_id), None);
+    assert_eq!(s.client.get_fee_escrow(&project_id), None);
+    assert_eq!(s.token.balance(&s.owner), 9_000);
+    assert_eq!(s.token.balance(&s.treasury), 1_000);
+}
@@ -0,0 +1,279 @@
+//! Tests for fee payments in multiple accepted tokens.
+
+use crate::errors::ContractError;
+use crate::events::FeeOperation;
+use crate::review_registry::ReviewRegistry;
+use crate::tests::fixtures::{
+    create_fee_token, create_test_project, mint_fee_token, setup_contract,
+};
+use crate::types::{ReviewEligibilityConfig, TokenFees, VerificationTierParams};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+const EVIDENCE: &str = "QmMultiTokenEvidence123456789012345678901234";
+
+fn token_fees(token: &Address, verification_fee: u128) -> TokenFees {
+    TokenFees {
+        token: token.clone(),
+        verification_fee,
+        registration_fee: 0,
+        review_fee: 0,
+    }
+}
+
+#[test]
+fn test_verification_fee_paid_and_refunded_in_accepted_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let treasury = Address::generate(&env);
+    let usdc = create_fee_token(&env);
+    let xlm = create_fee_token(&env);
+    client.set_fee(&admin, &Some(usdc.clone()), &100, &0, &treasury);
+    client.set_accepted_fee_token(&admin, &token_fees(&xlm, 40));
+
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "PaidInXlm");
+    mint_fee_token(&env, &xlm, &owner, 100);
+    let xlm_client = token::Client::new(&env, &xlm);
+
+    client.pay_fee(&owner, &project_id, &Some(xlm.clone()));
+    assert_eq!(xlm_client.balance(&treasury), 40);
+    let record = client.get_fee_payment_details(&project_id).unwrap();
+    assert_eq!(record.token, Some(xlm.clone()));
+    assert_eq!(record.amount, 40);
+
+    // The refund goes back in the token the fee was paid in.
+    client.cancel_fee_payment(&owner, &project_id);
+    assert_eq!(xlm_client.balance(&owner), 100);
+    assert_eq!(token::Client::new(&env, &usdc).balance(&owner), 0);
+
+    // An accepted-token payment satisfies the verification request.
+    client.pay_fee(&owner, &project_id, &Some(xlm.clone()));
+    client.request_verification(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+    assert!(!client.is_fee_paid(&project_id));
+}
+
+#[test]
+fn test_unaccepted_tokens_rejected_and_quotes_per_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let treasury = Address::generate(&env);
+    let usdc = create_fee_token(&env);
+    let xlm = create_fee_token(&env);
+    let other = create_fee_token(&env);
+    let owner = Address::generate(&env);
+
+    // Accepted tokens need the default configuration, which holds the treasury.
+    assert_eq!(
+        client.try_set_accepted_fee_token(&admin, &token_fees(&xlm, 40)),
+        Err(Ok(ContractError::FeeConfigNotSet))
+    );
+    client.set_fee(&admin, &Some(usdc.clone()), &100, &0, &treasury);
+    assert_eq!(
+        client.try_set_accepted_fee_token(&owner, &token_fees(&xlm, 40)),
+        Err(Ok(ContractError::AdminOnly))
+    );
+    client.set_accepted_fee_token(
+        &admin,
+        &TokenFees {
+            registration_fee: 7,
+            review_fee: 3,
+            ..token_fees(&xlm, 40)
+        },
+    );
+    assert_eq!(client.list_accepted_fee_tokens(), vec![&env, xlm.clone()]);
+
+    let xlm_some = Some(xlm.clone());
+    assert_eq!(
+        client.get_fee_quote(&xlm_some, &FeeOperation::Verification),
+        40
+    );
+    assert_eq!(
+        client.get_fee_quote(&xlm_some, &FeeOperation::Registration),
+        7
+    );
+    assert_eq!(client.get_fee_quote(&xlm_some, &FeeOperation::Review), 3);
+    assert_eq!(
+        client.get_fee_quote(&Some(usdc.clone()), &FeeOperation::Verification),
+        100
+    );
+    assert_eq!(
+        client.try_get_fee_quote(&Some(other.clone()), &FeeOperation::Verification),
+        Err(Ok(ContractError::InvalidProjectData))
+    );
+
+    // Registration now costs 7 XLM, and other tokens are refused.
+    mint_fee_token(&env, &other, &owner, 100);
+    assert_eq!(
+        client.try_pay_registration_fee(&owner, &Some(other.clone())),
+        Err(Ok(ContractError::InvalidProjectData))
+    );
+    mint_fee_token(&env, &xlm, &owner, 100);
+    client.pay_registration_fee(&owner, &xlm_some);
+    assert_eq!(token::Client::new(&env, &xlm).balance(&treasury), 7);
+    create_test_project(&client, &owner, "RegisteredInXlm");
+}
+
+#[test]
+fn test_removed_token_no_longer_accepted() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let treasury = Address::generate(&env);
+    let xlm = create_fee_token(&env);
+    client.set_fee(&admin, &None, &0, &0, &treasury);
+
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "RemovedToken");
+    client.set_accepted_fee_token(&admin, &token_fees(&xlm, 5));
+
+    client.remove_accepted_fee_token(&admin, &xlm);
+    assert_eq!(client.list_accepted_fee_tokens().len(), 0);
+    assert_eq!(client.get_token_fees(&xlm), None);
+    assert_eq!(
+        client.try_remove_accepted_fee_token(&admin, &xlm),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    assert_eq!(
+        client.try_pay_fee(&owner, &project_id, &Some(xlm.clone())),
+        Err(Ok(ContractError::InvalidProjectData))
+    );
+}
+
+#[test]
+fn test_tier_fee_not_covered_by_accepted_token_payment() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let treasury = Address::generate(&env);
+    let usdc = create_fee_token(&env);
+    let xlm = create_fee_token(&env);
+    client.set_fee(&admin, &Some(usdc.clone()), &100, &0, &treasury);
+    client.set_accepted_fee_token(&admin, &token_fees(&xlm, 600));
+    let tier_id = client.create_verification_tier(
+        &admin,
+        &VerificationTierParams {
+            name: String::from_str(&env, "Audited"),
+            level: 2,
+            fee: 500,
+            duration_seconds: 1_000,
+            requirements_cid: String::from_str(&env, EVIDENCE),
+            badge_cid: String::from_str(&env, EVIDENCE),
+        },
+    );
+
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "TierInXlm");
+    mint_fee_token(&env, &xlm, &owner, 600);
+    mint_fee_token(&env, &usdc, &owner, 500);
+
+    // 600 XLM is not 500 in the default token the tier is priced in.
+    client.pay_fee(&owner, &project_id, &Some(xlm.clone()));
+    assert_eq!(
+        client.try_request_tier_verification(
+            &project_id,
+            &owner,
+            &tier_id,
+            &String::from_str(&env, EVIDENCE)
+        ),
+        Err(Ok(ContractError::InvalidProjectData))
+    );
+    assert_eq!(
+        client.try_pay_tier_fee(&owner, &project_id, &tier_id, &Some(xlm.clone())),
+        Err(Ok(ContractError::InvalidProjectData))
+    );
+
+    client.cancel_fee_payment(&owner, &project_id);
+    client.pay_tier_fee(&owner, &project_id, &tier_id, &Some(usdc.clone()));
+    client.request_tier_verification(
+        &project_id,
+        &owner,
+        &tier_id,
+        &String::from_str(&env, EVIDENCE),
+    );
+    assert_eq!(
+        client.get_verification(&project_id).unwrap().fee_amount,
+        500
+    );
+}
+
+#[test]
+fn test_review_fee_paid_by_reviewer_in_accepted_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let treasury = Address::generate(&env);
+    let usdc = create_fee_token(&env);
+    let xlm = create_fee_token(&env);
+    client.set_fee(&admin, &Some(usdc.clone()), &0, &0, &treasury);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ReviewFees");
+
+    // A review fee in an accepted token alone makes reviews require a fee.
+    client.set_accepted_fee_token(
+        &admin,
+        &TokenFees {
+            review_fee: 5,
+            ..token_fees(&xlm, 40)
+        },
+    );
+    let reviewer = Address::generate(&env);
+    assert_eq!(
+        client.try_add_review(&project_id, &reviewer, &4, &None),
+        Err(Ok(ContractError::ReviewFeeRequired))
+    );
+
+    // The owner's verification fee does not pay for anyone's review.
+    mint_fee_token(&env, &xlm, &owner, 40);
+    client.pay_fee(&owner, &project_id, &Some(xlm.clone()));
+    assert_eq!(
+        client.try_add_review(&project_id, &reviewer, &4, &None),
+        Err(Ok(ContractError::ReviewFeeRequired))
+    );
+
+    mint_fee_token(&env, &xlm, &reviewer, 10);
+    client.pay_review_fee(&reviewer, &project_id, &Some(xlm.clone()));
+    let xlm_client = token::Client::new(&env, &xlm);
+    assert_eq!(xlm_client.balance(&reviewer), 5);
+    assert_eq!(xlm_client.balance(&treasury), 45);
+    let payment = client
+        .get_review_fee_payment(&project_id, &reviewer)
+        .unwrap();
+    assert_eq!((payment.amount, payment.token), (5, Some(xlm.clone())));
+    assert_eq!(
+        client.try_pay_review_fee(&reviewer, &project_id, &Some(xlm.clone())),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+
+    // The review consumes the payment, so the next project needs another.
+    client.add_review(&project_id, &reviewer, &4, &None);
+    assert_eq!(client.get_review_fee_payment(&project_id, &reviewer), None);
+    let other_project = create_test_project(&client, &owner, "ReviewFeesTwo");
+    assert_eq!(
+        client.try_add_review(&other_project, &reviewer, &4, &None),
+        Err(Ok(ContractError::ReviewFeeRequired))
+    );
+
+    // The default token prices the review fee from the eligibility config.
+    env.as_contract(&client.address, || {
+        ReviewRegistry::set_review_eligibility_config(
+            &env,
+            admin.clone(),
+            ReviewEligibilityConfig {
+                min_reviewer_age_seconds: 0,
+                require_endorsement: false,
+                review_fee: 8,
+            },
+        )
+        .unwrap();
+    });
+    assert_eq!(
+        client.get_fee_quote(&Some(usdc.clone()), &FeeOperation::Review),
+        8
+    );
+    mint_fee_token(&env, &usdc, &reviewer, 8);
+    client.pay_review_fee(&reviewer, &other_project, &Some(usdc.clone()));
+    assert_eq!(token::Client::new(&env, &usdc).balance(&treasury), 8);
+    client.add_review(&other_project, &reviewer, &5, &None);
+}
@@ -0,0 +1,243 @@
+//! Tests for verification fee payment expiry, reclaim and re-pricing.
+
+use crate::errors::ContractError;
+use crate::storage_keys::FeeKey;
+use crate::tests::fixtures::{create_test_project, setup_with_token_fees, TokenFeeSetup};
+use crate::types::{EscrowRefundPolicy, FeeEscrowConfig, FeePaymentStatus, VerificationTierParams};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    Address, Env, String,
+};
+
+const EVIDENCE: &str = "QmExpiryEvidence1234567890123456789012345678";
+const DAY: u64 = 24 * 60 * 60;
+
+fn setup(env: &Env) -> TokenFeeSetup<'_> {
+    let s = setup_with_token_fees(env, 10_000);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    s
+}
+
+#[test]
+fn test_expired_payment_rejected_by_request() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let project_id = create_test_project(&s.client, &s.owner, "ExpiredPayment");
+    assert_eq!(s.client.get_fee_payment_expiry(), 7 * DAY);
+    s.client.set_fee_payment_expiry(&s.admin, &DAY);
+
+    s.client
+        .pay_fee(&s.owner, &project_id, &Some(s.token.address.clone()));
+    let details = s.client.get_fee_payment_details(&project_id).unwrap();
+    assert_eq!(details.expires_at, 1_000 + DAY);
+    assert_eq!(details.status, FeePaymentStatus::Valid);
+
+    env.ledger().with_mut(|l| l.timestamp += DAY + 1);
+    assert_eq!(
+        s.client
+            .get_fee_payment_details(&project_id)
+            .unwrap()
+            .status,
+        FeePaymentStatus::Expired
+    );
+    let evidence = String::from_str(&env, EVIDENCE);
+    assert_eq!(
+        s.client
+            .try_request_verification(&project_id, &s.owner, &evidence),
+        Err(Ok(ContractError::FeePaymentExpired))
+    );
+
+    // A payment keeps the expiry it was made under; re-pricing renews it
+    // under the current window, here without expiry.
+    s.client.set_fee_payment_expiry(&s.admin, &0);
+    assert_eq!(
+        s.client
+            .try_request_verification(&project_id, &s.owner, &evidence),
+        Err(Ok(ContractError::FeePaymentExpired))
+    );
+    assert_eq!(s.client.reprice_fee_payment(&s.owner, &project_id), 1_000);
+    s.client
+        .request_verification(&project_id, &s.owner, &evidence);
+    let details = s.client.get_fee_payment_details(&project_id).unwrap();
+    assert_eq!(details.expires_at, 0);
+    assert_eq!(details.status, FeePaymentStatus::Consumed);
+}
+
+#[test]
+fn test_payment_expiry_fixed_when_paid() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let project_id = create_test_project(&s.client, &s.owner, "FixedExpiry");
+    s.client.set_fee_payment_expiry(&s.admin, &DAY);
+    s.client
+        .pay_fee(&s.owner, &project_id, &Some(s.token.address.clone()));
+
+    // Later changes to the window do not move an existing payment's expiry.
+    s.client.set_fee_payment_expiry(&s.admin, &(30 * DAY));
+    let details = s.client.get_fee_payment_details(&project_id).unwrap();
+    assert_eq!(details.expires_at, 1_000 + DAY);
+
+    // A payment stored before expiry existed never expires.
+    env.as_contract(&s.client.address, || {
+        env.storage()
+            .persistent()
+            .remove(&FeeKey::FeePaymentExpiresAt(project_id));
+    });
+    env.ledger().with_mut(|l| l.timestamp += 60 * DAY);
+    let details = s.client.get_fee_payment_details(&project_id).unwrap();
+    assert_eq!(details.expires_at, 0);
+    assert_eq!(details.status, FeePaymentStatus::Valid);
+    s.client
+        .request_verification(&project_id, &s.owner, &String::from_str(&env, EVIDENCE));
+}
+
+#[test]
+fn test_payer_reclaims_expired_payment() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let project_id = create_test_project(&s.client, &s.owner, "ReclaimedPayment");
+    let unescrowed = create_test_project(&s.client, &s.owner, "UnescrowedPayment");
+    let token = Some(s.token.address.clone());
+    s.client.pay_fee(&s.owner, &unescrowed, &token);
+    s.client.set_fee_escrow_config(
+        &s.admin,
+        &FeeEscrowConfig {
+            enabled: true,
+            refund_policy: EscrowRefundPolicy::Full,
+        },
+    );
+    s.client.pay_fee(&s.owner, &project_id, &token);
+
+    // Only expired payments can be reclaimed, and only by the payer.
+    assert_eq!(
+        s.client.try_reclaim_fee_payment(&s.owner, &project_id),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+    env.ledger().with_mut(|l| l.timestamp += 7 * DAY + 1);
+    assert_eq!(
+        s.client
+            .try_reclaim_fee_payment(&Address::generate(&env), &project_id),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    s.client.reclaim_fee_payment(&s.owner, &project_id);
+    assert_eq!(s.token.balance(&s.owner), 9_000);
+    assert!(!s.client.is_fee_paid(&project_id));
+    assert_eq!(s.client.get_fee_payment_details(&project_id), None);
+
+    // A payment made outside escrow sits in the treasury, which must
+    // authorize its refund, so only an admin cancellation returns it.
+    assert_eq!(
+        s.client.try_reclaim_fee_payment(&s.owner, &unescrowed),
+        Err(Ok(ContractError::FeeNotEscrowed))
+    );
+    s.client.cancel_fee_payment(&s.admin, &unescrowed);
+    assert_eq!(s.token.balance(&s.owner), 10_000);
+    assert_eq!(s.token.balance(&s.treasury), 0);
+}
+
+#[test]
+fn test_reprice_after_fee_change() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let project_id = create_test_project(&s.client, &s.owner, "RepricedPayment");
+    let token = Some(s.token.address.clone());
+    s.client.pay_fee(&s.owner, &project_id, &token);
+
+    // A payment below the current fee no longer covers a request.
+    s.client.set_fee(&s.admin, &token, &1_500, &0, &s.treasury);
+    let evidence = String::from_str(&env, EVIDENCE);
+    assert_eq!(
+        s.client
+            .try_request_verification(&project_id, &s.owner, &evidence),
+        Err(Ok(ContractError::InsufficientFee))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp += 8 * DAY);
+    assert_eq!(s.client.reprice_fee_payment(&s.owner, &project_id), 1_500);
+    assert_eq!(s.token.balance(&s.treasury), 1_500);
+    let details = s.client.get_fee_payment_details(&project_id).unwrap();
+    assert_eq!(details.amount, 1_500);
+    assert_eq!(details.status, FeePaymentStatus::Valid);
+
+    // Outside escrow, the treasury is not asked to refund a lower fee.
+    s.client.set_fee(&s.admin, &token, &400, &0, &s.treasury);
+    assert_eq!(
+        s.client.try_reprice_fee_payment(&s.owner, &project_id),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+    s.client
+        .request_verification(&project_id, &s.owner, &evidence);
+    assert_eq!(
+        s.client.try_reprice_fee_payment(&s.owner, &project_id),
+        Err(Ok(ContractError::InsufficientFee))
+    );
+
+    // An escrowed payment is refunded the difference from escrow.
+    s.client.set_fee_escrow_config(
+        &s.admin,
+        &FeeEscrowConfig {
+            enabled: true,
+            refund_policy: EscrowRefundPolicy::Full,
+        },
+    );
+    let escrowed = create_test_project(&s.client, &s.owner, "RepricedEscrow");
+    s.client.set_fee(&s.admin, &token, &1_000, &0, &s.treasury);
+    s.client.pay_fee(&s.owner, &escrowed, &token);
+    s.client.set_fee(&s.admin, &token, &400, &0, &s.treasury);
+    assert_eq!(s.client.reprice_fee_payment(&s.owner, &escrowed), 400);
+    assert_eq!(s.token.balance(&s.owner), 10_000 - 1_500 - 400);
+    assert_eq!(s.client.get_fee_escrow(&escrowed).unwrap().amount, 400);
+}
+
+#[test]
+fn test_reprice_tier_payment_to_tier_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let project_id = create_test_project(&s.client, &s.owner, "RepricedTier");
+    let token = Some(s.token.address.clone());
+    let params = VerificationTierParams {
+        name: String::from_str(&env, "Audited"),
+        level: 2,
+        fee: 2_000,
+        duration_seconds: 1_000,
+        requirements_cid: String::from_str(&env, EVIDENCE),
+        badge_cid: String::from_str(&env, EVIDENCE),
+    };
+    let tier_id = s.client.create_verification_tier(&s.admin, &params);
+    s.client
+        .pay_tier_fee(&s.owner, &project_id, &tier_id, &token);
+
+    // The tier's fee, not the global fee of 1000, prices the payment.
+    s.client.update_verification_tier(
+        &s.admin,
+        &tier_id,
+        &VerificationTierParams {
+            fee: 2_500,
+            ..params
+        },
+    );
+    assert_eq!(s.client.reprice_fee_payment(&s.owner, &project_id), 2_500);
+    assert_eq!(s.token.balance(&s.treasury), 2_500);
+    s.client.request_tier_verification(
+        &project_id,
+        &s.owner,
+        &tier_id,
+        &String::from_str(&env, EVIDENCE),
+    );
+    assert_eq!(
+        s.client.get_verification(&project_id).unwrap().fee_amount,
+        2_500
+    );
+
+    // Payments of the global fee are still re-priced to the global fee.
+    let untiered = create_test_project(&s.client, &s.owner, "RepricedUntiered");
+    s.client.pay_fee(&s.owner, &untiered, &token);
+    s.client.set_fee(&s.admin, &token, &1_200, &0, &s.treasury);
+    assert_eq!(s.client.reprice_fee_payment(&s.owner, &untiered), 1_200);
+}
@@ -0,0 +1,125 @@
+//! Tests for cumulative fee revenue accounting and reporting.
+
+use crate::events::FeeOperation;
+use crate::tests::fixtures::{create_test_project, setup_with_token_fees};
+use crate::types::{EscrowRefundPolicy, FeeEscrowConfig, FeeRevenueEntry, FeeRevenueReport};
+use soroban_sdk::{testutils::Ledger as _, Env, String};
+
+const EVIDENCE: &str = "QmRevenueEvidence12345678901234567890123456";
+const PERIOD: u64 = 30 * 24 * 60 * 60;
+
+fn entry(report: &FeeRevenueReport, operation: FeeOperation) -> Option<FeeRevenueEntry> {
+    report
+        .entries
+        .iter()
+        .find(|entry| entry.operation == operation)
+}
+
+#[test]
+fn test_revenue_totals_track_payments_and_refunds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_with_token_fees(&env, 100_000);
+    let token = Some(s.token.address.clone());
+    assert_eq!(s.client.get_fee_revenue_report(&None).entries.len(), 0);
+
+    s.client
+        .set_fee(&s.admin, &token, &1_000, &250, &s.treasury);
+    s.client.pay_registration_fee(&s.owner, &token);
+    let project_id = create_test_project(&s.client, &s.owner, "RevenueTotals");
+    s.client.pay_fee(&s.owner, &project_id, &token);
+
+    // Cancelling refunds the verification fee without touching registration.
+    s.client.cancel_fee_payment(&s.owner, &project_id);
+    s.client.pay_fee(&s.owner, &project_id, &token);
+    let report = s.client.get_fee_revenue_report(&None);
+    assert_eq!((report.period_start, report.period_end), (0, 0));
+    assert_eq!(report.entries.len(), 2);
+    let verification = entry(&report, FeeOperation::Verification).unwrap();
+    assert_eq!(verification.token, s.token.address);
+    assert_eq!(
+        (
+            verification.collected,
+            verification.refunded,
+            verification.net
+        ),
+        (2_000, 1_000, 1_000)
+    );
+    let registration = entry(&report, FeeOperation::Registration).unwrap();
+    assert_eq!((registration.collected, registration.net), (250, 250));
+
+    // Net revenue matches what the treasury holds.
+    assert_eq!(
+        s.token.balance(&s.treasury),
+        verification.net + registration.net
+    );
+}
+
+#[test]
+fn test_refunds_in_later_period_keep_totals_consistent() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_with_token_fees(&env, 100_000);
+    s.client.set_fee_escrow_config(
+        &s.admin,
+        &FeeEscrowConfig {
+            enabled: true,
+            refund_policy: EscrowRefundPolicy::Partial(4_000),
+        },
+    );
+    env.ledger().with_mut(|l| l.timestamp = PERIOD + 10);
+    let project_id = create_test_project(&s.client, &s.owner, "RevenuePeriods");
+    s.client
+        .pay_fee(&s.owner, &project_id, &Some(s.token.address.clone()));
+    s.client
+        .request_verification(&project_id, &s.owner, &String::from_str(&env, EVIDENCE));
+
+    // The rejection refund lands in the next period.
+    env.ledger().with_mut(|l| l.timestamp = 2 * PERIOD + 10);
+    s.client.reject_verification(&project_id, &s.admin);
+
+    let first = s.client.get_fee_revenue_report(&Some(PERIOD + 5));
+    assert_eq!((first.period_start, first.period_end), (PERIOD, 2 * PERIOD));
+    let paid = entry(&first, FeeOperation::Verification).unwrap();
+    assert_eq!((paid.collected, paid.refunded), (1_000, 0));
+
+    let second = s.client.get_fee_revenue_report(&Some(2 * PERIOD));
+    let refunded = entry(&second, FeeOperation::Verification).unwrap();
+    assert_eq!(
+        (refunded.collected, refunded.refunded, refunded.net),
+        (0, 400, -400)
+    );
+
+    let total = s.client.get_fee_revenue_report(&None);
+    assert_eq!(entry(&total, FeeOperation::Verification).unwrap().net, 600);
+    assert_eq!(s.client.get_fee_revenue_report(&Some(0)).entries.len(), 0);
+}
+
+#[test]
+fn test_renewal_fee_attributed_to_renewal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_with_token_fees(&env, 100_000);
+    let token = Some(s.token.address.clone());
+    let project_id = create_test_project(&s.client, &s.owner, "RevenueRenewal");
+    let evidence = String::from_str(&env, EVIDENCE);
+
+    s.client.pay_fee(&s.owner, &project_id, &token);
+    s.client
+        .request_verification(&project_id, &s.owner, &evidence);
+    s.client.approve_verification(&project_id, &s.admin);
+
+    s.client.pay_fee(&s.owner, &project_id, &token);
+    s.client.request_renewal(&project_id, &s.owner, &evidence);
+    let report = s.client.get_fee_revenue_report(&None);
+    assert_eq!(
+        entry(&report, FeeOperation::Verification)
+            .unwrap()
+            .collected,
+        1_000
+    );
+    assert_eq!(
+        entry(&report, FeeOperation::Renewal).unwrap().collected,
+        1_000
+    );
+}
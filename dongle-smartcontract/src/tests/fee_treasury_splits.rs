@@ -0,0 +1,173 @@
+//! Tests for treasury revenue splits across multiple recipients.
+
+use crate::errors::ContractError;
+use crate::events::FeeOperation;
+use crate::tests::fixtures::{
+    create_fee_token, create_test_project, mint_fee_token, setup_contract, setup_with_token_fees,
+};
+use crate::types::{EscrowRefundPolicy, FeeEscrowConfig, ProposalPayload, TreasurySplit};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String, Vec};
+
+const EVIDENCE: &str = "QmSplitEvidence1234567890123456789012345678";
+
+fn split(recipient: &Address, bps: u32) -> TreasurySplit {
+    TreasurySplit {
+        recipient: recipient.clone(),
+        bps,
+    }
+}
+
+fn escrow(enabled: bool) -> FeeEscrowConfig {
+    FeeEscrowConfig {
+        enabled,
+        refund_policy: EscrowRefundPolicy::Full,
+    }
+}
+
+#[test]
+fn test_fee_split_across_recipients_with_dust_to_first() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let token = create_fee_token(&env);
+    mint_fee_token(&env, &token, &owner, 5_000);
+    let treasury = Address::generate(&env);
+    client.set_fee(&admin, &Some(token.clone()), &0, &1_001, &treasury);
+
+    let operations = Address::generate(&env);
+    let verifier_pool = Address::generate(&env);
+    let grants = Address::generate(&env);
+    let splits = vec![
+        &env,
+        split(&operations, 7_000),
+        split(&verifier_pool, 2_000),
+        split(&grants, 1_000),
+    ];
+    client.set_treasury_splits(&admin, &FeeOperation::Registration, &splits);
+    assert_eq!(
+        client.get_treasury_splits(&FeeOperation::Registration),
+        splits
+    );
+
+    client.pay_registration_fee(&owner, &Some(token.clone()));
+    create_test_project(&client, &owner, "SplitFees");
+    let balances = token::Client::new(&env, &token);
+    assert_eq!(balances.balance(&operations), 701);
+    assert_eq!(balances.balance(&verifier_pool), 200);
+    assert_eq!(balances.balance(&grants), 100);
+    assert_eq!(balances.balance(&treasury), 0);
+
+    // Clearing the splits sends the fee to the treasury again.
+    client.set_treasury_splits(&admin, &FeeOperation::Registration, &Vec::new(&env));
+    assert_eq!(
+        client
+            .get_treasury_splits(&FeeOperation::Registration)
+            .len(),
+        0
+    );
+    client.pay_registration_fee(&owner, &Some(token.clone()));
+    create_test_project(&client, &owner, "UnsplitFees");
+    assert_eq!(balances.balance(&treasury), 1_001);
+}
+
+#[test]
+fn test_verification_splits_require_escrow_and_refund_from_it() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_with_token_fees(&env, 10_000);
+    let pool = Address::generate(&env);
+    let grants = Address::generate(&env);
+    let splits = vec![&env, split(&pool, 5_000), split(&grants, 5_000)];
+    let op = FeeOperation::Verification;
+
+    // Refunds cannot be taken back from recipients, so verification fees
+    // are only split while they are escrowed.
+    assert_eq!(
+        s.client.try_set_treasury_splits(&s.admin, &op, &splits),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+    s.client.set_fee_escrow_config(&s.admin, &escrow(true));
+    s.client.set_treasury_splits(&s.admin, &op, &splits);
+    assert_eq!(
+        s.client.try_set_fee_escrow_config(&s.admin, &escrow(false)),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+
+    // Cancelling a split payment refunds it in full from escrow.
+    let token = Some(s.token.address.clone());
+    let project_id = create_test_project(&s.client, &s.owner, "SplitRefund");
+    s.client.pay_fee(&s.owner, &project_id, &token);
+    s.client.cancel_fee_payment(&s.owner, &project_id);
+    assert_eq!(s.token.balance(&s.owner), 10_000);
+    assert_eq!(s.token.balance(&pool) + s.token.balance(&grants), 0);
+
+    // Approval splits the released fee.
+    s.client.pay_fee(&s.owner, &project_id, &token);
+    s.client
+        .request_verification(&project_id, &s.owner, &String::from_str(&env, EVIDENCE));
+    s.client.approve_verification(&project_id, &s.admin);
+    assert_eq!(s.token.balance(&pool), 500);
+    assert_eq!(s.token.balance(&grants), 500);
+    assert_eq!(s.token.balance(&s.treasury), 0);
+
+    // Escrow can be turned off once the splits are cleared.
+    s.client.set_treasury_splits(&s.admin, &op, &Vec::new(&env));
+    s.client.set_fee_escrow_config(&s.admin, &escrow(false));
+}
+
+#[test]
+fn test_invalid_splits_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let op = FeeOperation::Registration;
+
+    assert_eq!(
+        client.try_set_treasury_splits(&a, &op, &vec![&env, split(&a, 10_000)]),
+        Err(Ok(ContractError::AdminOnly))
+    );
+    for splits in [
+        vec![&env, split(&a, 6_000), split(&b, 3_000)],
+        vec![&env, split(&a, 10_000), split(&b, 0)],
+        vec![&env, split(&a, 5_000), split(&a, 5_000)],
+    ] {
+        assert_eq!(
+            client.try_set_treasury_splits(&admin, &op, &splits),
+            Err(Ok(ContractError::InvalidInput))
+        );
+    }
+    assert_eq!(client.get_treasury_splits(&op).len(), 0);
+}
+
+#[test]
+fn test_splits_governed_by_proposal_when_multisig() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    client.set_fee_escrow_config(&admin, &escrow(true));
+    let admin2 = Address::generate(&env);
+    client.add_admin(&admin, &admin2);
+    client.set_admin_approval_threshold(&admin, &2);
+
+    let splits = vec![
+        &env,
+        split(&Address::generate(&env), 5_000),
+        split(&Address::generate(&env), 5_000),
+    ];
+    assert_eq!(
+        client.try_set_treasury_splits(&admin, &FeeOperation::Verification, &splits),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    let payload = ProposalPayload::SetTreasurySplits(FeeOperation::Verification, splits.clone());
+    let proposal_id = client.create_proposal(&admin, &payload);
+    client.approve_proposal(&admin2, &proposal_id);
+    client.execute_proposal(&admin, &proposal_id);
+    assert_eq!(
+        client.get_treasury_splits(&FeeOperation::Verification),
+        splits
+    );
+}
@@ -5,7 +5,7 @@
 use crate::types::{Project, ProjectRegistrationParams, VerificationStatus};
 use crate::DongleContract;
 use crate::DongleContractClient;
-use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String, Vec};
 
 /// Initialize contract with a default admin and return client + admin address.
 ///
@@ -43,6 +43,47 @@ pub fn setup_with_fees(
     (client, admin, treasury)
 }
 
+/// Register a Stellar asset contract to pay fees in.
+pub fn create_fee_token(env: &Env) -> Address {
+    env.register_stellar_asset_contract_v2(Address::generate(env))
+        .address()
+}
+
+/// Mint `amount` of a fee token to `to`.
+pub fn mint_fee_token(env: &Env, token: &Address, to: &Address, amount: i128) {
+    token::StellarAssetClient::new(env, token).mint(to, &amount);
+}
+
+/// Contract charging a verification fee of 1000 in a fee token, and a
+/// project owner holding some of that token.
+pub struct TokenFeeSetup<'a> {
+    pub client: DongleContractClient<'a>,
+    pub admin: Address,
+    pub owner: Address,
+    pub treasury: Address,
+    pub token: token::Client<'a>,
+}
+
+/// Initialize the contract with a verification fee of 1000 in a new fee
+/// token and mint `owner_balance` of it to a fresh owner.
+pub fn setup_with_token_fees(env: &Env, owner_balance: i128) -> TokenFeeSetup<'_> {
+    let (client, admin) = setup_contract(env);
+    let owner = Address::generate(env);
+    let treasury = Address::generate(env);
+    let token = create_fee_token(env);
+    mint_fee_token(env, &token, &owner, owner_balance);
+    client
+        .mock_all_auths()
+        .set_fee(&admin, &Some(token.clone()), &1_000, &0, &treasury);
+    TokenFeeSetup {
+        client,
+        admin,
+        owner,
+        treasury,
+        token: token::Client::new(env, &token),
+    }
+}
+
 /// Create a test project with default parameters.
 ///
 /// Returns the project ID.
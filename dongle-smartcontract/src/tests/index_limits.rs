@@ -1,10 +1,12 @@
-//! Storage index size limit tests: owner projects and review indexes.
+//! Storage index size limit tests: owner project cap and chunked review indexes.
 
-use crate::constants::{MAX_PROJECTS_PER_USER, MAX_REVIEWS_PER_PROJECT, MAX_REVIEWS_PER_USER};
+use crate::constants::{MAX_PROJECTS_PER_USER, REVIEW_INDEX_PAGE_SIZE};
 use crate::errors::ContractError;
+use crate::review_registry::ReviewIndex;
+use crate::storage_keys::StorageKey;
 use crate::tests::fixtures::{create_test_project, setup_contract};
 use crate::types::ProjectRegistrationParams;
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
 
 fn register_project_for_owner(
     env: &Env,
@@ -61,6 +63,7 @@ fn test_max_projects_per_user_enforced() {
             description: String::from_str(&env, "Too many projects"),
             category: String::from_str(&env, "DeFi"),
             website: None,
+            license: None,
             logo_cid: None,
             metadata_cid: None,
             tags: None,
@@ -133,36 +136,70 @@ fn test_claim_rejected_when_claimant_at_project_cap() {
 }
 
 #[test]
-fn test_max_reviews_per_project_enforced() {
+fn test_project_reviews_not_capped() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup_contract(&env);
     let project_id = create_test_project(&client, &admin, "Review-Cap-Project");
 
-    for i in 0..MAX_REVIEWS_PER_PROJECT {
+    // Former per-project cap was 500.
+    for _ in 0..501 {
         let reviewer = Address::generate(&env);
         client
             .mock_all_auths()
             .add_review(&project_id, &reviewer, &3, &None);
-        let _ = i;
     }
 
-    let overflow_reviewer = Address::generate(&env);
-    let result = client
-        .mock_all_auths()
-        .try_add_review(&project_id, &overflow_reviewer, &4, &None);
+    assert_eq!(client.get_project_stats(&project_id).review_count, 501);
+    assert_eq!(client.list_reviews(&project_id, &500, &10).len(), 1);
 
-    assert_eq!(result, Err(Ok(ContractError::MaxProjectsExceeded.into())));
+    // Whole-index reads stop after the first MAX_REVIEW_SCAN_PAGES pages.
+    let sorted =
+        client.list_reviews_sorted(&project_id, &90, &20, &crate::types::ReviewSortMode::Newest);
+    assert_eq!(sorted.len(), 10);
 }
 
 #[test]
-fn test_max_reviews_per_user_enforced() {
+fn test_sorted_reviews_and_cids_span_index_pages() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let project_id = create_test_project(&client, &admin, "Review-Page-Project");
+    let cid = Some(String::from_str(
+        &env,
+        "QmReviewPagesCID123456789012345678901234567890",
+    ));
+
+    for _ in 0..REVIEW_INDEX_PAGE_SIZE {
+        client.add_review(&project_id, &Address::generate(&env), &3, &cid);
+    }
+    // The best review lives on the second index page.
+    let best = Address::generate(&env);
+    client.add_review(&project_id, &best, &5, &cid);
+
+    let sorted = client.list_reviews_sorted(
+        &project_id,
+        &0,
+        &1,
+        &crate::types::ReviewSortMode::RatingHigh,
+    );
+    assert_eq!(sorted.get(0).unwrap().reviewer, best);
+    assert_eq!(
+        client.get_project_review_cids(&project_id).len(),
+        REVIEW_INDEX_PAGE_SIZE + 1
+    );
+}
+
+#[test]
+fn test_user_review_index_spans_pages() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, _admin) = setup_contract(&env);
     let reviewer = Address::generate(&env);
+    let total = REVIEW_INDEX_PAGE_SIZE * 2 + 1;
 
-    for i in 0..MAX_REVIEWS_PER_USER {
+    let mut project_ids = Vec::new(&env);
+    for i in 0..total {
         extern crate alloc;
         use alloc::format;
         let owner = Address::generate(&env);
@@ -171,15 +208,90 @@ fn test_max_reviews_per_user_enforced() {
         client
             .mock_all_auths()
             .add_review(&project_id, &reviewer, &5, &None);
+        project_ids.push_back(project_id);
     }
 
-    let owner = Address::generate(&env);
-    let overflow_project =
-        register_project_for_owner(&env, &client, &owner, "Overflow-Review-Project");
+    // Delete an entry on the first page; the last entry moves into its slot.
+    let removed = project_ids.get(3).unwrap();
+    let last = project_ids.get(total - 1).unwrap();
+    client.delete_review(&removed, &reviewer);
 
-    let result = client
-        .mock_all_auths()
-        .try_add_review(&overflow_project, &reviewer, &4, &None);
+    env.as_contract(&client.address, || {
+        assert_eq!(ReviewIndex::user_len(&env, &reviewer), total - 1);
+        let indexed = ReviewIndex::user_projects(&env, &reviewer, 0, total);
+        assert_eq!(indexed.len(), total - 1);
+        assert_eq!(indexed.get(3).unwrap(), last);
+        assert!(!indexed.contains(removed));
+    });
+}
 
-    assert_eq!(result, Err(Ok(ContractError::MaxProjectsExceeded.into())));
+#[test]
+fn test_project_review_index_swap_remove_across_pages() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let project_id = create_test_project(&client, &admin, "Paged-Reviews");
+    let total = REVIEW_INDEX_PAGE_SIZE + 2;
+
+    let mut reviewers = Vec::new(&env);
+    for _ in 0..total {
+        let reviewer = Address::generate(&env);
+        client.add_review(&project_id, &reviewer, &4, &None);
+        reviewers.push_back(reviewer);
+    }
+
+    // Removing the last entry only shrinks the last page.
+    client.delete_review(&project_id, &reviewers.get(total - 1).unwrap());
+    // Removing from the first page pulls the new last entry across pages.
+    client.delete_review(&project_id, &reviewers.get(0).unwrap());
+
+    let listed = client.list_reviews(&project_id, &0, &100);
+    assert_eq!(listed.len(), total - 2);
+    assert_eq!(
+        listed.get(0).unwrap().reviewer,
+        reviewers.get(total - 2).unwrap()
+    );
+    assert_eq!(listed.get(1).unwrap().reviewer, reviewers.get(1).unwrap());
+
+    // Re-adding after removal appends at the end.
+    client.add_review(&project_id, &reviewers.get(0).unwrap(), &2, &None);
+    let tail = client.list_reviews(&project_id, &(total - 2), &10);
+    assert_eq!(tail.len(), 1);
+    assert_eq!(tail.get(0).unwrap().reviewer, reviewers.get(0).unwrap());
+}
+
+#[test]
+fn test_legacy_review_index_is_migrated_on_write() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let project_id = create_test_project(&client, &admin, "Legacy-Index");
+
+    // Simulate an index written by an earlier contract version.
+    let legacy_a = Address::generate(&env);
+    let legacy_b = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        let mut legacy = Vec::new(&env);
+        legacy.push_back(legacy_a.clone());
+        legacy.push_back(legacy_b.clone());
+        env.storage()
+            .persistent()
+            .set(&StorageKey::ProjectReviews(project_id), &legacy);
+        assert_eq!(ReviewIndex::project_len(&env, project_id), 2);
+    });
+
+    let reviewer = Address::generate(&env);
+    client.add_review(&project_id, &reviewer, &5, &None);
+
+    env.as_contract(&client.address, || {
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&StorageKey::ProjectReviews(project_id)));
+        let indexed = ReviewIndex::project_reviewers(&env, project_id, 0, 10);
+        assert_eq!(indexed.len(), 3);
+        assert_eq!(indexed.get(0).unwrap(), legacy_a);
+        assert_eq!(indexed.get(1).unwrap(), legacy_b);
+        assert_eq!(indexed.get(2).unwrap(), reviewer);
+    });
 }
@@ -25,9 +25,24 @@ mod maintainers;
 mod renewal;
 mod review_history;
 mod review_settings;
+mod reviewer_reputation;
 mod security_contact;
 mod verification;
+mod verification_attestations;
+mod verification_checklist;
+mod verification_conflicts;
+mod verification_evidence;
+mod verification_expiry;
 mod verification_features;
+mod verification_history;
+mod verification_queue;
+mod verification_quorum;
+mod verification_suspension;
+mod verification_sweep;
+mod verification_tiers;
+mod verification_withdrawal;
+mod verified_reviews;
+mod verifiers;
 
 // String validation: names, descriptions, CIDs, categories, URLs
 mod license_metadata;
@@ -38,13 +53,18 @@ mod tags;
 // mod verified_freeze;
 
 // Fee token rotation and payment behavior
+mod fee_escrow;
+mod fee_multi_token;
+mod fee_payment_expiry;
+mod fee_revenue;
 mod fee_token_rotation;
+mod fee_treasury_splits;
 
 // Storage field size boundary tests
 mod field_limits;
 
 // Storage index size limits (owner projects, reviews)
-// mod index_limits;
+mod index_limits;
 
 // Security invariant tests: stats, owner index, verification, admin count
 mod invariants;
@@ -56,8 +76,10 @@ mod proptest_pagination;
 mod fee_boundary;
 // Issues #240, #241, #246: review tombstones, sorting, cooldown
 mod review_features;
+mod review_policy;
 
 // Test infrastructure
+mod blocklist;
 mod bookmarks;
 mod changelog;
 mod duplicate_dispute;
@@ -66,6 +88,8 @@ pub mod fixtures;
 mod issues_242_252_256;
 mod linked_projects;
 mod multisig_and_history;
+mod review_rounds;
+mod reviewer_reviews;
 mod subscriptions;
 mod timelock;
 mod ttl_batch;
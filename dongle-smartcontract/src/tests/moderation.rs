@@ -102,7 +102,7 @@ fn test_hide_review_success() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectE");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectE");
 
     let reviewer = Address::generate(&env);
     client.add_review(&project_id, &reviewer, &5, &None);
@@ -118,7 +118,7 @@ fn test_hide_review_updates_stats() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectF");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectF");
 
     let reviewer = Address::generate(&env);
     client.add_review(&project_id, &reviewer, &5, &None);
@@ -140,7 +140,7 @@ fn test_hide_review_already_hidden_fails() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectG");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectG");
 
     let reviewer = Address::generate(&env);
     client.add_review(&project_id, &reviewer, &5, &None);
@@ -170,7 +170,7 @@ fn test_hide_review_nonexistent_review_fails() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectI");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectI");
 
     let reviewer = Address::generate(&env);
 
@@ -199,7 +199,7 @@ fn test_restore_review_success() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectJ");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectJ");
 
     let reviewer = Address::generate(&env);
     client.add_review(&project_id, &reviewer, &5, &None);
@@ -216,7 +216,7 @@ fn test_restore_review_updates_stats() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectK");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectK");
 
     let reviewer = Address::generate(&env);
     client.add_review(&project_id, &reviewer, &5, &None);
@@ -238,7 +238,7 @@ fn test_restore_review_not_hidden_fails() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectL");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectL");
 
     let reviewer = Address::generate(&env);
     client.add_review(&project_id, &reviewer, &5, &None);
@@ -252,7 +252,7 @@ fn test_restore_review_non_admin_fails() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectM");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectM");
 
     let reviewer = Address::generate(&env);
     let non_admin = Address::generate(&env);
@@ -268,7 +268,7 @@ fn test_restore_review_nonexistent_review_fails() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectN");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectN");
 
     let reviewer = Address::generate(&env);
 
@@ -285,7 +285,7 @@ fn test_list_reviews_excludes_hidden() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectO");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectO");
 
     let r1 = Address::generate(&env);
     let r2 = Address::generate(&env);
@@ -310,7 +310,7 @@ fn test_list_reviews_all_hidden() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectP");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectP");
 
     let r1 = Address::generate(&env);
     let r2 = Address::generate(&env);
@@ -333,7 +333,7 @@ fn test_hide_restore_hide_cycle() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectQ");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectQ");
 
     let reviewer = Address::generate(&env);
     client.add_review(&project_id, &reviewer, &5, &None);
@@ -359,7 +359,7 @@ fn test_report_then_hide() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectR");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectR");
 
     let reviewer = Address::generate(&env);
     let reporter1 = Address::generate(&env);
@@ -385,7 +385,7 @@ fn test_stats_with_mixed_hidden_reviews() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectS");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectS");
 
     let r1 = Address::generate(&env);
     let r2 = Address::generate(&env);
@@ -425,7 +425,7 @@ fn test_get_review_returns_hidden_review() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectT");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectT");
 
     let reviewer = Address::generate(&env);
     client.add_review(&project_id, &reviewer, &5, &None);
@@ -442,8 +442,8 @@ fn test_multiple_projects_independent_moderation() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project1 = create_test_project(&client, &admin, "ProjectU");
-    let project2 = create_test_project(&client, &admin, "ProjectV");
+    let project1 = create_test_project(&client, &Address::generate(&env), "ProjectU");
+    let project2 = create_test_project(&client, &Address::generate(&env), "ProjectV");
 
     let reviewer = Address::generate(&env);
     client.add_review(&project1, &reviewer, &5, &None);
@@ -467,7 +467,7 @@ fn test_cannot_add_review_if_already_reviewed_and_hidden() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectW");
+    let project_id = create_test_project(&client, &Address::generate(&env), "ProjectW");
 
     let reviewer = Address::generate(&env);
     client.add_review(&project_id, &reviewer, &5, &None);
@@ -18,9 +18,9 @@ fn test_request_renewal_success() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectA");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectA");
 
-    let owner = admin.clone();
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
     // First verify the project
@@ -41,9 +41,9 @@ fn test_request_renewal_unverified_fails() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectB");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectB");
 
-    let owner = admin.clone();
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
     // Try to renew without verification
@@ -56,9 +56,9 @@ fn test_request_renewal_duplicate_fails() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectC");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectC");
 
-    let owner = admin.clone();
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
     // Verify the project
@@ -78,9 +78,9 @@ fn test_request_renewal_not_owner_fails() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectD");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectD");
 
-    let owner = admin.clone();
     let not_owner = Address::generate(&env);
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
@@ -102,9 +102,9 @@ fn test_approve_renewal_success() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectE");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectE");
 
-    let owner = admin.clone();
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
     // Verify the project
@@ -128,9 +128,9 @@ fn test_approve_renewal_updates_expiry() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectF");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectF");
 
-    let owner = admin.clone();
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
     // Verify the project
@@ -159,9 +159,9 @@ fn test_approve_renewal_non_admin_fails() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectG");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectG");
 
-    let owner = admin.clone();
     let non_admin = Address::generate(&env);
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
@@ -182,9 +182,9 @@ fn test_approve_renewal_not_found_fails() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectH");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectH");
 
-    let owner = admin.clone();
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
     // Verify the project
@@ -205,9 +205,9 @@ fn test_reject_renewal_success() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectI");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectI");
 
-    let owner = admin.clone();
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
     // Verify the project
@@ -238,9 +238,9 @@ fn test_reject_renewal_non_admin_fails() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectJ");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectJ");
 
-    let owner = admin.clone();
     let non_admin = Address::generate(&env);
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
@@ -261,9 +261,9 @@ fn test_reject_renewal_not_found_fails() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectK");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectK");
 
-    let owner = admin.clone();
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
     // Verify the project
@@ -284,9 +284,9 @@ fn test_renewal_history_single() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectL");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectL");
 
-    let owner = admin.clone();
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
     // Verify the project
@@ -307,9 +307,9 @@ fn test_renewal_history_multiple() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectM");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectM");
 
-    let owner = admin.clone();
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
     // Verify the project
@@ -332,9 +332,9 @@ fn test_renewal_history_pagination() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectN");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectN");
 
-    let owner = admin.clone();
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
     // Verify the project
@@ -367,9 +367,9 @@ fn test_is_verification_expired_not_expired() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectO");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectO");
 
-    let owner = admin.clone();
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
     // Verify the project
@@ -386,12 +386,13 @@ fn test_is_verification_expired_no_expiry() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectP");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectP");
 
-    let owner = admin.clone();
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
-    // Verify the project (without renewal, expires_at = 0)
+    // Verify the project with a zero duration at ledger time 0 (expires_at = 0)
+    client.set_verification_duration(&admin, &0);
     client.request_verification(&project_id, &owner, &evidence_cid);
     client.approve_verification(&project_id, &admin);
 
@@ -410,9 +411,9 @@ fn test_renewal_after_rejection() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectQ");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectQ");
 
-    let owner = admin.clone();
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
     // Verify the project
@@ -436,10 +437,10 @@ fn test_multiple_projects_independent_renewal() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project1 = create_test_project(&client, &admin, "ProjectR");
-    let project2 = create_test_project(&client, &admin, "ProjectS");
+    let owner = Address::generate(&env);
+    let project1 = create_test_project(&client, &owner, "ProjectR");
+    let project2 = create_test_project(&client, &owner, "ProjectS");
 
-    let owner = admin.clone();
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
     // Verify both projects
@@ -465,9 +466,9 @@ fn test_renewal_preserves_verification_status() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectT");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectT");
 
-    let owner = admin.clone();
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
     // Verify the project
@@ -496,9 +497,9 @@ fn test_renewal_updates_last_renewed_at() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectU");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectU");
 
-    let owner = admin.clone();
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
     // Verify the project
@@ -527,9 +528,9 @@ fn test_request_renewal_after_expiry_success() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "ProjectV");
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ProjectV");
 
-    let owner = admin.clone();
     let evidence_cid = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
 
     // Configure verification duration to 1000 seconds
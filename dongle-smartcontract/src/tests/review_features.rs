@@ -29,7 +29,7 @@ fn test_immediate_review_update_fails_with_cooldown() {
 
     // Immediate second update must fail — cooldown not elapsed.
     let result = client.try_update_review(&project_id, &reviewer, &5, &None);
-    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+    assert_eq!(result, Err(Ok(ContractError::ReviewCooldownActive)));
 }
 
 #[test]
@@ -101,7 +101,8 @@ fn test_admin_delete_review_leaves_tombstone() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, admin) = setup(&env);
-    let project_id = create_test_project(&client, &admin, "AdminTombstoneProject");
+    let project_id =
+        create_test_project(&client, &Address::generate(&env), "AdminTombstoneProject");
     let reviewer = Address::generate(&env);
     client.add_review(&project_id, &reviewer, &3, &None);
 
@@ -0,0 +1,134 @@
+//! Tests for the configurable review edit policy.
+
+use crate::errors::ContractError;
+use crate::tests::fixtures::{create_test_project, setup_contract};
+use crate::types::{AdminActionType, ProposalPayload, ReviewPolicy};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    Address, Env, String,
+};
+
+const CID_A: &str = "QmPolicyReviewA12345678901234567890123456789012";
+const CID_B: &str = "QmPolicyReviewB12345678901234567890123456789012";
+
+fn policy(cooldown: u64, max_revisions: u32, window: u64, new_cid: bool) -> ReviewPolicy {
+    ReviewPolicy {
+        update_cooldown_seconds: cooldown,
+        max_revisions,
+        edit_window_seconds: window,
+        new_cid_on_rating_change: new_cid,
+    }
+}
+
+#[test]
+fn test_default_policy_matches_previous_constants() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+
+    let expected = policy(3600, 50, 0, false);
+    assert_eq!(client.get_review_policy(&None), expected);
+    assert_eq!(client.get_config().review_policy, expected);
+}
+
+#[test]
+fn test_global_policy_cooldown_and_revision_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "PolicyGlobal");
+    let reviewer = Address::generate(&env);
+    client.add_review(&project_id, &reviewer, &3, &None);
+
+    client.set_review_policy(&admin, &None, &policy(0, 2, 0, false));
+    assert_eq!(client.get_config().review_policy.max_revisions, 2);
+    let entry = client.list_admin_actions(&0, &1).get(0).unwrap();
+    assert_eq!(entry.action_type, AdminActionType::ReviewPolicyChanged);
+
+    // No cooldown: back-to-back updates succeed, history keeps the last two.
+    for rating in [4u32, 5, 2, 1] {
+        client.update_review(&project_id, &reviewer, &rating, &None);
+    }
+    assert_eq!(client.get_review_revision_count(&project_id, &reviewer), 2);
+
+    assert_eq!(
+        client.try_set_review_policy(&admin, &None, &policy(0, 0, 0, false)),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    assert_eq!(
+        client.try_set_review_policy(&admin, &None, &policy(0, 51, 0, false)),
+        Err(Ok(ContractError::InvalidInput))
+    );
+}
+
+#[test]
+fn test_project_override_edit_window_and_new_cid() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let strict = create_test_project(&client, &owner, "PolicyStrict");
+    let relaxed = create_test_project(&client, &owner, "PolicyRelaxed");
+    let reviewer = Address::generate(&env);
+    let cid_a = Some(String::from_str(&env, CID_A));
+    let cid_b = Some(String::from_str(&env, CID_B));
+    client.add_review(&strict, &reviewer, &3, &cid_a);
+    client.add_review(&relaxed, &reviewer, &3, &cid_a);
+
+    client.set_review_policy(&admin, &Some(strict), &policy(0, 50, 600, true));
+    assert!(
+        client
+            .get_review_policy(&Some(strict))
+            .new_cid_on_rating_change
+    );
+    assert!(
+        !client
+            .get_review_policy(&Some(relaxed))
+            .new_cid_on_rating_change
+    );
+
+    assert_eq!(
+        client.try_update_review(&strict, &reviewer, &4, &cid_a),
+        Err(Ok(ContractError::ReviewContentUnchanged))
+    );
+    client.update_review(&strict, &reviewer, &4, &cid_b);
+    // Same rating, same CID is not a rating change.
+    client.update_review(&strict, &reviewer, &4, &cid_b);
+    // The global policy still applies elsewhere.
+    client.update_review(&relaxed, &reviewer, &4, &cid_a);
+
+    env.ledger().with_mut(|l| l.timestamp += 601);
+    assert_eq!(
+        client.try_update_review(&strict, &reviewer, &5, &cid_a),
+        Err(Ok(ContractError::ReviewEditWindowClosed))
+    );
+
+    // Clearing the override falls back to the global policy (1 hour cooldown).
+    client.clear_project_review_policy(&admin, &strict);
+    env.ledger().with_mut(|l| l.timestamp += 3600);
+    client.update_review(&strict, &reviewer, &5, &cid_a);
+    assert_eq!(client.get_review(&strict, &reviewer).unwrap().rating, 5);
+}
+
+#[test]
+fn test_policy_changes_go_through_governance_when_multisig() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let admin2 = Address::generate(&env);
+    client.add_admin(&admin, &admin2);
+    client.set_admin_approval_threshold(&admin, &2);
+
+    let new_policy = policy(60, 10, 0, false);
+    assert_eq!(
+        client.try_set_review_policy(&admin, &None, &new_policy),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    let payload = ProposalPayload::SetReviewPolicy(None, new_policy.clone());
+    let proposal_id = client.create_proposal(&admin, &payload);
+    client.approve_proposal(&admin2, &proposal_id);
+    client.execute_proposal(&admin, &proposal_id);
+    assert_eq!(client.get_config().review_policy, new_policy);
+}
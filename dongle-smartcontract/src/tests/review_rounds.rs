@@ -0,0 +1,301 @@
+//! Tests for commit-reveal review rounds.
+
+use crate::errors::ContractError;
+use crate::tests::fixtures::{create_test_project, setup_contract};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    Address, BytesN, Env, String,
+};
+
+const COMMIT: u64 = 3_600;
+const REVEAL: u64 = 3_600;
+const CID: &str = "QmRoundReview123456789012345678901234567890123";
+
+fn advance(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|l| l.timestamp += seconds);
+}
+
+#[test]
+fn test_commit_reveal_round_happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "RoundHappy");
+
+    let round = client.start_review_round(&project_id, &owner, &COMMIT, &REVEAL);
+    assert_eq!(round.round_id, 1);
+    assert!(!round.finalized);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let cid = Some(String::from_str(&env, CID));
+    let alice_salt = BytesN::from_array(&env, &[1u8; 32]);
+    let bob_salt = BytesN::from_array(&env, &[2u8; 32]);
+    let alice_commit =
+        client.compute_review_commitment(&project_id, &1, &alice, &5, &cid, &alice_salt);
+    let bob_commit = client.compute_review_commitment(&project_id, &1, &bob, &3, &None, &bob_salt);
+    assert_ne!(alice_commit, bob_commit);
+
+    client.commit_review(&project_id, &alice, &alice_commit);
+    client.commit_review(&project_id, &bob, &bob_commit);
+    assert_eq!(
+        client.try_commit_review(&project_id, &alice, &alice_commit),
+        Err(Ok(ContractError::DuplicateReview))
+    );
+
+    advance(&env, COMMIT);
+    client.reveal_review(&project_id, &alice, &5, &cid, &alice_salt);
+    client.reveal_review(&project_id, &bob, &3, &None, &bob_salt);
+
+    // Revealed ratings stay out of the aggregate until the round is finalized.
+    assert_eq!(client.get_project_stats(&project_id).review_count, 0);
+    assert!(client.get_review(&project_id, &alice).is_none());
+
+    assert_eq!(
+        client.try_finalize_review_round(&project_id, &10),
+        Err(Ok(ContractError::ReviewRoundNotEnded))
+    );
+    advance(&env, REVEAL);
+    assert_eq!(client.finalize_review_round(&project_id, &10), 2);
+
+    let stats = client.get_project_stats(&project_id);
+    assert_eq!(stats.review_count, 2);
+    let review = client.get_review(&project_id, &alice).unwrap();
+    assert_eq!(review.content_cid, cid);
+
+    let round = client.get_review_round(&project_id).unwrap();
+    assert!(round.finalized);
+    assert_eq!(round.commit_count, 2);
+    assert_eq!(round.reveal_count, 2);
+
+    // With the round closed, plain reviews are allowed again, and a new round
+    // once the previous one has been over for as long as it ran.
+    let carol = Address::generate(&env);
+    client.add_review(&project_id, &carol, &4, &None);
+    assert_eq!(
+        client.try_start_review_round(&project_id, &owner, &COMMIT, &REVEAL),
+        Err(Ok(ContractError::ReviewRoundCooldown))
+    );
+    advance(&env, COMMIT + REVEAL - 1);
+    assert_eq!(
+        client.try_start_review_round(&project_id, &owner, &COMMIT, &REVEAL),
+        Err(Ok(ContractError::ReviewRoundCooldown))
+    );
+    advance(&env, 1);
+    let next = client.start_review_round(&project_id, &owner, &COMMIT, &REVEAL);
+    assert_eq!(next.round_id, 2);
+}
+
+#[test]
+fn test_round_blocks_direct_reviews_and_enforces_owner() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "RoundGuard");
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_start_review_round(&project_id, &stranger, &COMMIT, &REVEAL),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_start_review_round(&project_id, &owner, &0, &REVEAL),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    assert_eq!(
+        client.try_start_review_round(&project_id, &owner, &COMMIT, &(31 * 24 * 60 * 60)),
+        Err(Ok(ContractError::InvalidInput))
+    );
+
+    client.start_review_round(&project_id, &owner, &COMMIT, &REVEAL);
+    assert_eq!(
+        client.try_start_review_round(&project_id, &owner, &COMMIT, &REVEAL),
+        Err(Ok(ContractError::ReviewRoundActive))
+    );
+    assert_eq!(
+        client.try_add_review(&project_id, &stranger, &4, &None),
+        Err(Ok(ContractError::ReviewRoundActive))
+    );
+}
+
+#[test]
+fn test_commit_and_reveal_windows() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "RoundWindows");
+    let reviewer = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[7u8; 32]);
+    let commitment = client.compute_review_commitment(&project_id, &1, &reviewer, &4, &None, &salt);
+
+    assert_eq!(
+        client.try_commit_review(&project_id, &reviewer, &commitment),
+        Err(Ok(ContractError::NoActiveReviewRound))
+    );
+
+    client.start_review_round(&project_id, &owner, &COMMIT, &REVEAL);
+    client.commit_review(&project_id, &reviewer, &commitment);
+
+    assert_eq!(
+        client.try_reveal_review(&project_id, &reviewer, &4, &None, &salt),
+        Err(Ok(ContractError::NotInRevealWindow))
+    );
+
+    advance(&env, COMMIT);
+    let late = Address::generate(&env);
+    let late_commitment =
+        client.compute_review_commitment(&project_id, &1, &late, &4, &None, &salt);
+    assert_eq!(
+        client.try_commit_review(&project_id, &late, &late_commitment),
+        Err(Ok(ContractError::CommitPhaseClosed))
+    );
+    assert_eq!(
+        client.try_reveal_review(&project_id, &late, &4, &None, &salt),
+        Err(Ok(ContractError::CommitmentNotFound))
+    );
+    assert_eq!(
+        client.try_reveal_review(&project_id, &reviewer, &5, &None, &salt),
+        Err(Ok(ContractError::CommitmentMismatch))
+    );
+
+    advance(&env, REVEAL);
+    assert_eq!(
+        client.try_reveal_review(&project_id, &reviewer, &4, &None, &salt),
+        Err(Ok(ContractError::NotInRevealWindow))
+    );
+}
+
+#[test]
+fn test_unrevealed_commitments_expire_and_finalize_in_batches() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "RoundBatches");
+
+    client.start_review_round(&project_id, &owner, &COMMIT, &REVEAL);
+    let salt = BytesN::from_array(&env, &[9u8; 32]);
+    let mut revealers = soroban_sdk::Vec::new(&env);
+    for _ in 0..3 {
+        let reviewer = Address::generate(&env);
+        let commitment =
+            client.compute_review_commitment(&project_id, &1, &reviewer, &4, &None, &salt);
+        client.commit_review(&project_id, &reviewer, &commitment);
+        revealers.push_back(reviewer);
+    }
+    let silent = Address::generate(&env);
+    let commitment = client.compute_review_commitment(&project_id, &1, &silent, &4, &None, &salt);
+    client.commit_review(&project_id, &silent, &commitment);
+
+    advance(&env, COMMIT);
+    for reviewer in revealers.iter() {
+        client.reveal_review(&project_id, &reviewer, &4, &None, &salt);
+    }
+    advance(&env, REVEAL);
+
+    assert_eq!(client.finalize_review_round(&project_id, &2), 2);
+    assert!(!client.get_review_round(&project_id).unwrap().finalized);
+    assert_eq!(client.finalize_review_round(&project_id, &2), 1);
+
+    let round = client.get_review_round(&project_id).unwrap();
+    assert!(round.finalized);
+    assert_eq!(round.commit_count, 4);
+    assert_eq!(round.reveal_count, 3);
+    assert_eq!(client.get_project_stats(&project_id).review_count, 3);
+    assert!(client.get_review(&project_id, &silent).is_none());
+    assert_eq!(
+        client.try_finalize_review_round(&project_id, &2),
+        Err(Ok(ContractError::NoActiveReviewRound))
+    );
+}
+
+#[test]
+fn test_commitment_bound_to_reviewer_and_round() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "RoundBinding");
+    let other_project = create_test_project(&client, &owner, "RoundBindingOther");
+    let alice = Address::generate(&env);
+    let copycat = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[3u8; 32]);
+
+    let commitment = client.compute_review_commitment(&project_id, &1, &alice, &5, &None, &salt);
+    assert_ne!(
+        commitment,
+        client.compute_review_commitment(&project_id, &1, &copycat, &5, &None, &salt)
+    );
+    assert_ne!(
+        commitment,
+        client.compute_review_commitment(&project_id, &2, &alice, &5, &None, &salt)
+    );
+    assert_ne!(
+        commitment,
+        client.compute_review_commitment(&other_project, &1, &alice, &5, &None, &salt)
+    );
+
+    // Copying someone else's commitment does not let the copy be revealed.
+    client.start_review_round(&project_id, &owner, &COMMIT, &REVEAL);
+    client.commit_review(&project_id, &alice, &commitment);
+    client.commit_review(&project_id, &copycat, &commitment);
+    advance(&env, COMMIT);
+    client.reveal_review(&project_id, &alice, &5, &None, &salt);
+    assert_eq!(
+        client.try_reveal_review(&project_id, &copycat, &5, &None, &salt),
+        Err(Ok(ContractError::CommitmentMismatch))
+    );
+}
+
+#[test]
+fn test_blocklist_and_disabled_reviews_apply_to_reveals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "RoundBlocked");
+    let salt = BytesN::from_array(&env, &[4u8; 32]);
+    let blocked = Address::generate(&env);
+    let later_blocked = Address::generate(&env);
+    let honest = Address::generate(&env);
+    let reason = String::from_str(&env, CID);
+
+    client.start_review_round(&project_id, &owner, &COMMIT, &REVEAL);
+    for reviewer in [&blocked, &later_blocked, &honest] {
+        let commitment =
+            client.compute_review_commitment(&project_id, &1, reviewer, &4, &None, &salt);
+        client.commit_review(&project_id, reviewer, &commitment);
+    }
+    advance(&env, COMMIT);
+
+    client.block_address(&admin, &blocked, &reason, &None);
+    assert_eq!(
+        client.try_reveal_review(&project_id, &blocked, &4, &None, &salt),
+        Err(Ok(ContractError::AddressBlocked))
+    );
+    client.reveal_review(&project_id, &later_blocked, &4, &None, &salt);
+    client.reveal_review(&project_id, &honest, &4, &None, &salt);
+
+    // Blocked after revealing: the reveal is dropped at finalization.
+    client.block_address(&admin, &later_blocked, &reason, &None);
+    advance(&env, REVEAL);
+    assert_eq!(client.finalize_review_round(&project_id, &10), 1);
+    assert!(client.get_review(&project_id, &later_blocked).is_none());
+    assert!(client.get_review(&project_id, &honest).is_some());
+
+    // With reviews disabled, reveals are refused and pending ones dropped.
+    advance(&env, COMMIT + REVEAL);
+    client.start_review_round(&project_id, &owner, &COMMIT, &REVEAL);
+    let late = Address::generate(&env);
+    let commitment = client.compute_review_commitment(&project_id, &2, &late, &3, &None, &salt);
+    client.commit_review(&project_id, &late, &commitment);
+    advance(&env, COMMIT);
+    client.set_reviews_enabled(&project_id, &owner, &false);
+    assert_eq!(
+        client.try_reveal_review(&project_id, &late, &3, &None, &salt),
+        Err(Ok(ContractError::ReviewsDisabled))
+    );
+}
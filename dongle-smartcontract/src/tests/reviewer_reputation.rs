@@ -0,0 +1,195 @@
+//! Tests for reviewer reputation scores and the reputation-weighted rating.
+
+use crate::errors::ContractError;
+use crate::tests::fixtures::{create_test_project, setup_contract};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    Address, Env, String,
+};
+
+const DAY: u64 = 86_400;
+
+#[test]
+fn test_fresh_reviewer_has_base_score() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let reviewer = Address::generate(&env);
+
+    let reputation = client.get_reviewer_reputation(&reviewer);
+    assert_eq!(reputation.score, 100);
+    assert_eq!(reputation.account_age_seconds, 0);
+    assert_eq!(reputation.helpful_votes, 0);
+    assert!(!reputation.owns_verified_project);
+}
+
+#[test]
+fn test_account_age_raises_score() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let project_id = create_test_project(&client, &admin, "AgeProject");
+    let reviewer = Address::generate(&env);
+    client.add_review(&project_id, &reviewer, &4, &None);
+
+    env.ledger().with_mut(|l| l.timestamp += 10 * DAY);
+    let reputation = client.get_reviewer_reputation(&reviewer);
+    assert_eq!(reputation.account_age_seconds, 10 * DAY);
+    assert_eq!(reputation.score, 110);
+
+    // Age bonus is capped.
+    env.ledger().with_mut(|l| l.timestamp += 1_000 * DAY);
+    assert_eq!(client.get_reviewer_reputation(&reviewer).score, 200);
+}
+
+#[test]
+fn test_helpful_votes_raise_score_once_per_voter() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let history_project = create_test_project(&client, &admin, "VoterHistory");
+    let project_id = create_test_project(&client, &admin, "HelpfulProject");
+    let reviewer = Address::generate(&env);
+    let voter = Address::generate(&env);
+    let veteran = Address::generate(&env);
+    client.add_review(&history_project, &voter, &4, &None);
+    client.add_review(&history_project, &veteran, &4, &None);
+    client.add_review(&project_id, &reviewer, &4, &None);
+
+    // Fresh addresses cannot vote.
+    let result = client.try_vote_review_helpful(&project_id, &reviewer, &voter);
+    assert_eq!(result, Err(Ok(ContractError::ReputationTooLow)));
+    let result = client.try_vote_review_helpful(&project_id, &reviewer, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(ContractError::ReputationTooLow)));
+
+    // 30 days of history (score 130) is worth 6 points.
+    env.ledger().with_mut(|l| l.timestamp += 30 * DAY);
+    client.vote_review_helpful(&project_id, &reviewer, &voter);
+    assert!(client.has_voted_helpful(&project_id, &reviewer, &voter));
+    let reputation = client.get_reviewer_reputation(&reviewer);
+    assert_eq!(reputation.helpful_votes, 1);
+    assert_eq!(reputation.score, 130 + 6);
+
+    let result = client.try_vote_review_helpful(&project_id, &reviewer, &voter);
+    assert_eq!(result, Err(Ok(ContractError::AlreadyVoted)));
+
+    let result = client.try_vote_review_helpful(&project_id, &reviewer, &reviewer);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    // A voter with 100 days of history (score 200) is worth 10 points.
+    env.ledger().with_mut(|l| l.timestamp += 70 * DAY);
+    client.vote_review_helpful(&project_id, &reviewer, &veteran);
+    let reputation = client.get_reviewer_reputation(&reviewer);
+    assert_eq!(reputation.helpful_votes, 2);
+    assert_eq!(reputation.score, 200 + 16);
+}
+
+#[test]
+fn test_hidden_review_penalty_is_lifted_on_restore() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let project_id = create_test_project(&client, &Address::generate(&env), "HiddenProject");
+    let reviewer = Address::generate(&env);
+    client.add_review(&project_id, &reviewer, &1, &None);
+
+    client.hide_review(&project_id, &reviewer, &admin);
+    let reputation = client.get_reviewer_reputation(&reviewer);
+    assert_eq!(reputation.hidden_reviews, 1);
+    assert_eq!(reputation.score, 75);
+
+    client.restore_review(&project_id, &reviewer, &admin);
+    let reputation = client.get_reviewer_reputation(&reviewer);
+    assert_eq!(reputation.hidden_reviews, 0);
+    assert_eq!(reputation.score, 100);
+}
+
+#[test]
+fn test_admin_deleted_reviews_penalize_and_score_is_clamped() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let reviewer = Address::generate(&env);
+
+    for name in ["DelA", "DelB", "DelC", "DelD", "DelE"] {
+        let project_id = create_test_project(&client, &Address::generate(&env), name);
+        client.add_review(&project_id, &reviewer, &1, &None);
+        client.admin_delete_review(&project_id, &reviewer, &admin);
+    }
+
+    let reputation = client.get_reviewer_reputation(&reviewer);
+    assert_eq!(reputation.deleted_reviews, 5);
+    assert_eq!(reputation.score, 10);
+}
+
+#[test]
+fn test_verified_project_owner_bonus() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "VerifiedOwned");
+
+    assert!(!client.get_reviewer_reputation(&owner).owns_verified_project);
+
+    let evidence = String::from_str(&env, "QmTestEvidenceCid123456789012345678901234567890");
+    client.request_verification(&project_id, &owner, &evidence);
+    client.approve_verification(&project_id, &admin);
+
+    let reputation = client.get_reviewer_reputation(&owner);
+    assert!(reputation.owns_verified_project);
+    assert_eq!(reputation.score, 150);
+}
+
+#[test]
+fn test_reputation_weighted_rating_favours_established_reviewers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let veteran_project = create_test_project(&client, &admin, "VeteranHistory");
+    let project_id = create_test_project(&client, &admin, "WeightedProject");
+
+    // Veteran: 100 days of history -> score 200.
+    let veteran = Address::generate(&env);
+    client.add_review(&veteran_project, &veteran, &3, &None);
+    env.ledger().with_mut(|l| l.timestamp += 100 * DAY);
+    client.add_review(&project_id, &veteran, &5, &None);
+
+    // Fresh sybil -> score 100.
+    let sybil = Address::generate(&env);
+    client.add_review(&project_id, &sybil, &1, &None);
+
+    assert_eq!(client.get_project_stats(&project_id).average_rating, 300);
+    // (500 * 200 + 100 * 100) / 300 = 366
+    assert_eq!(client.get_reputation_weighted_rating(&project_id), 366);
+}
+
+#[test]
+fn test_reputation_weighted_rating_tracks_review_lifecycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let project_id = create_test_project(&client, &Address::generate(&env), "LifecycleProject");
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+
+    assert_eq!(client.get_reputation_weighted_rating(&project_id), 0);
+
+    client.add_review(&project_id, &a, &4, &None);
+    client.add_review(&project_id, &b, &2, &None);
+    assert_eq!(client.get_reputation_weighted_rating(&project_id), 300);
+
+    client.update_review(&project_id, &b, &5, &None);
+    assert_eq!(client.get_reputation_weighted_rating(&project_id), 450);
+
+    // Hiding drops b's rating from the weighted average; restoring adds it back.
+    client.hide_review(&project_id, &b, &admin);
+    assert_eq!(client.get_reputation_weighted_rating(&project_id), 400);
+
+    client.restore_review(&project_id, &b, &admin);
+    assert_eq!(client.get_reputation_weighted_rating(&project_id), 450);
+
+    client.delete_review(&project_id, &a);
+    client.delete_review(&project_id, &b);
+    assert_eq!(client.get_reputation_weighted_rating(&project_id), 0);
+}
@@ -0,0 +1,89 @@
+//! Tests for listing a reviewer's full review record.
+
+use crate::tests::fixtures::{create_test_project, setup_contract};
+use crate::types::ReviewRecord;
+use soroban_sdk::{testutils::Address as _, Address, Env, Vec};
+
+fn project_of(record: &ReviewRecord) -> u64 {
+    match record {
+        ReviewRecord::Active(review) | ReviewRecord::Hidden(review) => review.project_id,
+        ReviewRecord::Deleted(tombstone) => tombstone.project_id,
+    }
+}
+
+#[test]
+fn test_list_reviews_by_reviewer_marks_hidden_and_deleted() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let reviewer = Address::generate(&env);
+
+    let mut projects = Vec::new(&env);
+    for name in ["PortfolioA", "PortfolioB", "PortfolioC", "PortfolioD"] {
+        let project_id = create_test_project(&client, &owner, name);
+        client.add_review(&project_id, &reviewer, &4, &None);
+        projects.push_back(project_id);
+    }
+    let (a, b, c, d) = (
+        projects.get(0).unwrap(),
+        projects.get(1).unwrap(),
+        projects.get(2).unwrap(),
+        projects.get(3).unwrap(),
+    );
+    client.hide_review(&b, &reviewer, &admin);
+    client.delete_review(&c, &reviewer);
+    client.admin_delete_review(&d, &reviewer, &admin);
+
+    let first = client.list_reviews_by_reviewer(&reviewer, &0, &3);
+    assert_eq!(first.entries.len(), 3);
+    assert_eq!(first.next_cursor, Some(3));
+    let second = client.list_reviews_by_reviewer(&reviewer, &3, &3);
+    assert_eq!(second.entries.len(), 1);
+    assert_eq!(second.next_cursor, None);
+
+    let mut all = first.entries.clone();
+    all.append(&second.entries);
+    let record_for = |project_id: u64| {
+        all.iter()
+            .find(|record| project_of(record) == project_id)
+            .unwrap()
+    };
+
+    assert!(matches!(record_for(a), ReviewRecord::Active(review) if review.rating == 4));
+    assert!(matches!(record_for(b), ReviewRecord::Hidden(review) if review.hidden));
+    assert!(matches!(record_for(c), ReviewRecord::Deleted(_)));
+    assert!(matches!(record_for(d), ReviewRecord::Deleted(_)));
+}
+
+#[test]
+fn test_re_review_after_delete_is_listed_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let reviewer = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "PortfolioAgain");
+
+    client.add_review(&project_id, &reviewer, &2, &None);
+    client.delete_review(&project_id, &reviewer);
+    let page = client.list_reviews_by_reviewer(&reviewer, &0, &10);
+    assert_eq!(page.entries.len(), 1);
+    assert!(matches!(
+        page.entries.get(0).unwrap(),
+        ReviewRecord::Deleted(_)
+    ));
+
+    client.add_review(&project_id, &reviewer, &5, &None);
+    let page = client.list_reviews_by_reviewer(&reviewer, &0, &10);
+    assert_eq!(page.entries.len(), 1);
+    assert!(matches!(
+        page.entries.get(0).unwrap(),
+        ReviewRecord::Active(review) if review.rating == 5
+    ));
+
+    let stranger = Address::generate(&env);
+    let empty = client.list_reviews_by_reviewer(&stranger, &0, &10);
+    assert!(empty.entries.is_empty());
+    assert_eq!(empty.next_cursor, None);
+}
@@ -0,0 +1,194 @@
+//! Tests for off-chain attestations signed with ed25519.
+
+extern crate std;
+
+use crate::errors::ContractError;
+use crate::tests::fixtures::{create_test_project, setup_contract};
+use crate::types::AttestationPayload;
+use crate::DongleContractClient;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    vec, Address, BytesN, Env, String, Symbol, Vec,
+};
+
+/// Stand-in for an attestation service's key pair.
+fn attestor_key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+fn public_key(env: &Env, key: &SigningKey) -> BytesN<32> {
+    BytesN::from_array(env, &key.verifying_key().to_bytes())
+}
+
+fn payload(env: &Env, key: &SigningKey, nonce: u64, expires_at: u64) -> AttestationPayload {
+    AttestationPayload {
+        attestor: public_key(env, key),
+        subject: String::from_str(env, "github.com/dongle"),
+        nonce,
+        expires_at,
+    }
+}
+
+fn sign(
+    env: &Env,
+    client: &DongleContractClient<'_>,
+    key: &SigningKey,
+    project_id: u64,
+    claim_type: &Symbol,
+    payload: &AttestationPayload,
+) -> BytesN<64> {
+    let message = client.get_attestation_message(&project_id, claim_type, payload);
+    let message: std::vec::Vec<u8> = message.iter().collect();
+    BytesN::from_array(env, &key.sign(&message).to_bytes())
+}
+
+#[test]
+fn test_submit_signed_attestation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "AttestSigned");
+    let key = attestor_key(7);
+    let github = Symbol::new(&env, "github_org");
+
+    client.register_attestor(
+        &admin,
+        &public_key(&env, &key),
+        &String::from_str(&env, "GitHub checker"),
+        &vec![&env, github.clone()],
+    );
+    assert_eq!(client.list_attestors().len(), 1);
+
+    let body = payload(&env, &key, 1, 2_000);
+    let signature = sign(&env, &client, &key, project_id, &github, &body);
+    client.submit_attestation(&project_id, &github, &body, &signature);
+    assert!(client.has_attestation(&project_id, &github));
+    let stored = client.get_project_attestations(&project_id);
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored.get(0).unwrap().payload, body);
+
+    // The same signed payload cannot be submitted twice.
+    assert_eq!(
+        client.try_submit_attestation(&project_id, &github, &body, &signature),
+        Err(Ok(ContractError::AttestationReplayed))
+    );
+
+    // A fresh attestation for the same claim replaces the stored one.
+    let renewed = payload(&env, &key, 2, 3_000);
+    let signature = sign(&env, &client, &key, project_id, &github, &renewed);
+    client.submit_attestation(&project_id, &github, &renewed, &signature);
+    assert_eq!(client.get_project_attestations(&project_id).len(), 1);
+
+    // An older signed payload cannot roll the stored attestation back.
+    let older = payload(&env, &key, 0, 3_000);
+    let signature = sign(&env, &client, &key, project_id, &github, &older);
+    assert_eq!(
+        client.try_submit_attestation(&project_id, &github, &older, &signature),
+        Err(Ok(ContractError::AttestationReplayed))
+    );
+    assert_eq!(
+        client
+            .get_project_attestations(&project_id)
+            .get(0)
+            .unwrap()
+            .payload,
+        renewed
+    );
+
+    // Subjects are bounded like CIDs.
+    let mut oversized = payload(&env, &key, 3, 3_000);
+    oversized.subject = String::from_str(&env, &"a".repeat(129));
+    let signature = sign(&env, &client, &key, project_id, &github, &oversized);
+    assert_eq!(
+        client.try_submit_attestation(&project_id, &github, &oversized, &signature),
+        Err(Ok(ContractError::InvalidInput))
+    );
+
+    client.revoke_attestor(&admin, &public_key(&env, &key));
+    assert!(!client.has_attestation(&project_id, &github));
+}
+
+#[test]
+fn test_rejects_bad_signature_and_untrusted_claims() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "AttestInvalid");
+    let other_project = create_test_project(&client, &owner, "AttestOther");
+    let key = attestor_key(7);
+    let dns = Symbol::new(&env, "dns");
+    let github = Symbol::new(&env, "github_org");
+
+    let body = payload(&env, &key, 1, 2_000);
+    let signature = sign(&env, &client, &key, project_id, &dns, &body);
+    assert_eq!(
+        client.try_submit_attestation(&project_id, &dns, &body, &signature),
+        Err(Ok(ContractError::AttestorNotFound))
+    );
+
+    client.register_attestor(
+        &admin,
+        &public_key(&env, &key),
+        &String::from_str(&env, "DNS checker"),
+        &vec![&env, dns.clone()],
+    );
+    assert_eq!(
+        client.try_submit_attestation(&project_id, &github, &body, &signature),
+        Err(Ok(ContractError::AttestorOutOfScope))
+    );
+
+    // Signatures are bound to the project and to the signing key.
+    assert!(client
+        .try_submit_attestation(&other_project, &dns, &body, &signature)
+        .is_err());
+    let forged = sign(&env, &client, &attestor_key(9), project_id, &dns, &body);
+    assert!(client
+        .try_submit_attestation(&project_id, &dns, &body, &forged)
+        .is_err());
+    assert!(client.get_project_attestations(&project_id).is_empty());
+
+    env.ledger().with_mut(|l| l.timestamp = 2_000);
+    assert_eq!(
+        client.try_submit_attestation(&project_id, &dns, &body, &signature),
+        Err(Ok(ContractError::AttestationExpired))
+    );
+}
+
+#[test]
+fn test_attestor_registry_is_admin_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let key = public_key(&env, &attestor_key(7));
+    let name = String::from_str(&env, "Audit firm");
+    let outsider = Address::generate(&env);
+
+    assert_eq!(
+        client.try_register_attestor(&outsider, &key, &name, &Vec::new(&env)),
+        Err(Ok(ContractError::AdminOnly))
+    );
+    assert_eq!(
+        client.try_register_attestor(&admin, &key, &String::from_str(&env, ""), &Vec::new(&env)),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    client.register_attestor(&admin, &key, &name, &Vec::new(&env));
+    let attestor = client.get_attestor(&key).unwrap();
+    assert_eq!(attestor.registered_by, admin);
+    assert!(attestor.claim_types.is_empty());
+
+    assert_eq!(
+        client.try_revoke_attestor(&outsider, &key),
+        Err(Ok(ContractError::AdminOnly))
+    );
+    client.revoke_attestor(&admin, &key);
+    assert_eq!(
+        client.try_revoke_attestor(&admin, &key),
+        Err(Ok(ContractError::AttestorNotFound))
+    );
+    assert!(client.get_attestor(&key).is_none());
+}
@@ -0,0 +1,173 @@
+//! Tests for admin-defined verification checklists.
+
+use crate::errors::ContractError;
+use crate::tests::fixtures::{create_test_project, setup_contract};
+use crate::types::{ChecklistItem, ChecklistScope, VerificationStatus, VerificationTierParams};
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Symbol, Vec};
+
+const EVIDENCE: &str = "QmChecklistEvidence12345678901234567890123456";
+const NOTE: &str = "QmChecklistNote1234567890123456789012345678901";
+
+fn item(env: &Env, key: &str, required: bool) -> ChecklistItem {
+    ChecklistItem {
+        key: Symbol::new(env, key),
+        label: String::from_str(env, key),
+        required,
+    }
+}
+
+fn defi(env: &Env) -> ChecklistScope {
+    ChecklistScope::Category(String::from_str(env, "DeFi"))
+}
+
+#[test]
+fn test_approval_requires_every_required_item() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    client.set_verification_checklist(
+        &admin,
+        &defi(&env),
+        &vec![
+            &env,
+            item(&env, "domain_ownership", true),
+            item(&env, "repo_ownership", true),
+            item(&env, "audit_report", false),
+        ],
+    );
+    let project_id = create_test_project(&client, &owner, "ChecklistApprove");
+    client.request_verification(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+    assert_eq!(client.get_project_checklist(&project_id).len(), 3);
+
+    client.attest_checklist_item(
+        &project_id,
+        &admin,
+        &Symbol::new(&env, "domain_ownership"),
+        &Some(String::from_str(&env, NOTE)),
+    );
+    assert_eq!(
+        client.try_approve_verification(&project_id, &admin),
+        Err(Ok(ContractError::ChecklistIncomplete))
+    );
+
+    // The optional item is not needed; the outcome stays on the record.
+    client.attest_checklist_item(
+        &project_id,
+        &admin,
+        &Symbol::new(&env, "repo_ownership"),
+        &None,
+    );
+    client.approve_verification(&project_id, &admin);
+    let record = client.get_verification(&project_id).unwrap();
+    assert_eq!(record.status, VerificationStatus::Verified);
+    assert_eq!(record.checklist.len(), 2);
+    let tick = record.checklist.get(0).unwrap();
+    assert_eq!(tick.verifier, admin);
+    assert_eq!(tick.note_cid, Some(String::from_str(&env, NOTE)));
+
+    assert_eq!(
+        client.try_attest_checklist_item(
+            &project_id,
+            &admin,
+            &Symbol::new(&env, "audit_report"),
+            &None
+        ),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_tier_checklist_merges_with_category() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let tier_id = client.create_verification_tier(
+        &admin,
+        &VerificationTierParams {
+            name: String::from_str(&env, "Audited"),
+            level: 1,
+            fee: 0,
+            duration_seconds: 1_000,
+            requirements_cid: String::from_str(&env, NOTE),
+            badge_cid: String::from_str(&env, NOTE),
+        },
+    );
+    client.set_verification_checklist(
+        &admin,
+        &defi(&env),
+        &vec![&env, item(&env, "audit_report", false)],
+    );
+    client.set_verification_checklist(
+        &admin,
+        &ChecklistScope::Tier(tier_id),
+        &vec![
+            &env,
+            item(&env, "audit_report", true),
+            item(&env, "kyc", true),
+        ],
+    );
+
+    let project_id = create_test_project(&client, &owner, "ChecklistTier");
+    client.request_tier_verification(
+        &project_id,
+        &owner,
+        &tier_id,
+        &String::from_str(&env, EVIDENCE),
+    );
+    let checklist = client.get_project_checklist(&project_id);
+    assert_eq!(checklist.len(), 2);
+    assert!(checklist.get(0).unwrap().required);
+
+    for key in ["audit_report", "kyc"] {
+        assert_eq!(
+            client.try_approve_verification(&project_id, &admin),
+            Err(Ok(ContractError::ChecklistIncomplete))
+        );
+        client.attest_checklist_item(&project_id, &admin, &Symbol::new(&env, key), &None);
+    }
+    client.approve_verification(&project_id, &admin);
+}
+
+#[test]
+fn test_checklist_validation_and_scope() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_verification_checklist(&owner, &defi(&env), &Vec::new(&env)),
+        Err(Ok(ContractError::AdminOnly))
+    );
+    assert_eq!(
+        client.try_set_verification_checklist(&admin, &ChecklistScope::Tier(9), &Vec::new(&env)),
+        Err(Ok(ContractError::VerificationTierNotFound))
+    );
+    assert_eq!(
+        client.try_set_verification_checklist(
+            &admin,
+            &defi(&env),
+            &vec![&env, item(&env, "kyc", true), item(&env, "kyc", false)],
+        ),
+        Err(Ok(ContractError::InvalidInput))
+    );
+
+    client.set_verification_checklist(&admin, &defi(&env), &vec![&env, item(&env, "kyc", true)]);
+    let project_id = create_test_project(&client, &owner, "ChecklistScope");
+    client.request_verification(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+    assert_eq!(
+        client.try_attest_checklist_item(&project_id, &admin, &Symbol::new(&env, "other"), &None),
+        Err(Ok(ContractError::ChecklistItemNotFound))
+    );
+    assert_eq!(
+        client.try_attest_checklist_item(&project_id, &owner, &Symbol::new(&env, "kyc"), &None),
+        Err(Ok(ContractError::AdminOnly))
+    );
+
+    // Removing the checklist lifts the requirement for pending requests.
+    client.set_verification_checklist(&admin, &defi(&env), &Vec::new(&env));
+    assert!(client.get_verification_checklist(&defi(&env)).is_empty());
+    client.approve_verification(&project_id, &admin);
+}
@@ -0,0 +1,188 @@
+//! Tests for conflict-of-interest guards and recusals.
+
+use crate::errors::ContractError;
+use crate::tests::fixtures::{create_test_project, setup_contract};
+use crate::types::{AdminActionType, DisputeResolutionAction, ProposalPayload, VerificationStatus};
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Symbol};
+
+const EVIDENCE: &str = "QmConflictEvidence12345678901234567890123456";
+
+#[test]
+fn test_owner_and_affiliated_admins_cannot_decide() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let evidence = String::from_str(&env, EVIDENCE);
+
+    // An admin who owns the project can neither approve nor moderate it.
+    let own = create_test_project(&client, &admin, "AdminOwned");
+    client.request_verification(&own, &admin, &evidence);
+    assert!(client.has_conflict_of_interest(&own, &admin));
+    assert_eq!(
+        client.try_approve_verification(&own, &admin),
+        Err(Ok(ContractError::ConflictOfInterest))
+    );
+    let reviewer = Address::generate(&env);
+    client.add_review(&own, &reviewer, &4, &None);
+    assert_eq!(
+        client.try_hide_review(&own, &reviewer, &admin),
+        Err(Ok(ContractError::ConflictOfInterest))
+    );
+
+    // Another admin without ties can.
+    let other_admin = Address::generate(&env);
+    client.add_admin(&admin, &other_admin);
+    assert!(!client.has_conflict_of_interest(&own, &other_admin));
+    client.approve_verification(&own, &other_admin);
+
+    // Maintainers and endorsers are conflicted too.
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "Affiliated");
+    client.request_verification(&project_id, &owner, &evidence);
+    client.add_maintainer(&project_id, &owner, &admin);
+    assert_eq!(
+        client.try_approve_verification(&project_id, &admin),
+        Err(Ok(ContractError::ConflictOfInterest))
+    );
+    client.endorse_project(&project_id, &other_admin);
+    assert_eq!(
+        client.try_approve_verification(&project_id, &other_admin),
+        Err(Ok(ContractError::ConflictOfInterest))
+    );
+}
+
+#[test]
+fn test_recusal_is_logged_and_blocks_decisions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "Recused");
+    client.request_verification(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+
+    // Only admins and verifiers can recuse.
+    assert_eq!(
+        client.try_recuse_from_project(&project_id, &owner),
+        Err(Ok(ContractError::AdminOnly))
+    );
+    assert_eq!(
+        client.try_recuse_from_project(&99, &admin),
+        Err(Ok(ContractError::ProjectNotFound))
+    );
+
+    client.recuse_from_project(&project_id, &admin);
+    assert_eq!(
+        client.get_project_recusals(&project_id),
+        vec![&env, admin.clone()]
+    );
+    let action = client.list_admin_actions(&0, &1).get(0).unwrap();
+    assert_eq!(action.action_type, AdminActionType::Recused);
+    assert_eq!(action.target_id, Some(project_id));
+    assert_eq!(
+        client.try_recuse_from_project(&project_id, &admin),
+        Err(Ok(ContractError::InvalidInput))
+    );
+
+    assert_eq!(
+        client.try_approve_verification(&project_id, &admin),
+        Err(Ok(ContractError::ConflictOfInterest))
+    );
+    assert_eq!(
+        client.try_clear_project_reports(&project_id, &admin),
+        Err(Ok(ContractError::ConflictOfInterest))
+    );
+}
+
+#[test]
+fn test_claims_and_disputes_reject_conflicted_admins() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let other_admin = Address::generate(&env);
+    client.add_admin(&admin, &other_admin);
+    let owner = Address::generate(&env);
+    let evidence = String::from_str(&env, EVIDENCE);
+
+    // An admin cannot approve their own claim.
+    let project_id = create_test_project(&client, &owner, "Claimed");
+    client.set_project_claimable(&project_id, &owner, &true);
+    let claim_id = client.submit_claim_request(&project_id, &admin, &evidence);
+    assert_eq!(
+        client.try_approve_claim_request(&claim_id, &admin),
+        Err(Ok(ContractError::ConflictOfInterest))
+    );
+    client.approve_claim_request(&claim_id, &other_admin);
+
+    // Nor resolve a dispute they opened or one touching a project they own.
+    let original = create_test_project(&client, &owner, "Original");
+    let duplicate = create_test_project(&client, &Address::generate(&env), "Duplicate");
+    let dispute_id = client.open_duplicate_dispute(&duplicate, &original, &admin, &evidence);
+    assert_eq!(
+        client.try_resolve_duplicate_dispute(&dispute_id, &admin, &DisputeResolutionAction::Reject),
+        Err(Ok(ContractError::ConflictOfInterest))
+    );
+    let dispute_id = client.open_duplicate_dispute(&project_id, &original, &owner, &evidence);
+    assert_eq!(
+        client.try_resolve_duplicate_dispute(&dispute_id, &admin, &DisputeResolutionAction::Reject),
+        Err(Ok(ContractError::ConflictOfInterest))
+    );
+    client.resolve_duplicate_dispute(&dispute_id, &other_admin, &DisputeResolutionAction::Reject);
+}
+
+#[test]
+fn test_proposals_checklists_and_bulk_hiding_reject_conflicted_admins() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+    client.add_admin(&admin, &second);
+    client.add_admin(&admin, &third);
+    client.set_admin_approval_threshold(&admin, &2);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ConflictProposal");
+    client.request_verification(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+    client.recuse_from_project(&project_id, &admin);
+
+    // A recused admin can neither propose nor co-sign a decision.
+    assert_eq!(
+        client.try_create_proposal(&admin, &ProposalPayload::ApproveVerification(project_id)),
+        Err(Ok(ContractError::ConflictOfInterest))
+    );
+    let proposal_id =
+        client.create_proposal(&second, &ProposalPayload::RejectVerification(project_id));
+    assert_eq!(
+        client.try_approve_proposal(&admin, &proposal_id),
+        Err(Ok(ContractError::ConflictOfInterest))
+    );
+
+    // Signers are checked again when the proposal is executed.
+    client.approve_proposal(&third, &proposal_id);
+    client.endorse_project(&project_id, &third);
+    assert_eq!(
+        client.try_execute_proposal(&second, &proposal_id),
+        Err(Ok(ContractError::ConflictOfInterest))
+    );
+    assert_eq!(
+        client.get_project(&project_id).unwrap().verification_status,
+        VerificationStatus::Pending
+    );
+
+    assert_eq!(
+        client.try_attest_checklist_item(&project_id, &admin, &Symbol::new(&env, "audit"), &None),
+        Err(Ok(ContractError::ConflictOfInterest))
+    );
+
+    // Bulk hiding skips the reviews the admin is conflicted on.
+    let other = create_test_project(&client, &owner, "ConflictOther");
+    let reviewer = Address::generate(&env);
+    client.add_review(&project_id, &reviewer, &1, &None);
+    client.add_review(&other, &reviewer, &1, &None);
+    client.block_address(&admin, &reviewer, &String::from_str(&env, EVIDENCE), &None);
+    assert_eq!(
+        client.hide_reviews_by_reviewer(&admin, &reviewer, &0, &10),
+        1
+    );
+    assert!(!client.get_review(&project_id, &reviewer).unwrap().hidden);
+    assert!(client.get_review(&other, &reviewer).unwrap().hidden);
+}
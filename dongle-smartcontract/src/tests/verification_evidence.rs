@@ -0,0 +1,185 @@
+//! Tests for on-chain evidence hash commitments.
+
+use crate::errors::ContractError;
+use crate::tests::fixtures::{create_test_project, setup_contract};
+use crate::types::{EvidenceProofType, EvidenceSummary, VerificationStatus};
+use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, BytesN, Env, String};
+
+const EVIDENCE: &str = "QmEvidenceHash123456789012345678901234567890";
+const EVIDENCE_V2: &str = "QmEvidenceHashV223456789012345678901234567890";
+
+fn document_hash(env: &Env, document: &str) -> BytesN<32> {
+    env.crypto()
+        .sha256(&Bytes::from_slice(env, document.as_bytes()))
+        .into()
+}
+
+fn summary(env: &Env) -> EvidenceSummary {
+    EvidenceSummary {
+        proof_count: 3,
+        proof_types: vec![env, EvidenceProofType::Repository, EvidenceProofType::Audit],
+        attestation_count: 1,
+        signature_count: 0,
+        contains_personal_data: false,
+    }
+}
+
+#[test]
+fn test_commit_and_verify_evidence_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "EvidenceHash");
+    let cid = String::from_str(&env, EVIDENCE);
+    let hash = document_hash(&env, "{\"version\":\"1.0.0\"}");
+
+    client.request_verification(&project_id, &owner, &cid);
+    assert!(!client.verify_evidence_hash(&project_id, &hash));
+
+    client.commit_verification_evidence(&project_id, &owner, &cid, &hash, &summary(&env));
+    let request_id = client.get_verification(&project_id).unwrap().request_id;
+    let commitment = client.get_evidence_commitment(&request_id).unwrap();
+    assert_eq!(commitment.evidence_cid, cid);
+    assert_eq!(commitment.summary, summary(&env));
+    assert!(client.verify_evidence_hash(&project_id, &hash));
+    assert!(!client.verify_evidence_hash(&project_id, &document_hash(&env, "{}")));
+
+    // The commitment is fixed once the request is decided.
+    client.approve_verification(&project_id, &admin);
+    assert_eq!(
+        client.get_project(&project_id).unwrap().verification_status,
+        VerificationStatus::Verified
+    );
+    assert!(client.verify_evidence_hash(&project_id, &hash));
+    assert_eq!(
+        client.try_commit_verification_evidence(
+            &project_id,
+            &owner,
+            &cid,
+            &document_hash(&env, "{}"),
+            &summary(&env)
+        ),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_commit_rejects_mismatched_cid_and_invalid_summary() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "EvidenceInvalid");
+    let cid = String::from_str(&env, EVIDENCE);
+    let hash = document_hash(&env, "evidence");
+    client.request_verification(&project_id, &owner, &cid);
+
+    assert_eq!(
+        client.try_commit_verification_evidence(
+            &project_id,
+            &owner,
+            &String::from_str(&env, EVIDENCE_V2),
+            &hash,
+            &summary(&env)
+        ),
+        Err(Ok(ContractError::EvidenceCidMismatch))
+    );
+
+    let mut no_proofs = summary(&env);
+    no_proofs.proof_count = 0;
+    let mut duplicate_types = summary(&env);
+    duplicate_types
+        .proof_types
+        .push_back(EvidenceProofType::Audit);
+    let mut too_many_signatures = summary(&env);
+    too_many_signatures.signature_count = 26;
+    for invalid in [no_proofs, duplicate_types, too_many_signatures] {
+        assert_eq!(
+            client.try_commit_verification_evidence(&project_id, &owner, &cid, &hash, &invalid),
+            Err(Ok(ContractError::InvalidInput))
+        );
+    }
+
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_commit_verification_evidence(
+            &project_id,
+            &outsider,
+            &cid,
+            &hash,
+            &summary(&env)
+        ),
+        Err(Ok(ContractError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_evidence_update_clears_commitment() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "EvidenceUpdate");
+    let cid = String::from_str(&env, EVIDENCE);
+    let hash = document_hash(&env, "evidence");
+    client.request_verification(&project_id, &owner, &cid);
+    client.commit_verification_evidence(&project_id, &owner, &cid, &hash, &summary(&env));
+
+    let new_cid = String::from_str(&env, EVIDENCE_V2);
+    client.update_verification_evidence(&project_id, &owner, &new_cid);
+    let request_id = client.get_verification(&project_id).unwrap().request_id;
+    assert!(client.get_evidence_commitment(&request_id).is_none());
+    assert!(!client.verify_evidence_hash(&project_id, &hash));
+
+    let new_hash = document_hash(&env, "evidence v2");
+    client.commit_verification_evidence(&project_id, &owner, &new_cid, &new_hash, &summary(&env));
+    assert!(client.verify_evidence_hash(&project_id, &new_hash));
+}
+
+#[test]
+fn test_sign_off_without_commitment_and_recommit_clears_it() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "EvidenceSignOff");
+    let cid = String::from_str(&env, EVIDENCE);
+    client.set_verification_quorum(&admin, &2);
+    client.request_verification(&project_id, &owner, &cid);
+
+    // A request without a commitment, such as one made before commitments
+    // existed, can still be signed off.
+    client.attest_verification(&project_id, &admin, &None);
+
+    let first = document_hash(&env, "first");
+    client.commit_verification_evidence(&project_id, &owner, &cid, &first, &summary(&env));
+    assert!(client
+        .get_verification(&project_id)
+        .unwrap()
+        .approvals
+        .is_empty());
+    client.attest_verification(&project_id, &admin, &None);
+    assert_eq!(
+        client
+            .get_verification(&project_id)
+            .unwrap()
+            .approvals
+            .len(),
+        1
+    );
+
+    // Sign-offs covered the first document, not the replacement.
+    let second = document_hash(&env, "second");
+    client.commit_verification_evidence(&project_id, &owner, &cid, &second, &summary(&env));
+    assert!(client
+        .get_verification(&project_id)
+        .unwrap()
+        .approvals
+        .is_empty());
+    client.attest_verification(&project_id, &admin, &None);
+    assert_eq!(
+        client.get_project(&project_id).unwrap().verification_status,
+        VerificationStatus::Pending
+    );
+}
@@ -0,0 +1,193 @@
+//! Tests for the verification expiry index and renewal reminders.
+
+use crate::constants::EXPIRY_BUCKET_SECONDS;
+use crate::errors::ContractError;
+use crate::storage_keys::FeatureKey;
+use crate::tests::fixtures::{create_test_project, setup_contract};
+use crate::types::ExpiryCursor;
+use crate::verification_registry::VerificationExpiryIndex;
+use crate::DongleContractClient;
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger as _},
+    vec, Address, Env, String, Vec,
+};
+
+const EVIDENCE: &str = "QmExpiryEvidence1234567890123456789012345678";
+const DAY: u64 = 24 * 60 * 60;
+
+fn verify(
+    env: &Env,
+    client: &DongleContractClient<'_>,
+    admin: &Address,
+    owner: &Address,
+    name: &str,
+) -> u64 {
+    let project_id = create_test_project(client, owner, name);
+    client.request_verification(&project_id, owner, &String::from_str(env, EVIDENCE));
+    client.approve_verification(&project_id, admin);
+    project_id
+}
+
+fn listed(client: &DongleContractClient<'_>, before_ts: u64) -> Vec<u64> {
+    let mut ids = Vec::new(&client.env);
+    for entry in client
+        .list_expiring_verifications(&before_ts, &ExpiryCursor::default(), &50)
+        .entries
+        .iter()
+    {
+        ids.push_back(entry.project_id);
+    }
+    ids
+}
+
+#[test]
+fn test_expiring_verifications_listed_soonest_first() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.set_verification_duration(&admin, &(10 * DAY));
+
+    let first = verify(&env, &client, &admin, &owner, "ExpiryFirst");
+    env.ledger().with_mut(|l| l.timestamp += 3 * DAY);
+    let second = verify(&env, &client, &admin, &owner, "ExpirySecond");
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    let third = verify(&env, &client, &admin, &owner, "ExpiryThird");
+
+    let first_expiry = client.get_verification(&first).unwrap().expires_at;
+    assert_eq!(listed(&client, first_expiry), vec![&env, first]);
+    assert_eq!(
+        listed(&client, first_expiry + 4 * DAY),
+        vec![&env, first, second, third]
+    );
+
+    // Pages resume from the returned cursor.
+    let page = client.list_expiring_verifications(&u64::MAX, &ExpiryCursor::default(), &2);
+    assert_eq!(page.entries.len(), 2);
+    assert!(page.has_more);
+    let cursor = page.next_cursor;
+    assert_eq!(cursor.project_id, third);
+
+    // Removing a listed entry before the next call does not shift the cursor.
+    client.revoke_verification(&first, &admin, &String::from_str(&env, "Revoked"));
+    let rest = client.list_expiring_verifications(&u64::MAX, &cursor, &2);
+    assert_eq!(rest.entries.len(), 1);
+    assert_eq!(rest.entries.get(0).unwrap().project_id, third);
+    assert!(!rest.has_more);
+
+    // Renewal moves a project to its new expiry; revocation removes it.
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    client.renew_verification(&second, &admin);
+    assert_eq!(listed(&client, u64::MAX), vec![&env, third, second]);
+    client.revoke_verification(&third, &admin, &String::from_str(&env, "Revoked"));
+    assert_eq!(listed(&client, u64::MAX), vec![&env, second]);
+}
+
+#[test]
+fn test_sweep_removes_lapsed_verifications_from_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.set_verification_duration(&admin, &DAY);
+
+    let lapsed = verify(&env, &client, &admin, &owner, "SweepLapsed");
+    env.ledger().with_mut(|l| l.timestamp += 2 * DAY);
+    let current = verify(&env, &client, &admin, &owner, "SweepCurrent");
+
+    // Lapsed but unswept verifications are still listed.
+    assert_eq!(listed(&client, u64::MAX), vec![&env, lapsed, current]);
+    client.sweep_expired_verifications(&0, &10);
+    assert_eq!(listed(&client, u64::MAX), vec![&env, current]);
+}
+
+#[test]
+fn test_expiring_soon_reminders_sent_once_per_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.set_verification_duration(&admin, &(10 * DAY));
+
+    assert_eq!(
+        client.try_set_expiry_reminder_window(&owner, &DAY),
+        Err(Ok(ContractError::AdminOnly))
+    );
+    client.set_expiry_reminder_window(&admin, &(2 * DAY));
+    assert_eq!(client.get_expiry_reminder_window(), 2 * DAY);
+
+    let project_id = verify(&env, &client, &admin, &owner, "Reminded");
+    assert_eq!(
+        client
+            .notify_expiring_verifications(&ExpiryCursor::default(), &10)
+            .len(),
+        0
+    );
+
+    env.ledger().with_mut(|l| l.timestamp += 9 * DAY);
+    assert_eq!(
+        client.notify_expiring_verifications(&ExpiryCursor::default(), &10),
+        vec![&env, project_id]
+    );
+    assert_eq!(env.events().all().len(), 1);
+    assert_eq!(
+        client
+            .notify_expiring_verifications(&ExpiryCursor::default(), &10)
+            .len(),
+        0
+    );
+
+    // Renewing re-arms the reminder for the new expiry time.
+    client.renew_verification(&project_id, &admin);
+    env.ledger().with_mut(|l| l.timestamp += 9 * DAY);
+    assert_eq!(
+        client.notify_expiring_verifications(&ExpiryCursor::default(), &10),
+        vec![&env, project_id]
+    );
+
+    // A window of 0 turns reminders off.
+    client.set_expiry_reminder_window(&admin, &0);
+    client.renew_verification(&project_id, &admin);
+    env.ledger().with_mut(|l| l.timestamp += 9 * DAY);
+    assert_eq!(
+        client
+            .notify_expiring_verifications(&ExpiryCursor::default(), &10)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_reminder_pass_prunes_stale_entries() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.set_verification_duration(&admin, &(10 * DAY));
+    let project_id = verify(&env, &client, &admin, &owner, "PrunedLive");
+    let unverified = create_test_project(&client, &owner, "PrunedStale");
+
+    // An entry whose verification no longer matches is skipped, and the
+    // reminder pass removes it along with its bucket.
+    let stale_expiry = 1_000 + 9 * DAY;
+    env.as_contract(&client.address, || {
+        VerificationExpiryIndex::index(&env, unverified, stale_expiry);
+    });
+    assert_eq!(listed(&client, u64::MAX), vec![&env, project_id]);
+    env.ledger().with_mut(|l| l.timestamp += 9 * DAY - 10);
+    assert_eq!(
+        client.notify_expiring_verifications(&ExpiryCursor::default(), &10),
+        vec![&env, project_id]
+    );
+    env.as_contract(&client.address, || {
+        let storage = env.storage().persistent();
+        assert!(!storage.has(&FeatureKey::VerificationExpiryBucket(
+            stale_expiry / EXPIRY_BUCKET_SECONDS
+        )));
+        assert!(!storage.has(&FeatureKey::VerificationExpiryIndexed(unverified)));
+    });
+}
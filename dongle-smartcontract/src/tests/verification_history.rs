@@ -0,0 +1,161 @@
+//! Tests for paginated verification history, retention and pruned summaries.
+
+use crate::errors::ContractError;
+use crate::tests::fixtures::{create_test_project, setup_contract};
+use crate::types::{HistoryRetentionPolicy, VerificationRecord};
+use crate::DongleContractClient;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env, String,
+};
+
+const EVIDENCE: &str = "QmHistoryEvidence123456789012345678901234567";
+const DAY: u64 = 24 * 60 * 60;
+
+fn request(env: &Env, client: &DongleContractClient<'_>, project_id: u64, owner: &Address) {
+    client.request_verification(&project_id, owner, &String::from_str(env, EVIDENCE));
+}
+
+fn chain(env: &Env, previous: &BytesN<32>, record: &VerificationRecord) -> BytesN<32> {
+    let mut input = Bytes::from_array(env, &previous.to_array());
+    input.append(&record.clone().to_xdr(env));
+    env.crypto().sha256(&input).into()
+}
+
+#[test]
+fn test_history_pages_resume_from_cursor() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "HistoryPages");
+
+    for _ in 0..2 {
+        request(&env, &client, project_id, &owner);
+        client.reject_verification(&project_id, &admin);
+    }
+    request(&env, &client, project_id, &owner);
+    client.approve_verification(&project_id, &admin);
+
+    let page = client.list_verification_history(&project_id, &0, &2);
+    assert_eq!(page.records.len(), 2);
+    let next = page.next_cursor.unwrap();
+    let rest = client.list_verification_history(&project_id, &next, &2);
+    assert_eq!(rest.records.len(), 1);
+    assert_eq!(rest.records.get(0).unwrap().request_id, next);
+    assert_eq!(rest.next_cursor, None);
+
+    for _ in 0..3 {
+        client.request_renewal(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+        client.approve_renewal(&project_id, &admin);
+    }
+    let renewals = client.list_renewal_history(&project_id, &0, &2);
+    assert_eq!(renewals.records.len(), 2);
+    assert_eq!(renewals.next_cursor, Some(2));
+    assert_eq!(
+        client.list_renewal_history(&project_id, &2, &2).next_cursor,
+        None
+    );
+}
+
+#[test]
+fn test_retention_policy_prunes_into_hash_chain() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "HistoryRetained");
+
+    let policy = HistoryRetentionPolicy {
+        max_records: 2,
+        max_age_seconds: 0,
+    };
+    assert_eq!(
+        client.try_set_history_retention_policy(&owner, &policy),
+        Err(Ok(ContractError::AdminOnly))
+    );
+    client.set_history_retention_policy(&admin, &policy);
+    assert_eq!(client.get_history_retention_policy(), policy);
+
+    request(&env, &client, project_id, &owner);
+    client.reject_verification(&project_id, &admin);
+    request(&env, &client, project_id, &owner);
+    client.reject_verification(&project_id, &admin);
+    let history = client.get_verification_history(&project_id);
+    assert_eq!(client.get_verification_history_summary(&project_id), None);
+
+    // The third request pushes the oldest record out of the window.
+    request(&env, &client, project_id, &owner);
+    let retained = client.get_verification_history(&project_id);
+    assert_eq!(retained.len(), 2);
+    assert_eq!(retained.get(0).unwrap(), history.get(1).unwrap());
+
+    let summary = client
+        .get_verification_history_summary(&project_id)
+        .unwrap();
+    assert_eq!(summary.pruned_count, 1);
+    let zero = BytesN::from_array(&env, &[0; 32]);
+    let first_link = chain(&env, &zero, &history.get(0).unwrap());
+    assert_eq!(summary.chain_hash, first_link);
+
+    // Manual clears extend the same chain.
+    client.reject_verification(&project_id, &admin);
+    client.clear_verification_history(&project_id, &admin, &1);
+    let summary = client
+        .get_verification_history_summary(&project_id)
+        .unwrap();
+    assert_eq!(summary.pruned_count, 2);
+    assert_eq!(
+        summary.chain_hash,
+        chain(&env, &first_link, &history.get(1).unwrap())
+    );
+}
+
+#[test]
+fn test_age_retention_keeps_newest_renewal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "HistoryAged");
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    request(&env, &client, project_id, &owner);
+    client.approve_verification(&project_id, &admin);
+
+    for _ in 0..2 {
+        client.request_renewal(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+        client.approve_renewal(&project_id, &admin);
+    }
+    client.set_history_retention_policy(
+        &admin,
+        &HistoryRetentionPolicy {
+            max_records: 0,
+            max_age_seconds: DAY,
+        },
+    );
+
+    // Both renewals are too old, but the newest is always kept.
+    env.ledger().with_mut(|l| l.timestamp += 2 * DAY);
+    client.request_renewal(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+    let page = client.list_renewal_history(&project_id, &0, &10);
+    assert_eq!(page.records.len(), 1);
+    assert_eq!(
+        client
+            .get_renewal_history_summary(&project_id)
+            .unwrap()
+            .pruned_count,
+        1
+    );
+    assert_eq!(client.get_renewal_history(&project_id, &0, &10).len(), 1);
+
+    // Clearing folds the rest into the summary and reports what it removed.
+    assert_eq!(client.clear_renewal_history(&project_id, &admin), 1);
+    assert_eq!(
+        client
+            .get_renewal_history_summary(&project_id)
+            .unwrap()
+            .pruned_count,
+        2
+    );
+}
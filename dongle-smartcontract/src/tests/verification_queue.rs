@@ -0,0 +1,215 @@
+//! Tests for the pending verification queue, automatic assignment and SLA.
+
+extern crate alloc;
+
+use crate::errors::ContractError;
+use crate::storage_keys::VerificationKey;
+use crate::tests::fixtures::{create_test_project, setup_contract};
+use crate::types::{AdminActionType, VerificationAssignmentMode};
+use crate::DongleContractClient;
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger as _},
+    vec, Address, Env, String, Vec,
+};
+
+const EVIDENCE: &str = "QmQueueEvidence123456789012345678901234567890";
+
+fn submit(env: &Env, client: &DongleContractClient<'_>, owner: &Address, name: &str) -> u64 {
+    let project_id = create_test_project(client, owner, name);
+    client.request_verification(&project_id, owner, &String::from_str(env, EVIDENCE));
+    project_id
+}
+
+fn grant(env: &Env, client: &DongleContractClient<'_>, admin: &Address) -> Address {
+    let verifier = Address::generate(env);
+    client.grant_verifier(admin, &verifier, &Vec::new(env), &Vec::new(env), &0);
+    verifier
+}
+
+#[test]
+fn test_queue_lists_pending_in_request_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+    let first = submit(&env, &client, &owner, "QueueFirst");
+    env.ledger().with_mut(|l| l.timestamp += 50);
+    let second = submit(&env, &client, &owner, "QueueSecond");
+    env.ledger().with_mut(|l| l.timestamp += 50);
+    let third = submit(&env, &client, &owner, "QueueThird");
+
+    let pending = client.list_pending_verifications(&None, &0, &10);
+    assert_eq!(pending.len(), 3);
+    assert_eq!(pending.get(0).unwrap().project_id, first);
+    assert_eq!(pending.get(0).unwrap().age_seconds, 100);
+    assert_eq!(pending.get(2).unwrap().age_seconds, 0);
+    assert!(!pending.get(0).unwrap().sla_breached);
+    assert_eq!(client.get_verification_queue_position(&third), Some(3));
+
+    // Decided requests leave the queue and later requests move up.
+    client.approve_verification(&first, &admin);
+    client.reject_verification(&second, &admin);
+    assert_eq!(client.get_verification_queue_position(&first), None);
+    assert_eq!(client.get_verification_queue_position(&third), Some(1));
+    let page = client.list_pending_verifications(&None, &0, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().project_id, third);
+
+    // A resubmitted request joins the back of the queue.
+    client.request_verification(&second, &owner, &String::from_str(&env, EVIDENCE));
+    assert_eq!(client.get_verification_queue_position(&second), Some(2));
+    assert_eq!(client.list_pending_verifications(&None, &1, &10).len(), 1);
+}
+
+#[test]
+fn test_queue_filters_by_assignee_and_tracks_load() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let verifier = grant(&env, &client, &admin);
+
+    let first = submit(&env, &client, &owner, "LoadFirst");
+    let second = submit(&env, &client, &owner, "LoadSecond");
+    client.assign_verification(&first, &admin, &verifier);
+    client.assign_verification(&second, &admin, &verifier);
+    assert_eq!(client.get_verifier_load(&verifier), 2);
+
+    client.assign_verification(&second, &admin, &admin);
+    assert_eq!(client.get_verifier_load(&verifier), 1);
+    assert_eq!(client.get_verifier_load(&admin), 1);
+
+    let assigned = client.list_pending_verifications(&Some(verifier.clone()), &0, &10);
+    assert_eq!(assigned.len(), 1);
+    assert_eq!(assigned.get(0).unwrap().project_id, first);
+
+    client.approve_verification(&first, &verifier);
+    assert_eq!(client.get_verifier_load(&verifier), 0);
+    assert_eq!(
+        client
+            .list_pending_verifications(&Some(verifier), &0, &10)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_automatic_assignment_modes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let alice = grant(&env, &client, &admin);
+    let bob = grant(&env, &client, &admin);
+    let nft_only = Address::generate(&env);
+    client.grant_verifier(
+        &admin,
+        &nft_only,
+        &vec![&env, String::from_str(&env, "NFT")],
+        &Vec::new(&env),
+        &0,
+    );
+
+    // Manual by default.
+    let manual = submit(&env, &client, &owner, "AssignManual");
+    assert_eq!(client.get_assigned_admin(&manual), None);
+
+    client.set_verification_assignment_mode(&admin, &VerificationAssignmentMode::RoundRobin);
+    let a = submit(&env, &client, &owner, "AssignA");
+    let b = submit(&env, &client, &owner, "AssignB");
+    let c = submit(&env, &client, &owner, "AssignC");
+    // The NFT-scoped verifier is skipped for DeFi projects.
+    assert_eq!(client.get_assigned_admin(&a), Some(alice.clone()));
+    assert_eq!(client.get_assigned_admin(&b), Some(bob.clone()));
+    assert_eq!(client.get_assigned_admin(&c), Some(alice.clone()));
+
+    client.set_verification_assignment_mode(&admin, &VerificationAssignmentMode::LeastLoaded);
+    let d = submit(&env, &client, &owner, "AssignD");
+    assert_eq!(client.get_assigned_admin(&d), Some(bob.clone()));
+    assert_eq!(client.get_verifier_load(&alice), 2);
+    assert_eq!(client.get_verifier_load(&bob), 2);
+
+    // A verifier with a conflict of interest is skipped.
+    let conflicted = create_test_project(&client, &owner, "AssignConflict");
+    client.recuse_from_project(&conflicted, &alice);
+    client.request_verification(&conflicted, &owner, &String::from_str(&env, EVIDENCE));
+    assert_eq!(client.get_assigned_admin(&conflicted), Some(bob.clone()));
+}
+
+#[test]
+fn test_stale_requests_are_flagged_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+    assert_eq!(
+        client.try_set_verification_sla(&owner, &100),
+        Err(Ok(ContractError::AdminOnly))
+    );
+    client.set_verification_sla(&admin, &100);
+    assert_eq!(client.get_verification_sla(), 100);
+    assert_eq!(
+        client
+            .list_admin_actions(&0, &1)
+            .get(0)
+            .unwrap()
+            .action_type,
+        AdminActionType::VerificationSettingsChanged
+    );
+
+    let old = submit(&env, &client, &owner, "StaleOld");
+    env.ledger().with_mut(|l| l.timestamp += 80);
+    let recent = submit(&env, &client, &owner, "StaleRecent");
+    env.ledger().with_mut(|l| l.timestamp += 30);
+
+    assert_eq!(client.flag_stale_verifications(&0, &10), vec![&env, old]);
+    assert_eq!(env.events().all().len(), 1);
+    assert!(
+        client
+            .list_pending_verifications(&None, &0, &10)
+            .get(0)
+            .unwrap()
+            .sla_breached
+    );
+
+    // Already-flagged requests are not reported again.
+    assert_eq!(client.flag_stale_verifications(&0, &10).len(), 0);
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    assert_eq!(client.flag_stale_verifications(&0, &10), vec![&env, recent]);
+}
+
+#[test]
+fn test_queue_spans_pages() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let mut project_ids = Vec::new(&env);
+    for i in 0..55u32 {
+        let owner = Address::generate(&env);
+        let name = alloc::format!("PagedQueue{i}");
+        let project_id = create_test_project(&client, &owner, &name);
+        client.request_verification(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+        project_ids.push_back(project_id);
+    }
+    let last = project_ids.get(54).unwrap();
+    assert_eq!(client.get_verification_queue_position(&last), Some(55));
+
+    // Emptying the first page moves the head onto the second one.
+    for i in 0..50 {
+        client.reject_verification(&project_ids.get(i).unwrap(), &admin);
+    }
+    env.as_contract(&client.address, || {
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&VerificationKey::QueuePage(0)));
+    });
+    assert_eq!(client.get_verification_queue_position(&last), Some(5));
+    let page = client.list_pending_verifications(&None, &3, &10);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(1).unwrap().project_id, last);
+}
@@ -0,0 +1,155 @@
+//! Tests for the per-request verifier sign-off quorum.
+
+use crate::errors::ContractError;
+use crate::tests::fixtures::{create_test_project, setup_contract};
+use crate::types::{AdminActionType, VerificationStatus};
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+const EVIDENCE: &str = "QmQuorumEvidence12345678901234567890123456789";
+const EVIDENCE_V2: &str = "QmQuorumEvidenceV2234567890123456789012345678";
+const NOTES: &str = "QmQuorumNotes1234567890123456789012345678901";
+
+#[test]
+fn test_default_quorum_approves_with_single_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "QuorumDefault");
+
+    assert_eq!(client.get_verification_quorum(), 1);
+    client.request_verification(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+    client.approve_verification(&project_id, &admin);
+
+    let record = client.get_verification(&project_id).unwrap();
+    assert_eq!(record.status, VerificationStatus::Verified);
+    assert_eq!(record.required_approvals, 1);
+    assert_eq!(record.approvals.len(), 1);
+    assert_eq!(record.approvals.get(0).unwrap().verifier, admin);
+    assert_eq!(record.approvals.get(0).unwrap().notes_cid, None);
+}
+
+#[test]
+fn test_quorum_requires_distinct_verifiers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let second = Address::generate(&env);
+    client.add_admin(&admin, &second);
+    client.set_verification_quorum(&admin, &2);
+
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "QuorumTwo");
+    client.request_verification(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+
+    let notes = Some(String::from_str(&env, NOTES));
+    client.attest_verification(&project_id, &admin, &notes);
+    let record = client.get_verification(&project_id).unwrap();
+    assert_eq!(record.status, VerificationStatus::Pending);
+    assert_eq!(record.approvals.len(), 1);
+    assert_eq!(
+        client
+            .list_admin_actions(&0, &1)
+            .get(0)
+            .unwrap()
+            .action_type,
+        AdminActionType::VerificationAttested
+    );
+
+    assert_eq!(
+        client.try_attest_verification(&project_id, &admin, &None),
+        Err(Ok(ContractError::AlreadyAttested))
+    );
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_attest_verification(&project_id, &outsider, &None),
+        Err(Ok(ContractError::AdminOnly))
+    );
+
+    client.approve_verification(&project_id, &second);
+    let record = client.get_verification(&project_id).unwrap();
+    assert_eq!(record.status, VerificationStatus::Verified);
+    assert_eq!(record.approvals.len(), 2);
+    assert_eq!(record.approvals.get(0).unwrap().notes_cid, notes);
+
+    // Sign-offs are part of the stored record history.
+    let history = client.get_verification_history(&project_id);
+    assert_eq!(history.get(0).unwrap().approvals.len(), 2);
+}
+
+#[test]
+fn test_quorum_snapshot_and_evidence_update_resets_sign_offs() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let second = Address::generate(&env);
+    client.add_admin(&admin, &second);
+
+    assert_eq!(
+        client.try_set_verification_quorum(&admin, &0),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    assert_eq!(
+        client.try_set_verification_quorum(&admin, &11),
+        Err(Ok(ContractError::InvalidInput))
+    );
+
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "QuorumSnapshot");
+    client.set_verification_quorum(&admin, &2);
+    client.request_verification(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+
+    // Raising the quorum later does not change the pending request.
+    client.set_verification_quorum(&admin, &3);
+    assert_eq!(
+        client
+            .get_verification(&project_id)
+            .unwrap()
+            .required_approvals,
+        2
+    );
+
+    client.attest_verification(&project_id, &admin, &None);
+    client.update_verification_evidence(&project_id, &owner, &String::from_str(&env, EVIDENCE_V2));
+    assert_eq!(
+        client
+            .get_verification(&project_id)
+            .unwrap()
+            .approvals
+            .len(),
+        0
+    );
+
+    client.attest_verification(&project_id, &admin, &None);
+    client.attest_verification(&project_id, &second, &None);
+    assert_eq!(
+        client.get_verification(&project_id).unwrap().status,
+        VerificationStatus::Verified
+    );
+}
+
+#[test]
+fn test_attestation_is_independent_of_governance_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let second = Address::generate(&env);
+    client.add_admin(&admin, &second);
+    client.set_verification_quorum(&admin, &2);
+    client.set_admin_approval_threshold(&admin, &2);
+
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "QuorumGov");
+    client.request_verification(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+
+    assert_eq!(
+        client.try_approve_verification(&project_id, &admin),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    client.attest_verification(&project_id, &admin, &None);
+    client.attest_verification(&project_id, &second, &None);
+    assert_eq!(
+        client.get_project(&project_id).unwrap().verification_status,
+        VerificationStatus::Verified
+    );
+}
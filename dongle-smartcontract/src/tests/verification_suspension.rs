@@ -0,0 +1,167 @@
+//! Tests for suspending and resuming verified projects.
+
+use crate::errors::ContractError;
+use crate::tests::fixtures::{create_test_project, setup_contract};
+use crate::types::{AdminActionType, VerificationStatus};
+use crate::DongleContractClient;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    Address, Env, String,
+};
+
+const EVIDENCE: &str = "QmSuspendEvidence1234567890123456789012345678";
+const INCIDENT: &str = "QmIncidentNotice12345678901234567890123456789";
+
+fn verified_project(
+    env: &Env,
+    client: &DongleContractClient<'_>,
+    admin: &Address,
+    name: &str,
+) -> u64 {
+    let owner = Address::generate(env);
+    let project_id = create_test_project(client, &owner, name);
+    client.request_verification(&project_id, &owner, &String::from_str(env, EVIDENCE));
+    client.approve_verification(&project_id, admin);
+    project_id
+}
+
+#[test]
+fn test_suspend_and_resume_keeps_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    let project_id = verified_project(&env, &client, &admin, "SuspendResume");
+    let expires_at = client.get_verification(&project_id).unwrap().expires_at;
+    let reason = String::from_str(&env, INCIDENT);
+
+    assert_eq!(
+        client.try_suspend_verification(&project_id, &Address::generate(&env), &reason, &0),
+        Err(Ok(ContractError::AdminOnly))
+    );
+    client.suspend_verification(&project_id, &admin, &reason, &0);
+    assert_eq!(
+        client.get_project(&project_id).unwrap().verification_status,
+        VerificationStatus::Suspended
+    );
+    assert!(!client.is_verification_active(&project_id));
+    let suspension = client.get_verification_suspension(&project_id).unwrap();
+    assert_eq!(suspension.reason_cid, reason);
+    assert_eq!(suspension.resume_at, 0);
+    let log = client.list_admin_actions(&0, &1).get(0).unwrap();
+    assert_eq!(log.action_type, AdminActionType::VerificationSuspended);
+
+    // Without a deadline only an admin can resume.
+    assert_eq!(
+        client.try_resume_verification(&project_id, &Address::generate(&env)),
+        Err(Ok(ContractError::AdminOnly))
+    );
+    client.resume_verification(&project_id, &admin);
+    let record = client.get_verification(&project_id).unwrap();
+    assert_eq!(record.status, VerificationStatus::Verified);
+    assert_eq!(record.expires_at, expires_at);
+    assert!(client.is_verification_active(&project_id));
+    assert_eq!(
+        client.try_resume_verification(&project_id, &admin),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_suspension_lapses_at_resume_deadline() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    let project_id = verified_project(&env, &client, &admin, "SuspendLapse");
+    let reason = String::from_str(&env, INCIDENT);
+
+    assert_eq!(
+        client.try_suspend_verification(&project_id, &admin, &reason, &1_000),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    client.suspend_verification(&project_id, &admin, &reason, &1_500);
+    let anyone = Address::generate(&env);
+    assert_eq!(
+        client.try_resume_verification(&project_id, &anyone),
+        Err(Ok(ContractError::AdminOnly))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = 1_500);
+    // Reads treat the lapsed suspension as over before anyone resumes it.
+    assert!(client.is_verification_active(&project_id));
+    client.resume_verification(&project_id, &anyone);
+    assert_eq!(
+        client.get_project(&project_id).unwrap().verification_status,
+        VerificationStatus::Verified
+    );
+}
+
+#[test]
+fn test_suspend_requires_verified_and_revoke_from_suspended() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let reason = String::from_str(&env, INCIDENT);
+
+    let pending = create_test_project(&client, &owner, "SuspendPending");
+    client.request_verification(&pending, &owner, &String::from_str(&env, EVIDENCE));
+    assert_eq!(
+        client.try_suspend_verification(&pending, &admin, &reason, &0),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+
+    let project_id = verified_project(&env, &client, &admin, "SuspendRevoke");
+    client.suspend_verification(&project_id, &admin, &reason, &0);
+    client.revoke_verification(&project_id, &admin, &String::from_str(&env, "confirmed"));
+    assert_eq!(
+        client.get_project(&project_id).unwrap().verification_status,
+        VerificationStatus::Unverified
+    );
+    assert!(!client.is_verification_active(&project_id));
+}
+
+#[test]
+fn test_suspend_drops_pending_renewal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let project_id = verified_project(&env, &client, &admin, "SuspendRenewal");
+    let owner = client.get_project(&project_id).unwrap().owner;
+    client.request_renewal(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+
+    client.suspend_verification(&project_id, &admin, &String::from_str(&env, INCIDENT), &0);
+    assert_eq!(client.get_renewal_request(&project_id), None);
+    assert_eq!(
+        client.try_approve_renewal(&project_id, &admin),
+        Err(Ok(ContractError::VerificationNotFound))
+    );
+    assert!(!client.is_verification_active(&project_id));
+
+    // After resuming, the owner can request the renewal again.
+    client.resume_verification(&project_id, &admin);
+    client.request_renewal(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+    client.approve_renewal(&project_id, &admin);
+    assert!(client.is_verification_active(&project_id));
+}
+
+#[test]
+fn test_resume_rejects_pending_request() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "ResumePending");
+    client.request_verification(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+
+    // Pending may become Verified, but only by a decision, not a resume.
+    assert_eq!(
+        client.try_resume_verification(&project_id, &admin),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+    assert_eq!(
+        client.get_project(&project_id).unwrap().verification_status,
+        VerificationStatus::Pending
+    );
+}
@@ -0,0 +1,171 @@
+//! Tests for the batched expired-verification sweep.
+
+use crate::tests::fixtures::{create_test_project, setup_contract};
+use crate::types::{EscrowRefundPolicy, FeeEscrowConfig, VerificationStatus};
+use crate::DongleContractClient;
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger as _},
+    token, vec, Address, Env, String,
+};
+
+const EVIDENCE: &str = "QmSweepEvidence123456789012345678901234567890";
+const GRACE: u64 = 14 * 24 * 60 * 60;
+
+fn verify(
+    env: &Env,
+    client: &DongleContractClient<'_>,
+    admin: &Address,
+    owner: &Address,
+    name: &str,
+) -> u64 {
+    let project_id = create_test_project(client, owner, name);
+    client.request_verification(&project_id, owner, &String::from_str(env, EVIDENCE));
+    client.approve_verification(&project_id, admin);
+    project_id
+}
+
+#[test]
+fn test_sweep_moves_expired_projects_to_unverified() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+
+    client.set_verification_duration(&admin, &1_000);
+    let short = verify(&env, &client, &admin, &owner, "SweepShort");
+    client.set_verification_duration(&admin, &10_000);
+    let long = verify(&env, &client, &admin, &owner, "SweepLong");
+
+    // Nothing has lapsed yet.
+    let result = client.sweep_expired_verifications(&0, &10);
+    assert_eq!(result.expired.len(), 0);
+    assert_eq!(result.next_cursor, None);
+
+    env.ledger().with_mut(|l| l.timestamp += 1_001);
+    let result = client.sweep_expired_verifications(&0, &10);
+    assert_eq!(result.expired, vec![&env, short]);
+    assert_eq!(env.events().all().len(), 1);
+
+    assert_eq!(
+        client.get_project(&short).unwrap().verification_status,
+        VerificationStatus::Unverified
+    );
+    assert_eq!(
+        client.get_verification(&short).unwrap().status,
+        VerificationStatus::Unverified
+    );
+    let verified = client.list_projects_by_status(&VerificationStatus::Verified, &1, &100);
+    assert_eq!(verified.len(), 1);
+    assert_eq!(verified.get(0).unwrap().id, long);
+
+    // A second sweep is a no-op, and the owner can request verification again.
+    assert_eq!(client.sweep_expired_verifications(&0, &10).expired.len(), 0);
+    client.request_verification(&short, &owner, &String::from_str(&env, EVIDENCE));
+    assert_eq!(
+        client.get_project(&short).unwrap().verification_status,
+        VerificationStatus::Pending
+    );
+}
+
+#[test]
+fn test_sweep_is_bounded_and_skips_pending_renewals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    client.set_verification_duration(&admin, &1_000);
+
+    let first = verify(&env, &client, &admin, &owner, "SweepOne");
+    let second = verify(&env, &client, &admin, &owner, "SweepTwo");
+    let renewing = verify(&env, &client, &admin, &owner, "SweepThree");
+    client.request_renewal(&renewing, &owner, &String::from_str(&env, EVIDENCE));
+
+    env.ledger().with_mut(|l| l.timestamp += 1_001);
+
+    let page = client.sweep_expired_verifications(&0, &2);
+    assert_eq!(page.expired, vec![&env, first, second]);
+    assert_eq!(page.next_cursor, Some(3));
+
+    let page = client.sweep_expired_verifications(&page.next_cursor.unwrap(), &2);
+    assert_eq!(page.expired.len(), 0);
+    assert_eq!(page.next_cursor, None);
+    assert_eq!(
+        client.get_project(&renewing).unwrap().verification_status,
+        VerificationStatus::Verified
+    );
+
+    // Once the renewal is decided the project is swept like any other.
+    client.reject_renewal(&renewing, &admin);
+    assert_eq!(
+        client.sweep_expired_verifications(&3, &2).expired,
+        vec![&env, renewing]
+    );
+}
+
+#[test]
+fn test_pending_renewal_holds_off_sweep_only_for_grace_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let fee_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    token::StellarAssetClient::new(&env, &fee_token).mint(&owner, &1_000);
+    let balance = token::Client::new(&env, &fee_token);
+    client.set_verification_duration(&admin, &1_000);
+    let project_id = verify(&env, &client, &admin, &owner, "SweepGrace");
+
+    client.set_fee(&admin, &Some(fee_token.clone()), &100, &0, &treasury);
+    client.set_fee_escrow_config(
+        &admin,
+        &FeeEscrowConfig {
+            enabled: true,
+            refund_policy: EscrowRefundPolicy::Partial(0),
+        },
+    );
+    client.pay_fee(&owner, &project_id, &Some(fee_token.clone()));
+    client.request_renewal(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+    assert_eq!(balance.balance(&owner), 900);
+
+    env.ledger().with_mut(|l| l.timestamp += 1_000 + GRACE);
+    assert_eq!(client.sweep_expired_verifications(&0, &10).expired.len(), 0);
+
+    // Once the grace period has passed the renewal is dropped with a full
+    // refund and the verification expires.
+    env.ledger().with_mut(|l| l.timestamp += 1);
+    assert_eq!(
+        client.sweep_expired_verifications(&0, &10).expired,
+        vec![&env, project_id]
+    );
+    assert_eq!(client.get_renewal_request(&project_id), None);
+    assert_eq!(client.get_fee_escrow(&project_id), None);
+    assert_eq!(balance.balance(&owner), 1_000);
+    assert_eq!(balance.balance(&treasury), 0);
+    assert_eq!(
+        client.get_project(&project_id).unwrap().verification_status,
+        VerificationStatus::Unverified
+    );
+}
+
+#[test]
+fn test_sweep_expires_suspended_verifications() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    client.set_verification_duration(&admin, &1_000);
+    let project_id = verify(&env, &client, &admin, &owner, "SweepSuspended");
+    client.suspend_verification(&project_id, &admin, &String::from_str(&env, EVIDENCE), &0);
+
+    env.ledger().with_mut(|l| l.timestamp += 1_001);
+    assert_eq!(
+        client.sweep_expired_verifications(&0, &10).expired,
+        vec![&env, project_id]
+    );
+    assert_eq!(
+        client.get_verification(&project_id).unwrap().status,
+        VerificationStatus::Unverified
+    );
+}
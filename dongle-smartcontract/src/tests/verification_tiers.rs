@@ -0,0 +1,363 @@
+//! Tests for admin-defined verification tiers.
+
+use crate::errors::ContractError;
+use crate::storage_keys::StorageKey;
+use crate::storage_migration::{LegacyVerificationRecord, LegacyVerificationRenewalRecord};
+use crate::tests::fixtures::{create_test_project, setup_contract};
+use crate::types::{AdminActionType, VerificationStatus, VerificationTierParams};
+use crate::DongleContractClient;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    Address, Env, String,
+};
+
+const EVIDENCE: &str = "QmTierEvidence1234567890123456789012345678901";
+const REQUIREMENTS: &str = "QmTierRequirements12345678901234567890123456";
+const BADGE: &str = "QmTierBadge123456789012345678901234567890123";
+
+fn tier_params(
+    env: &Env,
+    name: &str,
+    level: u32,
+    fee: u128,
+    duration: u64,
+) -> VerificationTierParams {
+    VerificationTierParams {
+        name: String::from_str(env, name),
+        level,
+        fee,
+        duration_seconds: duration,
+        requirements_cid: String::from_str(env, REQUIREMENTS),
+        badge_cid: String::from_str(env, BADGE),
+    }
+}
+
+/// Configure a fee token and fund `owner`. Returns (token, treasury).
+fn setup_fee_token(
+    env: &Env,
+    client: &DongleContractClient<'_>,
+    admin: &Address,
+    owner: &Address,
+) -> (Address, Address) {
+    let treasury = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(env, &token).mint(owner, &10_000);
+    client.set_fee(admin, &Some(token.clone()), &100u128, &0u128, &treasury);
+    (token, treasury)
+}
+
+#[test]
+fn test_tier_create_update_and_deactivate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+
+    let identity =
+        client.create_verification_tier(&admin, &tier_params(&env, "Identity", 1, 0, 1000));
+    let audited =
+        client.create_verification_tier(&admin, &tier_params(&env, "Audited", 2, 50, 2000));
+    assert_eq!((identity, audited), (1, 2));
+    assert_eq!(client.list_verification_tiers().len(), 2);
+
+    client.update_verification_tier(
+        &admin,
+        &audited,
+        &tier_params(&env, "Code audited", 3, 75, 3000),
+    );
+    let tier = client.get_verification_tier(&audited).unwrap();
+    assert_eq!(tier.name, String::from_str(&env, "Code audited"));
+    assert_eq!((tier.level, tier.fee, tier.duration_seconds), (3, 75, 3000));
+    assert!(tier.active);
+
+    client.set_verification_tier_active(&admin, &audited, &false);
+    assert!(!client.get_verification_tier(&audited).unwrap().active);
+    let log = client.list_admin_actions(&0, &1);
+    assert_eq!(
+        log.get(0).unwrap().action_type,
+        AdminActionType::VerificationTierUpdated
+    );
+
+    assert_eq!(
+        client.try_create_verification_tier(&admin, &tier_params(&env, "Bad", 1, 0, 0)),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    assert_eq!(
+        client.try_update_verification_tier(&admin, &9, &tier_params(&env, "Missing", 1, 0, 10)),
+        Err(Ok(ContractError::VerificationTierNotFound))
+    );
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_create_verification_tier(&outsider, &tier_params(&env, "Rogue", 1, 0, 10)),
+        Err(Ok(ContractError::AdminOnly))
+    );
+}
+
+#[test]
+fn test_tiered_request_charges_tier_fee_and_uses_tier_duration() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "TierFee");
+    let (token, treasury) = setup_fee_token(&env, &client, &admin, &owner);
+    let tier_id =
+        client.create_verification_tier(&admin, &tier_params(&env, "Legal", 5, 500, 7_200));
+
+    // The global fee (100) does not cover the tier fee.
+    client.pay_fee(&owner, &project_id, &Some(token.clone()));
+    assert_eq!(
+        client.try_request_tier_verification(
+            &project_id,
+            &owner,
+            &tier_id,
+            &String::from_str(&env, EVIDENCE)
+        ),
+        Err(Ok(ContractError::InsufficientFee))
+    );
+
+    client.pay_tier_fee(&owner, &project_id, &tier_id, &Some(token.clone()));
+    client.request_tier_verification(
+        &project_id,
+        &owner,
+        &tier_id,
+        &String::from_str(&env, EVIDENCE),
+    );
+    let record = client.get_verification(&project_id).unwrap();
+    assert_eq!(record.tier_id, Some(tier_id));
+    assert_eq!(record.fee_amount, 500);
+    assert_eq!(
+        soroban_sdk::token::Client::new(&env, &token).balance(&treasury),
+        600
+    );
+
+    let now = env.ledger().timestamp();
+    client.approve_verification(&project_id, &admin);
+    let record = client.get_verification(&project_id).unwrap();
+    assert_eq!(record.expires_at, now + 7_200);
+    assert_eq!(
+        client
+            .get_project_verification_tier(&project_id)
+            .unwrap()
+            .tier_id,
+        tier_id
+    );
+
+    let grants = client.get_project_tier_grants(&project_id);
+    assert_eq!(grants.len(), 1);
+    assert_eq!(grants.get(0).unwrap().expires_at, now + 7_200);
+
+    // Once the grant expires the project no longer holds the tier.
+    env.ledger().with_mut(|l| l.timestamp += 7_200);
+    assert!(client.get_project_verification_tier(&project_id).is_none());
+}
+
+#[test]
+fn test_tier_renewal_upgrades_to_highest_tier() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "TierUpgrade");
+    let basic =
+        client.create_verification_tier(&admin, &tier_params(&env, "Identity", 1, 0, 10_000));
+    let audited =
+        client.create_verification_tier(&admin, &tier_params(&env, "Audited", 3, 0, 5_000));
+
+    client.request_tier_verification(
+        &project_id,
+        &owner,
+        &basic,
+        &String::from_str(&env, EVIDENCE),
+    );
+    client.approve_verification(&project_id, &admin);
+    assert_eq!(
+        client
+            .get_project_verification_tier(&project_id)
+            .unwrap()
+            .tier_id,
+        basic
+    );
+
+    client.request_tier_renewal(
+        &project_id,
+        &owner,
+        &audited,
+        &String::from_str(&env, EVIDENCE),
+    );
+    client.approve_renewal(&project_id, &admin);
+    assert_eq!(
+        client
+            .get_project_verification_tier(&project_id)
+            .unwrap()
+            .tier_id,
+        audited
+    );
+    assert_eq!(
+        client.get_verification(&project_id).unwrap().tier_id,
+        Some(audited)
+    );
+    assert_eq!(client.get_project_tier_grants(&project_id).len(), 2);
+
+    // The higher tier lapses first; the project falls back to the lower one.
+    env.ledger().with_mut(|l| l.timestamp += 5_000);
+    assert_eq!(
+        client
+            .get_project_verification_tier(&project_id)
+            .unwrap()
+            .tier_id,
+        basic
+    );
+
+    // Deactivating a tier removes it from resolution.
+    client.set_verification_tier_active(&admin, &basic, &false);
+    assert!(client.get_project_verification_tier(&project_id).is_none());
+}
+
+#[test]
+fn test_inactive_tier_rejected_and_revoke_clears_tiers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "TierRevoke");
+    let tier_id =
+        client.create_verification_tier(&admin, &tier_params(&env, "Identity", 1, 0, 10_000));
+
+    client.set_verification_tier_active(&admin, &tier_id, &false);
+    assert_eq!(
+        client.try_request_tier_verification(
+            &project_id,
+            &owner,
+            &tier_id,
+            &String::from_str(&env, EVIDENCE)
+        ),
+        Err(Ok(ContractError::VerificationTierInactive))
+    );
+    assert_eq!(
+        client.try_request_tier_verification(
+            &project_id,
+            &owner,
+            &7,
+            &String::from_str(&env, EVIDENCE)
+        ),
+        Err(Ok(ContractError::VerificationTierNotFound))
+    );
+
+    client.set_verification_tier_active(&admin, &tier_id, &true);
+    client.request_tier_verification(
+        &project_id,
+        &owner,
+        &tier_id,
+        &String::from_str(&env, EVIDENCE),
+    );
+    client.approve_verification(&project_id, &admin);
+    assert!(client.get_project_verification_tier(&project_id).is_some());
+
+    client.revoke_verification(
+        &project_id,
+        &admin,
+        &String::from_str(&env, "expired audit"),
+    );
+    assert!(client.get_project_verification_tier(&project_id).is_none());
+    assert_eq!(client.get_project_tier_grants(&project_id).len(), 0);
+}
+
+#[test]
+fn test_list_projects_by_status_filters_by_tier() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let basic =
+        client.create_verification_tier(&admin, &tier_params(&env, "Identity", 1, 0, 10_000));
+    let legal = client.create_verification_tier(&admin, &tier_params(&env, "Legal", 2, 0, 10_000));
+
+    let untiered = create_test_project(&client, &owner, "Untiered");
+    let identity = create_test_project(&client, &owner, "IdentityOnly");
+    let entity = create_test_project(&client, &owner, "LegalEntity");
+
+    client.request_verification(&untiered, &owner, &String::from_str(&env, EVIDENCE));
+    client.approve_verification(&untiered, &admin);
+    client.request_tier_verification(&identity, &owner, &basic, &String::from_str(&env, EVIDENCE));
+    client.approve_verification(&identity, &admin);
+    client.request_tier_verification(&entity, &owner, &legal, &String::from_str(&env, EVIDENCE));
+    client.approve_verification(&entity, &admin);
+
+    let all = client.list_projects_by_status(&VerificationStatus::Verified, &1, &100);
+    assert_eq!(all.len(), 3);
+
+    let basic_only =
+        client.list_projects_by_status_and_tier(&VerificationStatus::Verified, &basic, &1, &100);
+    assert_eq!(basic_only.len(), 1);
+    assert_eq!(basic_only.get(0).unwrap().id, identity);
+
+    let legal_only =
+        client.list_projects_by_status_and_tier(&VerificationStatus::Verified, &legal, &1, &100);
+    assert_eq!(legal_only.len(), 1);
+    assert_eq!(legal_only.get(0).unwrap().id, entity);
+}
+
+#[test]
+fn test_records_stored_before_tiers_still_readable() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "LegacyRecords");
+    let evidence = String::from_str(&env, EVIDENCE);
+
+    // Rewrite a pending request in the layout used before tiers existed.
+    client.request_verification(&project_id, &owner, &evidence);
+    let record = client.get_verification(&project_id).unwrap();
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &StorageKey::VerificationRecord(record.request_id),
+            &LegacyVerificationRecord {
+                request_id: record.request_id,
+                project_id,
+                requester: owner.clone(),
+                status: record.status.clone(),
+                evidence_cid: record.evidence_cid.clone(),
+                requested_at: record.requested_at,
+                decided_at: record.decided_at,
+                fee_amount: record.fee_amount,
+                revoke_reason: None,
+                expires_at: record.expires_at,
+                last_renewed_at: record.last_renewed_at,
+                assigned_admin: record.assigned_admin.clone(),
+            },
+        );
+    });
+    let legacy = client.get_verification(&project_id).unwrap();
+    assert_eq!(legacy.tier_id, None);
+    assert_eq!(legacy.required_approvals, 1);
+    assert_eq!(client.get_verification_history(&project_id).len(), 1);
+    client.approve_verification(&project_id, &admin);
+    assert_eq!(
+        client.get_verification(&project_id).unwrap().status,
+        VerificationStatus::Verified
+    );
+
+    // Same for a pending renewal.
+    client.request_renewal(&project_id, &owner, &evidence);
+    let renewal = client.get_renewal_request(&project_id).unwrap();
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &StorageKey::VerificationRenewal(project_id),
+            &LegacyVerificationRenewalRecord {
+                project_id,
+                requester: owner.clone(),
+                status: renewal.status.clone(),
+                evidence_cid: renewal.evidence_cid.clone(),
+                timestamp: renewal.timestamp,
+                fee_amount: renewal.fee_amount,
+                expires_at: renewal.expires_at,
+            },
+        );
+    });
+    assert_eq!(client.get_renewal_request(&project_id), Some(renewal));
+    client.approve_renewal(&project_id, &admin);
+    assert_eq!(client.get_renewal_history(&project_id, &0, &10).len(), 1);
+}
@@ -0,0 +1,133 @@
+//! Tests for owner withdrawal of pending verification requests.
+
+use crate::errors::ContractError;
+use crate::tests::fixtures::{create_test_project, setup_contract};
+use crate::types::{AdminActionType, EscrowRefundPolicy, FeeEscrowConfig, VerificationStatus};
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+const EVIDENCE: &str = "QmWithdrawEvidence12345678901234567890123456";
+
+#[test]
+fn test_withdraw_refunds_configured_share() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let treasury = Address::generate(&env);
+    client.set_fee(&admin, &Some(token.clone()), &200, &0, &treasury);
+    client.set_withdrawal_refund_bps(&admin, &7_500);
+    assert_eq!(
+        client
+            .list_admin_actions(&0, &1)
+            .get(0)
+            .unwrap()
+            .action_type,
+        AdminActionType::VerificationSettingsChanged
+    );
+
+    // A fee already paid to the treasury is refunded by the treasury.
+    let owner = Address::generate(&env);
+    let balances = token::Client::new(&env, &token);
+    let paid_out = create_test_project(&client, &owner, "WithdrawPaidOut");
+    token::StellarAssetClient::new(&env, &token).mint(&owner, &400);
+    client.pay_fee(&owner, &paid_out, &Some(token.clone()));
+    client.request_verification(&paid_out, &owner, &String::from_str(&env, EVIDENCE));
+    assert_eq!(client.withdraw_verification_request(&paid_out, &owner), 150);
+    assert!(env.auths().iter().any(|(address, _)| *address == treasury));
+    assert_eq!(balances.balance(&owner), 350);
+    assert_eq!(balances.balance(&treasury), 50);
+
+    client.set_fee_escrow_config(
+        &admin,
+        &FeeEscrowConfig {
+            enabled: true,
+            refund_policy: EscrowRefundPolicy::Full,
+        },
+    );
+    let project_id = create_test_project(&client, &owner, "WithdrawRefund");
+    client.pay_fee(&owner, &project_id, &Some(token.clone()));
+    client.request_verification(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+
+    assert_eq!(
+        client.withdraw_verification_request(&project_id, &owner),
+        150
+    );
+    assert_eq!(balances.balance(&owner), 300);
+    assert_eq!(balances.balance(&treasury), 100);
+
+    assert_eq!(
+        client.get_project(&project_id).unwrap().verification_status,
+        VerificationStatus::Withdrawn
+    );
+    let history = client.get_verification_history(&project_id);
+    assert_eq!(history.len(), 1);
+    assert_eq!(
+        history.get(0).unwrap().status,
+        VerificationStatus::Withdrawn
+    );
+    assert_eq!(client.get_verification_queue_position(&project_id), None);
+}
+
+#[test]
+fn test_withdraw_without_refund_and_resubmit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "WithdrawFree");
+
+    assert_eq!(client.get_withdrawal_refund_bps(), 0);
+    assert_eq!(
+        client.try_withdraw_verification_request(&project_id, &owner),
+        Err(Ok(ContractError::VerificationNotFound))
+    );
+
+    client.request_verification(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+    assert_eq!(
+        client.try_withdraw_verification_request(&project_id, &Address::generate(&env)),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(client.withdraw_verification_request(&project_id, &owner), 0);
+
+    // A withdrawn project can request verification again.
+    client.request_verification(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+    assert_eq!(
+        client.get_project(&project_id).unwrap().verification_status,
+        VerificationStatus::Pending
+    );
+    assert_eq!(client.get_verification_history(&project_id).len(), 2);
+}
+
+#[test]
+fn test_withdraw_rejected_once_in_review() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_withdrawal_refund_bps(&admin, &10_001),
+        Err(Ok(ContractError::InvalidInput))
+    );
+
+    let assigned = create_test_project(&client, &owner, "WithdrawAssigned");
+    client.request_verification(&assigned, &owner, &String::from_str(&env, EVIDENCE));
+    client.assign_verification(&assigned, &admin, &admin);
+    assert_eq!(
+        client.try_withdraw_verification_request(&assigned, &owner),
+        Err(Ok(ContractError::VerificationInReview))
+    );
+
+    let second = Address::generate(&env);
+    client.add_admin(&admin, &second);
+    client.set_verification_quorum(&admin, &2);
+    let attested = create_test_project(&client, &owner, "WithdrawAttested");
+    client.request_verification(&attested, &owner, &String::from_str(&env, EVIDENCE));
+    client.attest_verification(&attested, &admin, &None);
+    assert_eq!(
+        client.try_withdraw_verification_request(&attested, &owner),
+        Err(Ok(ContractError::VerificationInReview))
+    );
+}
@@ -0,0 +1,223 @@
+//! Tests for verified-interaction badges on reviews.
+
+use crate::errors::ContractError;
+use crate::storage_keys::StorageKey;
+use crate::storage_migration::LegacyReview;
+use crate::tests::fixtures::{create_test_project, setup_contract};
+use crate::types::ReviewSortMode;
+use crate::DongleContractClient;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    Address, Env, String,
+};
+
+/// Register `contract` as an approved contract claim of `project_id`.
+fn verify_contract(
+    env: &Env,
+    client: &DongleContractClient,
+    project_id: u64,
+    owner: &Address,
+    admin: &Address,
+    contract: &Address,
+) {
+    let contract_str = contract.to_string();
+    let proof_cid = String::from_str(env, "QmProofCID1234567890123456789012345678901234567");
+    client.claim_contract_address(&project_id, owner, &contract_str, &proof_cid);
+    client.approve_contract_claim(&project_id, &contract_str, admin);
+}
+
+#[test]
+fn test_project_contract_can_attest_review() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "AttestedProject");
+    let dapp = Address::generate(&env);
+    verify_contract(&env, &client, project_id, &owner, &admin, &dapp);
+
+    let reviewer = Address::generate(&env);
+    client.add_review(&project_id, &reviewer, &5, &None);
+    assert!(
+        !client
+            .get_review(&project_id, &reviewer)
+            .unwrap()
+            .verified_user
+    );
+
+    client.attach_interaction_proof(&project_id, &reviewer, &dapp);
+
+    assert!(
+        client
+            .get_review(&project_id, &reviewer)
+            .unwrap()
+            .verified_user
+    );
+    let proof = client
+        .get_interaction_proof(&project_id, &reviewer)
+        .unwrap();
+    assert_eq!(proof.attestor, dapp);
+    assert!(proof.from_project_contract);
+}
+
+#[test]
+fn test_trusted_attestor_can_attest_review() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "TrustedProject");
+    let dapp = Address::generate(&env);
+    verify_contract(&env, &client, project_id, &owner, &admin, &dapp);
+
+    let oracle = Address::generate(&env);
+    let reviewer = Address::generate(&env);
+    client.add_review(&project_id, &reviewer, &4, &None);
+
+    let result = client.try_attach_interaction_proof(&project_id, &reviewer, &oracle);
+    assert_eq!(result, Err(Ok(ContractError::AttestorNotTrusted)));
+
+    client.add_trusted_attestor(&admin, &oracle);
+    assert_eq!(client.get_trusted_attestors().len(), 1);
+    client.attach_interaction_proof(&project_id, &reviewer, &oracle);
+    assert!(
+        !client
+            .get_interaction_proof(&project_id, &reviewer)
+            .unwrap()
+            .from_project_contract
+    );
+
+    client.remove_trusted_attestor(&admin, &oracle);
+    assert_eq!(client.get_trusted_attestors().len(), 0);
+    // Already-accepted proofs survive removal of the attestor.
+    assert!(
+        client
+            .get_review(&project_id, &reviewer)
+            .unwrap()
+            .verified_user
+    );
+}
+
+#[test]
+fn test_attach_proof_requires_verified_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let project_id = create_test_project(&client, &admin, "NoContracts");
+    let oracle = Address::generate(&env);
+    client.add_trusted_attestor(&admin, &oracle);
+
+    let reviewer = Address::generate(&env);
+    client.add_review(&project_id, &reviewer, &4, &None);
+
+    let result = client.try_attach_interaction_proof(&project_id, &reviewer, &oracle);
+    assert_eq!(result, Err(Ok(ContractError::NoVerifiedContracts)));
+}
+
+#[test]
+fn test_attach_proof_requires_existing_review() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "NoReview");
+    let dapp = Address::generate(&env);
+    verify_contract(&env, &client, project_id, &owner, &admin, &dapp);
+
+    let reviewer = Address::generate(&env);
+    let result = client.try_attach_interaction_proof(&project_id, &reviewer, &dapp);
+    assert_eq!(result, Err(Ok(ContractError::ReviewNotFound)));
+}
+
+#[test]
+fn test_trusted_attestor_management_is_admin_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let stranger = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    let result = client.try_add_trusted_attestor(&stranger, &oracle);
+    assert!(result.is_err());
+    assert_eq!(client.get_trusted_attestors().len(), 0);
+}
+
+#[test]
+fn test_list_reviews_sorted_verified_filter_and_ordering() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "SortedBadges");
+    let dapp = Address::generate(&env);
+    verify_contract(&env, &client, project_id, &owner, &admin, &dapp);
+
+    let verified = Address::generate(&env);
+    client.add_review(&project_id, &verified, &3, &None);
+    env.ledger().with_mut(|l| l.timestamp += 10);
+    let plain = Address::generate(&env);
+    client.add_review(&project_id, &plain, &5, &None);
+    client.attach_interaction_proof(&project_id, &verified, &dapp);
+
+    let all = client.list_reviews_sorted(&project_id, &0, &10, &ReviewSortMode::VerifiedFirst);
+    assert_eq!(all.len(), 2);
+    assert_eq!(all.get(0).unwrap().reviewer, verified);
+    assert_eq!(all.get(1).unwrap().reviewer, plain);
+
+    let only = client.list_verified_reviews_sorted(&project_id, &0, &10, &ReviewSortMode::Newest);
+    assert_eq!(only.len(), 1);
+    assert_eq!(only.get(0).unwrap().reviewer, verified);
+}
+
+#[test]
+fn test_deleting_review_drops_interaction_proof() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "DeleteBadge");
+    let dapp = Address::generate(&env);
+    verify_contract(&env, &client, project_id, &owner, &admin, &dapp);
+
+    let reviewer = Address::generate(&env);
+    client.add_review(&project_id, &reviewer, &4, &None);
+    client.attach_interaction_proof(&project_id, &reviewer, &dapp);
+    client.delete_review(&project_id, &reviewer);
+
+    assert!(client
+        .get_interaction_proof(&project_id, &reviewer)
+        .is_none());
+}
+
+#[test]
+fn test_review_stored_before_badges_still_readable() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _) = setup_contract(&env);
+    let project_id = create_test_project(&client, &Address::generate(&env), "LegacyReview");
+    let reviewer = Address::generate(&env);
+    client.add_review(&project_id, &reviewer, &4, &None);
+
+    // Rewrite the entry in the layout used before `verified_user` existed.
+    let review = client.get_review(&project_id, &reviewer).unwrap();
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &StorageKey::Review(project_id, reviewer.clone()),
+            &LegacyReview {
+                project_id,
+                reviewer: reviewer.clone(),
+                rating: review.rating,
+                content_cid: review.content_cid.clone(),
+                owner_response: review.owner_response.clone(),
+                created_at: review.created_at,
+                updated_at: review.updated_at,
+                hidden: review.hidden,
+                report_count: review.report_count,
+            },
+        );
+    });
+
+    assert_eq!(client.get_review(&project_id, &reviewer), Some(review));
+    client.update_review(&project_id, &reviewer, &2, &None);
+    assert_eq!(client.get_review(&project_id, &reviewer).unwrap().rating, 2);
+}
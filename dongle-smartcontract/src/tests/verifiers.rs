@@ -0,0 +1,213 @@
+//! Tests for the delegated verifier role.
+
+use crate::errors::ContractError;
+use crate::tests::fixtures::{create_test_project, setup_contract};
+use crate::types::{AdminActionType, VerificationStatus, VerificationTierParams};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    vec, Address, Env, String, Vec,
+};
+
+const EVIDENCE: &str = "QmVerifierEvidence1234567890123456789012345678";
+const REPORT: &str = "QmVerifierReport123456789012345678901234567890";
+
+#[test]
+fn test_verifier_approves_in_scope_and_is_attributed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let auditor = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "VerifierScope");
+
+    client.grant_verifier(
+        &admin,
+        &auditor,
+        &vec![&env, String::from_str(&env, "DeFi")],
+        &Vec::new(&env),
+        &0,
+    );
+    assert_eq!(client.list_verifiers(), vec![&env, auditor.clone()]);
+    assert_eq!(
+        client
+            .list_admin_actions(&0, &1)
+            .get(0)
+            .unwrap()
+            .action_type,
+        AdminActionType::VerifierRoleChanged
+    );
+
+    client.request_verification(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+    client.approve_verification(&project_id, &auditor);
+    assert_eq!(
+        client.get_project(&project_id).unwrap().verification_status,
+        VerificationStatus::Verified
+    );
+    let entry = client.list_admin_actions(&0, &1).get(0).unwrap();
+    assert_eq!(entry.action_type, AdminActionType::VerificationApproved);
+    assert_eq!(entry.admin, auditor);
+
+    // The role grants no other admin powers.
+    let treasury = Address::generate(&env);
+    assert_eq!(
+        client.try_set_fee(&auditor, &None, &0, &0, &treasury),
+        Err(Ok(ContractError::AdminOnly))
+    );
+    assert_eq!(
+        client.try_add_admin(&auditor, &Address::generate(&env)),
+        Err(Ok(ContractError::AdminOnly))
+    );
+}
+
+#[test]
+fn test_verifier_scope_by_category_and_tier() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let tier_id = client.create_verification_tier(
+        &admin,
+        &VerificationTierParams {
+            name: String::from_str(&env, "Audited"),
+            level: 2,
+            fee: 0,
+            duration_seconds: 10_000,
+            requirements_cid: String::from_str(&env, EVIDENCE),
+            badge_cid: String::from_str(&env, EVIDENCE),
+        },
+    );
+
+    let nft_only = Address::generate(&env);
+    client.grant_verifier(
+        &admin,
+        &nft_only,
+        &vec![&env, String::from_str(&env, "NFT")],
+        &Vec::new(&env),
+        &0,
+    );
+    let tier_auditor = Address::generate(&env);
+    client.grant_verifier(
+        &admin,
+        &tier_auditor,
+        &Vec::new(&env),
+        &vec![&env, tier_id],
+        &0,
+    );
+
+    let untiered = create_test_project(&client, &owner, "ScopeUntiered");
+    client.request_verification(&untiered, &owner, &String::from_str(&env, EVIDENCE));
+    assert_eq!(
+        client.try_approve_verification(&untiered, &nft_only),
+        Err(Ok(ContractError::VerifierOutOfScope))
+    );
+    assert_eq!(
+        client.try_reject_verification(&untiered, &tier_auditor),
+        Err(Ok(ContractError::VerifierOutOfScope))
+    );
+
+    let tiered = create_test_project(&client, &owner, "ScopeTiered");
+    client.request_tier_verification(&tiered, &owner, &tier_id, &String::from_str(&env, EVIDENCE));
+    client.reject_verification(&tiered, &tier_auditor);
+    assert_eq!(
+        client.get_project(&tiered).unwrap().verification_status,
+        VerificationStatus::Rejected
+    );
+}
+
+#[test]
+fn test_verifier_expiry_and_revocation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "VerifierExpiry");
+    client.request_verification(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    let now = env.ledger().timestamp();
+    assert_eq!(
+        client.try_grant_verifier(&admin, &owner, &Vec::new(&env), &Vec::new(&env), &now),
+        Err(Ok(ContractError::InvalidInput))
+    );
+
+    let auditor = Address::generate(&env);
+    client.grant_verifier(
+        &admin,
+        &auditor,
+        &Vec::new(&env),
+        &Vec::new(&env),
+        &(now + 100),
+    );
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    assert_eq!(
+        client.try_approve_verification(&project_id, &auditor),
+        Err(Ok(ContractError::AdminOnly))
+    );
+
+    client.grant_verifier(&admin, &auditor, &Vec::new(&env), &Vec::new(&env), &0);
+    client.revoke_verifier(&admin, &auditor);
+    assert!(client.get_verifier(&auditor).is_none());
+    assert_eq!(client.list_verifiers().len(), 0);
+    assert_eq!(
+        client.try_attest_verification(&project_id, &auditor, &None),
+        Err(Ok(ContractError::AdminOnly))
+    );
+    assert_eq!(
+        client.try_revoke_verifier(&admin, &auditor),
+        Err(Ok(ContractError::VerifierNotFound))
+    );
+}
+
+#[test]
+fn test_assigned_verifier_decides_and_attaches_reports() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let owner = Address::generate(&env);
+    let project_id = create_test_project(&client, &owner, "VerifierAssigned");
+    let assigned = Address::generate(&env);
+    let other = Address::generate(&env);
+    for verifier in [&assigned, &other] {
+        client.grant_verifier(&admin, verifier, &Vec::new(&env), &Vec::new(&env), &0);
+    }
+
+    client.request_verification(&project_id, &owner, &String::from_str(&env, EVIDENCE));
+    assert_eq!(
+        client.try_assign_verification(&project_id, &admin, &Address::generate(&env)),
+        Err(Ok(ContractError::AdminNotFound))
+    );
+    client.assign_verification(&project_id, &admin, &assigned);
+    assert_eq!(
+        client.get_assigned_admin(&project_id),
+        Some(assigned.clone())
+    );
+
+    assert_eq!(
+        client.try_attach_verification_report(&project_id, &other, &String::from_str(&env, REPORT)),
+        Err(Ok(ContractError::VerifierOutOfScope))
+    );
+    client.attach_verification_report(&project_id, &assigned, &String::from_str(&env, REPORT));
+    let request_id = client.get_verification(&project_id).unwrap().request_id;
+    let reports = client.get_verification_reports(&request_id);
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports.get(0).unwrap().author, assigned);
+    let entry = client.list_admin_actions(&0, &1).get(0).unwrap();
+    assert_eq!(
+        entry.action_type,
+        AdminActionType::VerificationReportAttached
+    );
+    assert_eq!(
+        (entry.admin, entry.target_id),
+        (assigned.clone(), Some(project_id))
+    );
+
+    assert_eq!(
+        client.try_approve_verification(&project_id, &other),
+        Err(Ok(ContractError::VerifierOutOfScope))
+    );
+    client.approve_verification(&project_id, &assigned);
+    assert_eq!(
+        client.get_project(&project_id).unwrap().verification_status,
+        VerificationStatus::Verified
+    );
+}
@@ -1,4 +1,5 @@
-use soroban_sdk::{contracttype, Address, Map, String, Vec};
+use crate::events::FeeOperation;
+use soroban_sdk::{contracttype, Address, BytesN, Map, String, Symbol, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -66,6 +67,24 @@ pub struct Review {
 
     /// Number of times this review has been reported.
     pub report_count: u32,
+
+    /// Whether the reviewer attached an accepted proof of interaction with the
+    /// project (see `ReviewInteractionProof`).
+    pub verified_user: bool,
+}
+
+/// Proof that a reviewer actually interacted with a project, attested by one
+/// of the project's verified contracts or by a trusted attestor address.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewInteractionProof {
+    pub project_id: u64,
+    pub reviewer: Address,
+    pub attestor: Address,
+    /// True when the attestor is one of the project's verified contracts,
+    /// false when it is a globally trusted attestor.
+    pub from_project_contract: bool,
+    pub attested_at: u64,
 }
 
 #[contracttype]
@@ -233,6 +252,10 @@ pub enum VerificationStatus {
     Pending,
     Verified,
     Rejected,
+    /// The owner withdrew the request before it was assigned.
+    Withdrawn,
+    /// Verification is on hold during an incident; see `VerificationSuspension`.
+    Suspended,
 }
 
 #[contracttype]
@@ -253,6 +276,256 @@ pub struct VerificationRecord {
     pub last_renewed_at: u64,
     /// Admin assigned to review this verification request
     pub assigned_admin: Option<Address>,
+    /// Verification tier requested (`None` = untiered verification)
+    pub tier_id: Option<u32>,
+    /// Verifier sign-offs needed before the request is approved
+    pub required_approvals: u32,
+    /// Sign-offs collected so far, in the order they were given
+    pub approvals: Vec<VerificationApproval>,
+    /// Checklist items ticked by verifiers, one entry per item
+    pub checklist: Vec<ChecklistAttestation>,
+}
+
+/// One item of an admin-defined verification checklist.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChecklistItem {
+    /// Identifier verifiers tick the item by, e.g. `domain_ownership`
+    pub key: Symbol,
+    pub label: String,
+    /// Whether the item must be ticked before the request can be approved
+    pub required: bool,
+}
+
+/// Which verification requests a checklist applies to.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ChecklistScope {
+    /// Requests for projects in this category
+    Category(String),
+    /// Requests for this verification tier
+    Tier(u32),
+}
+
+/// A verifier's tick of one checklist item on a verification request.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChecklistAttestation {
+    pub key: Symbol,
+    pub verifier: Address,
+    /// Optional IPFS CID of the verifier's note on the check
+    pub note_cid: Option<String>,
+    pub attested_at: u64,
+}
+
+/// Delegated verifier role granted by an admin. Verifiers can be assigned
+/// requests, approve, reject and attest them, and attach reports, within
+/// their scope.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifierGrant {
+    pub verifier: Address,
+    /// Project categories the verifier may act on (empty = any category)
+    pub categories: Vec<String>,
+    /// Tiers the verifier may act on (empty = any request, tiered or not)
+    pub tier_ids: Vec<u32>,
+    /// Unix timestamp when the role lapses (0 = no expiry)
+    pub expires_at: u64,
+    pub granted_by: Address,
+    pub granted_at: u64,
+}
+
+/// A report attached to a verification request by an admin or verifier.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationReport {
+    pub author: Address,
+    pub report_cid: String,
+    pub attached_at: u64,
+}
+
+/// Off-chain service trusted to sign attestations about projects, such as
+/// GitHub org membership, DNS control or an audit firm's sign-off.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attestor {
+    /// ed25519 public key the attestor signs with
+    pub public_key: BytesN<32>,
+    pub name: String,
+    /// Claim types the attestor may sign (empty = any claim type)
+    pub claim_types: Vec<Symbol>,
+    pub registered_by: Address,
+    pub registered_at: u64,
+}
+
+/// Signed body of an off-chain attestation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationPayload {
+    /// Public key of the registered attestor that signed the attestation
+    pub attestor: BytesN<32>,
+    /// What was attested, e.g. a GitHub org, a domain or an audit report CID
+    pub subject: String,
+    /// Single-use value chosen by the attestor
+    pub nonce: u64,
+    /// Unix timestamp after which the signature can no longer be submitted
+    pub expires_at: u64,
+}
+
+/// The message an attestor signs: the XDR encoding of this struct.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationMessage {
+    /// Contract the attestation is meant for
+    pub contract: Address,
+    pub project_id: u64,
+    pub claim_type: Symbol,
+    pub payload: AttestationPayload,
+}
+
+/// An off-chain attestation accepted for a project.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectAttestation {
+    pub claim_type: Symbol,
+    pub payload: AttestationPayload,
+    pub submitted_at: u64,
+}
+
+/// Why and until when a verification is suspended.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationSuspension {
+    pub request_id: u64,
+    /// IPFS CID of the incident notes
+    pub reason_cid: String,
+    pub suspended_by: Address,
+    pub suspended_at: u64,
+    /// Unix timestamp when the suspension lapses on its own (0 = manual resume only)
+    pub resume_at: u64,
+}
+
+/// How new verification requests are assigned to delegated verifiers.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VerificationAssignmentMode {
+    /// Requests stay unassigned until an admin calls `assign_verification`.
+    Manual,
+    /// Rotate through eligible verifiers in the order they were granted.
+    RoundRobin,
+    /// Pick the eligible verifier with the fewest pending assignments.
+    LeastLoaded,
+}
+
+/// A pending verification request as listed by the queue.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingVerification {
+    pub request_id: u64,
+    pub project_id: u64,
+    pub requester: Address,
+    pub requested_at: u64,
+    /// Seconds since the request was submitted
+    pub age_seconds: u64,
+    pub assigned_admin: Option<Address>,
+    pub tier_id: Option<u32>,
+    /// Whether the request is older than the configured SLA
+    pub sla_breached: bool,
+}
+
+/// A verification in the expiry index.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpiringVerification {
+    pub project_id: u64,
+    pub expires_at: u64,
+}
+
+/// One page of `list_expiring_verifications`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpiringVerificationPage {
+    /// Verifications expiring at or before the requested time, soonest first.
+    pub entries: Vec<ExpiringVerification>,
+    /// Whether entries remain after this page.
+    pub has_more: bool,
+    /// Entry to resume from when `has_more` is set.
+    pub next_cursor: ExpiryCursor,
+}
+
+/// Where a listing of the expiry index resumes: the first entry at or after
+/// this expiry time and project ID. The expiry time names the bucket to start
+/// from, and verifications indexed or removed between calls do not shift it.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ExpiryCursor {
+    pub expires_at: u64,
+    pub project_id: u64,
+}
+
+/// Proof item types from `verification-evidence.schema.json`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EvidenceProofType {
+    Repository,
+    Deployment,
+    Transaction,
+    Screenshot,
+    Audit,
+    Attestation,
+    Signature,
+    Documentation,
+    Other,
+}
+
+/// Summary fields of an evidence document, as declared by the requester.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EvidenceSummary {
+    /// Number of entries in `proofs` (1-50)
+    pub proof_count: u32,
+    /// Distinct proof types present in `proofs`
+    pub proof_types: Vec<EvidenceProofType>,
+    /// Number of entries in `attestations` (0-25)
+    pub attestation_count: u32,
+    /// Number of entries in `signatures` (0-25)
+    pub signature_count: u32,
+    /// `privacy.containsPersonalData`
+    pub contains_personal_data: bool,
+}
+
+/// On-chain commitment to the evidence document behind a request's CID.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EvidenceCommitment {
+    pub request_id: u64,
+    /// Evidence CID the hash was committed for
+    pub evidence_cid: String,
+    /// SHA-256 of the canonical JSON evidence document
+    pub evidence_sha256: soroban_sdk::BytesN<32>,
+    pub summary: EvidenceSummary,
+    pub committed_by: Address,
+    pub committed_at: u64,
+}
+
+/// Result of one `sweep_expired_verifications` batch.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationSweepResult {
+    /// Projects moved from Verified to Unverified by this batch.
+    pub expired: Vec<u64>,
+    /// Project ID to resume from, or `None` once every project was checked.
+    pub next_cursor: Option<u64>,
+}
+
+/// One verifier's sign-off on a verification request.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationApproval {
+    pub verifier: Address,
+    /// Optional IPFS CID of the verifier's review notes
+    pub notes_cid: Option<String>,
+    pub approved_at: u64,
 }
 
 #[contracttype]
@@ -266,6 +539,94 @@ pub struct VerificationRenewalRecord {
     pub fee_amount: u128,
     /// Unix timestamp when the renewed verification expires
     pub expires_at: u64,
+    /// Tier the verification is renewed at (`None` = untiered)
+    pub tier_id: Option<u32>,
+}
+
+/// Admin-configured retention for verification and renewal history, applied
+/// on each new request. A record is pruned once it falls outside the most
+/// recent `max_records` or is older than `max_age_seconds`; 0 disables a
+/// limit. The newest record is always kept.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistoryRetentionPolicy {
+    pub max_records: u32,
+    pub max_age_seconds: u64,
+}
+
+/// Hash chain over the records pruned from one history. Each pruned record
+/// extends `chain_hash` as `sha256(chain_hash || record.to_xdr())`, starting
+/// from 32 zero bytes, oldest record first.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistorySummary {
+    pub pruned_count: u32,
+    pub last_pruned_at: u64,
+    pub chain_hash: BytesN<32>,
+}
+
+/// One page of a project's verification history.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationHistoryPage {
+    /// Records in request order.
+    pub records: Vec<VerificationRecord>,
+    /// Request ID to resume from, or `None` once the history is complete.
+    pub next_cursor: Option<u64>,
+}
+
+/// One page of a project's renewal history.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RenewalHistoryPage {
+    /// Records in approval order.
+    pub records: Vec<VerificationRenewalRecord>,
+    /// History index to resume from, or `None` once the history is complete.
+    pub next_cursor: Option<u32>,
+}
+
+/// Admin-defined verification tier, e.g. Identity, Code-audited or Legal entity.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationTier {
+    pub tier_id: u32,
+    pub name: String,
+    /// Rank among tiers. A project's tier is its active grant with the highest level.
+    pub level: u32,
+    /// Fee (in the configured fee token) for a request or renewal at this tier.
+    pub fee: u128,
+    /// How long a verification at this tier stays valid, in seconds.
+    pub duration_seconds: u64,
+    /// CID of the document describing the evidence a request must include.
+    pub requirements_cid: String,
+    /// CID of the badge frontends display for this tier.
+    pub badge_cid: String,
+    /// Inactive tiers accept no new requests and do not count towards a
+    /// project's tier.
+    pub active: bool,
+}
+
+/// Admin-supplied fields of a `VerificationTier`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationTierParams {
+    pub name: String,
+    pub level: u32,
+    pub fee: u128,
+    pub duration_seconds: u64,
+    pub requirements_cid: String,
+    pub badge_cid: String,
+}
+
+/// A tier held by a project until `expires_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationTierGrant {
+    pub tier_id: u32,
+    /// Verification request that granted (or last renewed) the tier.
+    pub request_id: u64,
+    pub granted_at: u64,
+    pub expires_at: u64,
 }
 
 /// Fee configuration for contract operations
@@ -277,6 +638,73 @@ pub struct FeeConfig {
     pub registration_fee: u128,
 }
 
+/// Fee amounts charged when paying in an accepted token other than the
+/// default `FeeConfig` token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenFees {
+    pub token: Address,
+    pub verification_fee: u128,
+    pub registration_fee: u128,
+    pub review_fee: u128,
+}
+
+/// One recipient's share of a fee type's treasury revenue.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasurySplit {
+    pub recipient: Address,
+    /// Share in basis points; a fee type's shares sum to 10000.
+    pub bps: u32,
+}
+
+/// Amount of one fee payment transferred to a split recipient.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryPayout {
+    pub recipient: Address,
+    pub amount: u128,
+}
+
+/// How an escrowed verification fee is settled when the request is rejected.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EscrowRefundPolicy {
+    /// Refund the whole fee.
+    Full,
+    /// Refund this share of the fee, in basis points.
+    Partial(u32),
+    /// Keep up to this amount as a review fee and refund the rest.
+    KeepReviewFee(u128),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeEscrowConfig {
+    /// Whether new verification fee payments are held by the contract.
+    pub enabled: bool,
+    pub refund_policy: EscrowRefundPolicy,
+}
+
+/// A verification fee held by the contract until the verification decision.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowedFee {
+    pub payer: Address,
+    pub token: Address,
+    pub amount: u128,
+    pub escrowed_at: u64,
+}
+
+/// Escrowed fees in one token, next to the contract's balance of it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowTokenTotal {
+    pub token: Address,
+    pub escrowed: u128,
+    pub balance: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FeePaymentRecord {
@@ -286,6 +714,62 @@ pub struct FeePaymentRecord {
     pub token: Option<Address>,
 }
 
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FeePaymentStatus {
+    /// Paid and usable for a verification request.
+    Valid,
+    /// Paid, but older than the payment validity window.
+    Expired,
+    /// Used by a verification request or renewal.
+    Consumed,
+}
+
+/// A project's fee payment with its expiry status.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeePaymentDetails {
+    pub paid_at: u64,
+    pub payer: Address,
+    pub amount: u128,
+    pub token: Option<Address>,
+    /// When the payment expires; 0 if payments do not expire.
+    pub expires_at: u64,
+    pub status: FeePaymentStatus,
+}
+
+/// Running fee amounts collected from and refunded to payers.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FeeRevenueTotals {
+    pub collected: u128,
+    pub refunded: u128,
+}
+
+/// Fee revenue of one token and fee type.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeRevenueEntry {
+    pub token: Address,
+    pub operation: FeeOperation,
+    pub collected: u128,
+    pub refunded: u128,
+    /// `collected - refunded`; negative when a period refunds fees collected
+    /// in earlier periods.
+    pub net: i128,
+}
+
+/// Fee revenue per token and fee type, all-time or for one period.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeRevenueReport {
+    /// Start of the reported period; 0 for the all-time report.
+    pub period_start: u64,
+    /// End of the reported period (exclusive); 0 for the all-time report.
+    pub period_end: u64,
+    pub entries: Vec<FeeRevenueEntry>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FeeConfigHistoryEntry {
@@ -391,10 +875,95 @@ pub enum AdminActionType {
     VerificationAssigned,
     ReservedNameAdded,
     ReservedNameRemoved,
+    TrustedAttestorAdded,
+    TrustedAttestorRemoved,
+    AddressBlocked,
+    AddressUnblocked,
+    /// Admin hid a batch of reviews written by a blocked address.
+    ReviewsHiddenByReviewer,
     /// Admin toggled the global pause flag on (`true` was the new value).
     ContractPaused,
     /// Admin toggled the global pause flag off (`false` was the new value).
     ContractResumed,
+    /// Global review policy or a project override was set or cleared.
+    ReviewPolicyChanged,
+    /// Admin created a verification tier.
+    VerificationTierCreated,
+    /// Admin updated, activated or deactivated a verification tier.
+    VerificationTierUpdated,
+    /// A verifier signed off on a verification request.
+    VerificationAttested,
+    /// The number of sign-offs required for new verification requests changed.
+    VerificationQuorumSet,
+    /// A verifier role was granted, updated or revoked.
+    VerifierRoleChanged,
+    /// Verification workflow settings changed (queue assignment mode, SLA,
+    /// withdrawal refund).
+    VerificationSettingsChanged,
+    /// A verified project was suspended during an incident.
+    VerificationSuspended,
+    /// A suspended verification was resumed.
+    VerificationResumed,
+    /// An off-chain attestor was registered, updated or revoked.
+    AttestorChanged,
+    /// An admin or verifier recused themselves from a project.
+    Recused,
+    /// An admin or verifier attached a report to a verification request.
+    VerificationReportAttached,
+}
+
+/// A commit-reveal review round opened by a project owner. While a round is
+/// open (not yet finalized) the project only accepts reviews through
+/// `commit_review` / `reveal_review`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewRound {
+    pub round_id: u32,
+    pub project_id: u64,
+    pub opened_by: Address,
+    pub opened_at: u64,
+    /// Commitments are accepted until this timestamp.
+    pub commit_ends_at: u64,
+    /// Reveals are accepted from `commit_ends_at` until this timestamp.
+    pub reveal_ends_at: u64,
+    pub commit_count: u32,
+    pub reveal_count: u32,
+    /// Number of revealed reviews already added to the project.
+    pub finalized_count: u32,
+    pub finalized: bool,
+}
+
+/// Sealed review commitment: `sha256(rating_be_u32 || comment_cid || salt)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewCommitment {
+    pub round_id: u32,
+    pub reviewer: Address,
+    pub commitment: soroban_sdk::BytesN<32>,
+    pub committed_at: u64,
+}
+
+/// A revealed review waiting for its round to be finalized.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RevealedReview {
+    pub reviewer: Address,
+    pub rating: u32,
+    pub comment_cid: Option<String>,
+    pub revealed_at: u64,
+}
+
+/// Admin blocklist entry. A blocked address cannot register projects, review,
+/// report, endorse or follow until it is unblocked or `expires_at` passes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlocklistEntry {
+    pub address: Address,
+    pub reason_cid: String,
+    pub blocked_by: Address,
+    pub blocked_at: u64,
+    /// Ledger timestamp after which the block no longer applies; `None` = permanent.
+    pub expires_at: Option<u64>,
 }
 
 #[contracttype]
@@ -497,6 +1066,12 @@ pub enum ProposalPayload {
     ApproveVerification(u64),
     RejectVerification(u64),
     RevokeVerification(u64, String),
+    /// Set the global review policy (`None`) or a project's override.
+    SetReviewPolicy(Option<u64>, ReviewPolicy),
+    /// Remove a project's review policy override.
+    ClearProjectReviewPolicy(u64),
+    /// Set (or, with an empty list, clear) a fee type's treasury splits.
+    SetTreasurySplits(FeeOperation, Vec<TreasurySplit>),
 }
 
 #[contracttype]
@@ -522,6 +1097,27 @@ pub struct ReviewTombstone {
     pub deleted_at: u64,
 }
 
+/// One entry in a reviewer's review record.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReviewRecord {
+    Active(Review),
+    /// Hidden by moderation; excluded from project listings and aggregates.
+    Hidden(Review),
+    /// Deleted by the reviewer or an admin; only the tombstone remains.
+    Deleted(ReviewTombstone),
+}
+
+/// A page of `list_reviews_by_reviewer`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewerReviewPage {
+    pub entries: Vec<ReviewRecord>,
+    /// Cursor for the next page, or `None` once the record is exhausted.
+    /// Positional: only consistent while the reviewer's record is unchanged.
+    pub next_cursor: Option<u32>,
+}
+
 /// Optional anti-sybil review eligibility constraints.
 ///
 /// When all constraints are zero/false (default), any address may review
@@ -544,6 +1140,69 @@ pub struct ReviewEligibilityConfig {
     pub review_fee: u128,
 }
 
+/// Rules for editing an existing review. A global policy applies to every
+/// project unless the project has its own override.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewPolicy {
+    /// Minimum seconds between two updates of the same review.
+    pub update_cooldown_seconds: u64,
+    /// Revisions kept per review; the oldest is dropped beyond this
+    /// (`1..=MAX_REVIEW_REVISIONS`).
+    pub max_revisions: u32,
+    /// Seconds after creation during which a review may be edited.
+    /// Zero = no window, reviews stay editable.
+    pub edit_window_seconds: u64,
+    /// If true, an update that changes the rating must also change the
+    /// content CID.
+    pub new_cid_on_rating_change: bool,
+}
+
+/// Moderation and community signals tracked per reviewer address. These are
+/// the mutable inputs to `ReviewerReputation`; the account-age and verified
+/// ownership signals are read from existing storage at query time.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReviewerSignals {
+    /// Helpful votes received across all of the reviewer's reviews.
+    pub helpful_votes: u32,
+    /// Score earned from helpful votes, each weighted by the voter's
+    /// reputation when the vote was cast.
+    pub helpful_points: u32,
+    /// Reviews currently hidden by moderation.
+    pub hidden_reviews: u32,
+    /// Reviews permanently removed by an admin.
+    pub deleted_reviews: u32,
+}
+
+/// Computed reputation snapshot for a reviewer.
+///
+/// `score` is scaled by 100 (100 = neutral 1.00x weight) and is clamped to
+/// `[REPUTATION_MIN_SCORE, REPUTATION_MAX_SCORE]`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewerReputation {
+    pub reviewer: Address,
+    pub score: u32,
+    /// Seconds since the reviewer's `FirstInteraction` (0 if never recorded).
+    pub account_age_seconds: u64,
+    pub helpful_votes: u32,
+    pub hidden_reviews: u32,
+    pub deleted_reviews: u32,
+    /// Whether the reviewer owns at least one `Verified` project.
+    pub owns_verified_project: bool,
+}
+
+/// Reputation-weighted rating aggregates for a project. Each visible review
+/// contributes `rating * 100 * weight`, where `weight` is the reviewer's
+/// reputation score snapshotted when the review was submitted.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReputationWeightedStats {
+    pub weighted_rating_sum: u64,
+    pub weight_total: u64,
+}
+
 /// Sort order for `list_reviews_sorted`. Sorting is performed on-chain in-memory.
 /// For large projects this increases compute budget usage proportionally to review count.
 #[contracttype]
@@ -557,6 +1216,8 @@ pub enum ReviewSortMode {
     RatingHigh,
     /// Lowest rating first.
     RatingLow,
+    /// Reviews with a verified interaction first, newest first within each group.
+    VerifiedFirst,
 }
 
 /// Sort order for `list_projects_sorted`. Sorting is performed on-chain in-memory.
@@ -617,7 +1278,8 @@ pub struct ContractLimits {
     pub max_page_limit: u32,
     /// Maximum projects a single owner may register (`MAX_PROJECTS_PER_USER`).
     pub max_projects_per_user: u32,
-    /// Maximum reviewers indexed per project (`MAX_REVIEWS_PER_PROJECT`).
+    /// Maximum reviewers indexed per project. The review index is paged and
+    /// uncapped, so this reports `u32::MAX` (the width of the entry counter).
     pub max_reviews_per_project: u32,
     /// Maximum project name length in bytes (`MAX_NAME_LEN`).
     pub max_name_len: u32,
@@ -655,4 +1317,6 @@ pub struct ContractConfigView {
     pub fees: FeeConfig,
     /// User-facing limits (see `ContractLimits` doc for stability rules).
     pub limits: ContractLimits,
+    /// Global review edit policy (projects may override it).
+    pub review_policy: ReviewPolicy,
 }
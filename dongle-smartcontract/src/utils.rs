@@ -4,7 +4,8 @@ use soroban_sdk::{Env, String, Vec};
 
 use crate::constants::{
     MAX_CATEGORY_LEN, MAX_CID_LEN, MAX_DESCRIPTION_LEN, MAX_LICENSE_LEN, MAX_NAME_LEN,
-    MAX_SECURITY_CONTACT_LEN, MAX_SLUG_LEN, MAX_TAG_LENGTH, MAX_TAGS_PER_PROJECT, MAX_WEBSITE_LEN,
+    MAX_SECURITY_CONTACT_LEN, MAX_SLUG_LEN, MAX_SOCIAL_LINK_PLATFORM_LEN, MAX_TAGS_PER_PROJECT,
+    MAX_TAG_LENGTH, MAX_WEBSITE_LEN,
 };
 use crate::errors::ContractError;
 use crate::storage_keys::StorageKey;
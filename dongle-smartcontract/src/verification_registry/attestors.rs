@@ -0,0 +1,231 @@
+//! Off-chain attestations verified on-chain.
+//!
+//! Admins register the ed25519 public keys of trusted attestation services,
+//! optionally limited to some claim types. Anyone can then submit an
+//! attestation signed by a registered attestor; the signature covers this
+//! contract, the project, the claim type and the payload, and each payload
+//! carries a single-use nonce and a submission deadline so signatures cannot
+//! be replayed. Accepted attestations are stored per project, one per
+//! attestor and claim type; a replacement must carry a higher nonce than the
+//! stored attestation, so an older signed payload cannot roll it back.
+
+use crate::admin_action_log::AdminActionLog;
+use crate::auth::require_admin_auth;
+use crate::constants::{
+    MAX_ATTESTORS, MAX_ATTESTOR_CLAIM_TYPES, MAX_CID_LEN, MAX_NAME_LEN, MAX_PROJECT_ATTESTATIONS,
+};
+use crate::errors::ContractError;
+use crate::events::{
+    publish_attestation_submitted_event, publish_attestor_registered_event,
+    publish_attestor_revoked_event,
+};
+use crate::project_registry::ProjectRegistry;
+use crate::storage_keys::FeatureKey;
+use crate::types::{
+    AdminActionType, AttestationMessage, AttestationPayload, Attestor, ProjectAttestation,
+};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, String, Symbol, Vec};
+
+pub struct AttestorRegistry;
+
+impl AttestorRegistry {
+    /// Admin: register or update the attestor with `public_key`.
+    pub fn register_attestor(
+        env: &Env,
+        admin: Address,
+        public_key: BytesN<32>,
+        name: String,
+        claim_types: Vec<Symbol>,
+    ) -> Result<(), ContractError> {
+        require_admin_auth(env, &admin)?;
+
+        if name.is_empty() || name.len() as usize > MAX_NAME_LEN {
+            return Err(ContractError::InvalidInput);
+        }
+        if claim_types.len() > MAX_ATTESTOR_CLAIM_TYPES {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let mut attestors = Self::list_attestors(env);
+        if !attestors.contains(&public_key) {
+            if attestors.len() >= MAX_ATTESTORS {
+                return Err(ContractError::InvalidInput);
+            }
+            attestors.push_back(public_key.clone());
+            env.storage()
+                .persistent()
+                .set(&FeatureKey::AttestorList, &attestors);
+        }
+
+        let attestor = Attestor {
+            public_key: public_key.clone(),
+            name: name.clone(),
+            claim_types,
+            registered_by: admin.clone(),
+            registered_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&FeatureKey::Attestor(public_key), &attestor);
+
+        publish_attestor_registered_event(env, attestor);
+        AdminActionLog::record_action(
+            env,
+            admin,
+            AdminActionType::AttestorChanged,
+            None,
+            None,
+            Some(name),
+        );
+        Ok(())
+    }
+
+    /// Admin: stop trusting an attestor. Its stored attestations stay on the
+    /// projects but no longer count in `has_attestation`.
+    pub fn revoke_attestor(
+        env: &Env,
+        admin: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        require_admin_auth(env, &admin)?;
+
+        let attestor =
+            Self::get_attestor(env, &public_key).ok_or(ContractError::AttestorNotFound)?;
+        env.storage()
+            .persistent()
+            .remove(&FeatureKey::Attestor(public_key.clone()));
+
+        let mut attestors = Self::list_attestors(env);
+        if let Some(index) = attestors.first_index_of(&public_key) {
+            attestors.remove(index);
+            env.storage()
+                .persistent()
+                .set(&FeatureKey::AttestorList, &attestors);
+        }
+
+        publish_attestor_revoked_event(env, public_key, admin.clone());
+        AdminActionLog::record_action(
+            env,
+            admin,
+            AdminActionType::AttestorChanged,
+            None,
+            None,
+            Some(attestor.name),
+        );
+        Ok(())
+    }
+
+    pub fn get_attestor(env: &Env, public_key: &BytesN<32>) -> Option<Attestor> {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::Attestor(public_key.clone()))
+    }
+
+    pub fn list_attestors(env: &Env) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::AttestorList)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// The bytes an attestor signs for this attestation.
+    pub fn attestation_message(
+        env: &Env,
+        project_id: u64,
+        claim_type: Symbol,
+        payload: AttestationPayload,
+    ) -> Bytes {
+        AttestationMessage {
+            contract: env.current_contract_address(),
+            project_id,
+            claim_type,
+            payload,
+        }
+        .to_xdr(env)
+    }
+
+    /// Store an attestation signed by a registered attestor. Anyone may
+    /// submit it; the signature is the authorization. An invalid signature
+    /// aborts the call. An attestation from the same attestor for the same
+    /// claim type with a higher nonce replaces the stored one.
+    pub fn submit_attestation(
+        env: &Env,
+        project_id: u64,
+        claim_type: Symbol,
+        payload: AttestationPayload,
+        signature: BytesN<64>,
+    ) -> Result<(), ContractError> {
+        ProjectRegistry::get_project(env, project_id).ok_or(ContractError::ProjectNotFound)?;
+        if payload.subject.is_empty() || payload.subject.len() as usize > MAX_CID_LEN {
+            return Err(ContractError::InvalidInput);
+        }
+        let attestor =
+            Self::get_attestor(env, &payload.attestor).ok_or(ContractError::AttestorNotFound)?;
+        if !attestor.claim_types.is_empty() && !attestor.claim_types.contains(&claim_type) {
+            return Err(ContractError::AttestorOutOfScope);
+        }
+        let now = env.ledger().timestamp();
+        if now >= payload.expires_at {
+            return Err(ContractError::AttestationExpired);
+        }
+        let nonce_key = FeatureKey::AttestationNonce(payload.attestor.clone(), payload.nonce);
+        if env.storage().persistent().has(&nonce_key) {
+            return Err(ContractError::AttestationReplayed);
+        }
+
+        let mut attestations = Self::get_project_attestations(env, project_id);
+        let existing = attestations.iter().position(|stored| {
+            stored.payload.attestor == payload.attestor && stored.claim_type == claim_type
+        });
+        match existing.and_then(|index| attestations.get(index as u32)) {
+            Some(stored) if payload.nonce <= stored.payload.nonce => {
+                return Err(ContractError::AttestationReplayed);
+            }
+            None if attestations.len() >= MAX_PROJECT_ATTESTATIONS => {
+                return Err(ContractError::InvalidInput);
+            }
+            _ => {}
+        }
+
+        let message =
+            Self::attestation_message(env, project_id, claim_type.clone(), payload.clone());
+        env.crypto()
+            .ed25519_verify(&payload.attestor, &message, &signature);
+        env.storage().persistent().set(&nonce_key, &true);
+
+        let attestation = ProjectAttestation {
+            claim_type,
+            payload,
+            submitted_at: now,
+        };
+        match existing {
+            Some(index) => attestations.set(index as u32, attestation.clone()),
+            None => attestations.push_back(attestation.clone()),
+        }
+        env.storage()
+            .persistent()
+            .set(&FeatureKey::ProjectAttestations(project_id), &attestations);
+
+        publish_attestation_submitted_event(env, project_id, attestation);
+        Ok(())
+    }
+
+    pub fn get_project_attestations(env: &Env, project_id: u64) -> Vec<ProjectAttestation> {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::ProjectAttestations(project_id))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Whether the project holds an attestation of `claim_type` from an
+    /// attestor that is still registered.
+    pub fn has_attestation(env: &Env, project_id: u64, claim_type: Symbol) -> bool {
+        Self::get_project_attestations(env, project_id)
+            .iter()
+            .any(|attestation| {
+                attestation.claim_type == claim_type
+                    && Self::get_attestor(env, &attestation.payload.attestor).is_some()
+            })
+    }
+}
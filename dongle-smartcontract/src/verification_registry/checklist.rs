@@ -0,0 +1,190 @@
+//! Admin-defined verification checklists.
+//!
+//! Admins define checklists per project category and per verification tier.
+//! A request's checklist is its category's items followed by its tier's
+//! items; an item listed in both is required if either list requires it.
+//! Verifiers tick items on the request's `VerificationRecord`, and the request
+//! cannot be signed off until every required item has been ticked. The
+//! checklist is read at decision time, so changes apply to pending requests.
+
+use crate::admin_action_log::AdminActionLog;
+use crate::auth::require_admin_auth;
+use crate::conflict_registry::ConflictRegistry;
+use crate::constants::{MAX_CHECKLIST_ITEMS, MAX_NAME_LEN};
+use crate::errors::ContractError;
+use crate::events::{publish_checklist_item_attested_event, publish_checklist_set_event};
+use crate::project_registry::ProjectRegistry;
+use crate::storage_keys::{FeatureKey, StorageKey};
+use crate::types::{
+    AdminActionType, ChecklistAttestation, ChecklistItem, ChecklistScope, VerificationRecord,
+    VerificationStatus,
+};
+use crate::verification_registry::storage::VerificationRegistry;
+use crate::verification_registry::tiers::VerificationTierRegistry;
+use crate::verification_registry::validation::VerificationValidation;
+use crate::verification_registry::verifiers::VerifierRegistry;
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+pub struct VerificationChecklistRegistry;
+
+impl VerificationChecklistRegistry {
+    fn scope_key(scope: &ChecklistScope) -> FeatureKey {
+        match scope {
+            ChecklistScope::Category(category) => FeatureKey::CategoryChecklist(category.clone()),
+            ChecklistScope::Tier(tier_id) => FeatureKey::TierChecklist(*tier_id),
+        }
+    }
+
+    /// Admin: replace the checklist for `scope`. An empty list removes it.
+    pub fn set_checklist(
+        env: &Env,
+        admin: Address,
+        scope: ChecklistScope,
+        items: Vec<ChecklistItem>,
+    ) -> Result<(), ContractError> {
+        require_admin_auth(env, &admin)?;
+        if let ChecklistScope::Tier(tier_id) = scope {
+            VerificationTierRegistry::get_tier(env, tier_id)
+                .ok_or(ContractError::VerificationTierNotFound)?;
+        }
+        Self::validate_items(&items)?;
+
+        let key = Self::scope_key(&scope);
+        if items.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &items);
+        }
+
+        publish_checklist_set_event(env, scope, admin.clone(), items.len());
+        AdminActionLog::record_action(
+            env,
+            admin,
+            AdminActionType::VerificationSettingsChanged,
+            None,
+            None,
+            None,
+        );
+        Ok(())
+    }
+
+    fn validate_items(items: &Vec<ChecklistItem>) -> Result<(), ContractError> {
+        if items.len() > MAX_CHECKLIST_ITEMS {
+            return Err(ContractError::InvalidInput);
+        }
+        for (index, item) in items.iter().enumerate() {
+            if item.label.is_empty() || item.label.len() as usize > MAX_NAME_LEN {
+                return Err(ContractError::InvalidInput);
+            }
+            if Self::position(items, &item.key) != Some(index as u32) {
+                return Err(ContractError::InvalidInput);
+            }
+        }
+        Ok(())
+    }
+
+    fn position(items: &Vec<ChecklistItem>, key: &Symbol) -> Option<u32> {
+        items
+            .iter()
+            .position(|item| item.key == *key)
+            .map(|index| index as u32)
+    }
+
+    pub fn get_checklist(env: &Env, scope: ChecklistScope) -> Vec<ChecklistItem> {
+        env.storage()
+            .persistent()
+            .get(&Self::scope_key(&scope))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// The checklist for a request in `category` for `tier_id`.
+    pub fn items_for(env: &Env, category: &String, tier_id: Option<u32>) -> Vec<ChecklistItem> {
+        let mut items = Self::get_checklist(env, ChecklistScope::Category(category.clone()));
+        let Some(tier_id) = tier_id else {
+            return items;
+        };
+        for item in Self::get_checklist(env, ChecklistScope::Tier(tier_id)).iter() {
+            match Self::position(&items, &item.key) {
+                Some(index) => {
+                    if item.required {
+                        let mut existing = items.get_unchecked(index);
+                        existing.required = true;
+                        items.set(index, existing);
+                    }
+                }
+                None => items.push_back(item),
+            }
+        }
+        items
+    }
+
+    /// The checklist for the project's current verification request.
+    pub fn get_project_checklist(env: &Env, project_id: u64) -> Vec<ChecklistItem> {
+        let (Some(project), Some(record)) = (
+            ProjectRegistry::get_project(env, project_id),
+            VerificationRegistry::get_verification(env, project_id),
+        ) else {
+            return Vec::new(env);
+        };
+        Self::items_for(env, &project.category, record.tier_id)
+    }
+
+    /// Admin or in-scope verifier: tick a checklist item on the project's
+    /// pending request. Ticking an item again replaces the earlier tick.
+    /// Verifiers with a conflict of interest in the project cannot tick.
+    pub fn attest_item(
+        env: &Env,
+        project_id: u64,
+        verifier: Address,
+        key: Symbol,
+        note_cid: Option<String>,
+    ) -> Result<(), ContractError> {
+        VerifierRegistry::require_can_decide(env, &verifier, project_id)?;
+        let project =
+            ProjectRegistry::get_project(env, project_id).ok_or(ContractError::ProjectNotFound)?;
+        let mut record = VerificationRegistry::get_verification(env, project_id)
+            .ok_or(ContractError::VerificationNotFound)?;
+        if record.status != VerificationStatus::Pending {
+            return Err(ContractError::InvalidStatus);
+        }
+        ConflictRegistry::require_no_conflict(env, project_id, &verifier)?;
+        if let Some(cid) = &note_cid {
+            VerificationValidation::validate_evidence_cid(cid)?;
+        }
+        let items = Self::items_for(env, &project.category, record.tier_id);
+        if Self::position(&items, &key).is_none() {
+            return Err(ContractError::ChecklistItemNotFound);
+        }
+
+        let attestation = ChecklistAttestation {
+            key: key.clone(),
+            verifier,
+            note_cid,
+            attested_at: env.ledger().timestamp(),
+        };
+        match record.checklist.iter().position(|tick| tick.key == key) {
+            Some(index) => record.checklist.set(index as u32, attestation.clone()),
+            None => record.checklist.push_back(attestation.clone()),
+        }
+        env.storage()
+            .persistent()
+            .set(&StorageKey::VerificationRecord(record.request_id), &record);
+
+        publish_checklist_item_attested_event(env, project_id, record.request_id, attestation);
+        Ok(())
+    }
+
+    /// Check that every required item of the request's checklist is ticked.
+    pub(crate) fn require_complete(
+        env: &Env,
+        category: &String,
+        record: &VerificationRecord,
+    ) -> Result<(), ContractError> {
+        for item in Self::items_for(env, category, record.tier_id).iter() {
+            if item.required && !record.checklist.iter().any(|tick| tick.key == item.key) {
+                return Err(ContractError::ChecklistIncomplete);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+//! On-chain commitments to verification evidence.
+//!
+//! The requester commits the SHA-256 of the canonical evidence document and a
+//! summary of its schema fields for the request's evidence CID. Verifiers can
+//! then check the document they fetched against the hash, so content swapped
+//! at the gateway after approval is detectable. Committing is optional, so
+//! requests made before commitments existed can still be decided; committing
+//! clears the sign-offs and checklist ticks given so far. Commitments are
+//! keyed by request ID and kept after the request is decided.
+
+use crate::auth::require_owner_auth;
+use crate::constants::{MAX_EVIDENCE_ATTESTATIONS, MAX_EVIDENCE_PROOFS, MAX_EVIDENCE_SIGNATURES};
+use crate::errors::ContractError;
+use crate::events::publish_evidence_committed_event;
+use crate::project_registry::ProjectRegistry;
+use crate::storage_keys::{FeatureKey, StorageKey};
+use crate::types::{EvidenceCommitment, EvidenceSummary, VerificationStatus};
+use crate::verification_registry::storage::VerificationRegistry;
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
+
+pub struct EvidenceRegistry;
+
+impl EvidenceRegistry {
+    /// Owner: commit the evidence hash and summary for the pending request.
+    /// `evidence_cid` must be the request's current evidence CID. Committing
+    /// again replaces the previous commitment while the request is pending;
+    /// sign-offs and checklist ticks are cleared so verifiers review the
+    /// committed evidence.
+    pub fn commit_evidence(
+        env: &Env,
+        project_id: u64,
+        requester: Address,
+        evidence_cid: String,
+        evidence_sha256: BytesN<32>,
+        summary: EvidenceSummary,
+    ) -> Result<(), ContractError> {
+        let project =
+            ProjectRegistry::get_project(env, project_id).ok_or(ContractError::ProjectNotFound)?;
+        require_owner_auth(&requester, &project.owner)?;
+
+        let mut record = VerificationRegistry::get_verification(env, project_id)
+            .ok_or(ContractError::VerificationNotFound)?;
+        if record.status != VerificationStatus::Pending {
+            return Err(ContractError::InvalidStatus);
+        }
+        if record.evidence_cid != evidence_cid {
+            return Err(ContractError::EvidenceCidMismatch);
+        }
+        Self::validate_summary(&summary)?;
+
+        if !record.approvals.is_empty() || !record.checklist.is_empty() {
+            record.approvals = Vec::new(env);
+            record.checklist = Vec::new(env);
+            env.storage()
+                .persistent()
+                .set(&StorageKey::VerificationRecord(record.request_id), &record);
+        }
+
+        let commitment = EvidenceCommitment {
+            request_id: record.request_id,
+            evidence_cid,
+            evidence_sha256,
+            summary,
+            committed_by: requester,
+            committed_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(
+            &FeatureKey::EvidenceCommitment(record.request_id),
+            &commitment,
+        );
+
+        publish_evidence_committed_event(env, project_id, &commitment);
+        Ok(())
+    }
+
+    /// Check declared summary fields against the evidence schema's limits.
+    pub fn validate_summary(summary: &EvidenceSummary) -> Result<(), ContractError> {
+        if summary.proof_count == 0
+            || summary.proof_count > MAX_EVIDENCE_PROOFS
+            || summary.attestation_count > MAX_EVIDENCE_ATTESTATIONS
+            || summary.signature_count > MAX_EVIDENCE_SIGNATURES
+        {
+            return Err(ContractError::InvalidInput);
+        }
+
+        // Every proof has a type, so there are between one and `proof_count`
+        // distinct types.
+        let types = &summary.proof_types;
+        if types.is_empty() || types.len() > summary.proof_count {
+            return Err(ContractError::InvalidInput);
+        }
+        for (index, proof_type) in types.iter().enumerate() {
+            if types.first_index_of(proof_type) != Some(index as u32) {
+                return Err(ContractError::InvalidInput);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_commitment(env: &Env, request_id: u64) -> Option<EvidenceCommitment> {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::EvidenceCommitment(request_id))
+    }
+
+    /// Drop the commitment when the request's evidence CID changes.
+    pub(crate) fn clear_commitment(env: &Env, request_id: u64) {
+        env.storage()
+            .persistent()
+            .remove(&FeatureKey::EvidenceCommitment(request_id));
+    }
+
+    /// Whether `evidence_sha256` matches the commitment for the project's
+    /// current verification request. False when nothing was committed.
+    pub fn verify_evidence_hash(env: &Env, project_id: u64, evidence_sha256: BytesN<32>) -> bool {
+        VerificationRegistry::get_verification(env, project_id)
+            .and_then(|record| Self::get_commitment(env, record.request_id))
+            .map(|commitment| commitment.evidence_sha256 == evidence_sha256)
+            .unwrap_or(false)
+    }
+}
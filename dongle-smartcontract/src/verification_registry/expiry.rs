@@ -0,0 +1,303 @@
+//! Time-bucketed index of verification expiry times.
+//!
+//! Verified projects are indexed under their `expires_at` in day-wide
+//! buckets, each kept in (expiry time, project ID) order, so projects nearing
+//! expiry can be listed without scanning the registry. Listings resume from
+//! an `ExpiryCursor` naming the next entry, so they start at its bucket and
+//! are not shifted by entries added or removed between calls. The index is
+//! updated when a verification is approved, renewed, revoked or swept, and
+//! `sweep_expired_verifications` indexes any verification it passes that is
+//! missing. Entries whose verification no longer matches are skipped when
+//! listing and removed by the reminder pass, which drops buckets left empty.
+//! Renewal reminders are emitted once per project and expiry time.
+
+use crate::admin_action_log::AdminActionLog;
+use crate::auth::require_admin_auth;
+use crate::constants::{DEFAULT_EXPIRY_REMINDER_WINDOW, EXPIRY_BUCKET_SECONDS, MAX_PAGE_LIMIT};
+use crate::errors::ContractError;
+use crate::events::publish_verification_expiring_soon_event;
+use crate::project_registry::ProjectRegistry;
+use crate::storage_keys::FeatureKey;
+use crate::types::{
+    AdminActionType, ExpiringVerification, ExpiringVerificationPage, ExpiryCursor,
+    VerificationStatus,
+};
+use crate::verification_registry::storage::VerificationRegistry;
+use soroban_sdk::{Address, Env, Vec};
+
+pub struct VerificationExpiryIndex;
+
+impl VerificationExpiryIndex {
+    fn get_buckets(env: &Env) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::VerificationExpiryBuckets)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    fn get_bucket(env: &Env, bucket: u64) -> Vec<ExpiringVerification> {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::VerificationExpiryBucket(bucket))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    fn set_bucket(env: &Env, bucket: u64, entries: &Vec<ExpiringVerification>) {
+        let mut buckets = Self::get_buckets(env);
+        let position = buckets.binary_search(bucket);
+        if entries.is_empty() {
+            env.storage()
+                .persistent()
+                .remove(&FeatureKey::VerificationExpiryBucket(bucket));
+            if let Ok(index) = position {
+                buckets.remove(index);
+                Self::set_buckets(env, &buckets);
+            }
+            return;
+        }
+        env.storage()
+            .persistent()
+            .set(&FeatureKey::VerificationExpiryBucket(bucket), entries);
+        if let Err(index) = position {
+            buckets.insert(index, bucket);
+            Self::set_buckets(env, &buckets);
+        }
+    }
+
+    fn set_buckets(env: &Env, buckets: &Vec<u64>) {
+        if buckets.is_empty() {
+            env.storage()
+                .persistent()
+                .remove(&FeatureKey::VerificationExpiryBuckets);
+        } else {
+            env.storage()
+                .persistent()
+                .set(&FeatureKey::VerificationExpiryBuckets, buckets);
+        }
+    }
+
+    /// Index the project under `expires_at`, replacing any earlier entry. An
+    /// `expires_at` of 0 only removes the entry.
+    pub(crate) fn index(env: &Env, project_id: u64, expires_at: u64) {
+        let indexed: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&FeatureKey::VerificationExpiryIndexed(project_id));
+        if indexed == Some(expires_at) {
+            return;
+        }
+        Self::unindex(env, project_id);
+        if expires_at == 0 {
+            return;
+        }
+
+        let bucket = expires_at / EXPIRY_BUCKET_SECONDS;
+        let mut entries = Self::get_bucket(env, bucket);
+        let index = entries
+            .iter()
+            .position(|entry| (entry.expires_at, entry.project_id) > (expires_at, project_id))
+            .map(|index| index as u32)
+            .unwrap_or(entries.len());
+        entries.insert(
+            index,
+            ExpiringVerification {
+                project_id,
+                expires_at,
+            },
+        );
+        Self::set_bucket(env, bucket, &entries);
+        env.storage().persistent().set(
+            &FeatureKey::VerificationExpiryIndexed(project_id),
+            &expires_at,
+        );
+    }
+
+    /// Remove the project from the index. Does nothing if it is not indexed.
+    pub(crate) fn unindex(env: &Env, project_id: u64) {
+        let key = FeatureKey::VerificationExpiryIndexed(project_id);
+        let Some(expires_at) = env.storage().persistent().get::<_, u64>(&key) else {
+            return;
+        };
+        env.storage().persistent().remove(&key);
+
+        Self::remove_entry(
+            env,
+            &ExpiringVerification {
+                project_id,
+                expires_at,
+            },
+        );
+    }
+
+    fn remove_entry(env: &Env, entry: &ExpiringVerification) {
+        let bucket = entry.expires_at / EXPIRY_BUCKET_SECONDS;
+        let mut entries = Self::get_bucket(env, bucket);
+        if let Some(index) = entries.first_index_of(entry) {
+            entries.remove(index);
+            Self::set_bucket(env, bucket, &entries);
+        }
+    }
+
+    /// Remove a stale entry, and the project's indexed marker if it still
+    /// points at this entry.
+    fn prune_entry(env: &Env, entry: &ExpiringVerification) {
+        let key = FeatureKey::VerificationExpiryIndexed(entry.project_id);
+        if env.storage().persistent().get::<_, u64>(&key) == Some(entry.expires_at) {
+            env.storage().persistent().remove(&key);
+        }
+        Self::remove_entry(env, entry);
+    }
+
+    /// Whether the project's current verification still expires at the
+    /// indexed time.
+    fn is_live(env: &Env, entry: &ExpiringVerification) -> bool {
+        VerificationRegistry::get_verification(env, entry.project_id)
+            .map(|record| {
+                matches!(
+                    record.status,
+                    VerificationStatus::Verified | VerificationStatus::Suspended
+                ) && record.expires_at == entry.expires_at
+            })
+            .unwrap_or(false)
+    }
+
+    /// Verifications expiring at or before `before_ts`, soonest first,
+    /// including lapsed ones not yet swept, from `cursor` (the start of the
+    /// index when default), as returned in `next_cursor`.
+    pub fn list_expiring(
+        env: &Env,
+        before_ts: u64,
+        cursor: ExpiryCursor,
+        limit: u32,
+    ) -> ExpiringVerificationPage {
+        Self::scan(env, before_ts, cursor, limit, false)
+    }
+
+    /// List like `list_expiring`, removing entries whose verification no
+    /// longer matches when `prune` is set.
+    fn scan(
+        env: &Env,
+        before_ts: u64,
+        cursor: ExpiryCursor,
+        limit: u32,
+        prune: bool,
+    ) -> ExpiringVerificationPage {
+        let limit = if limit == 0 || limit > MAX_PAGE_LIMIT {
+            MAX_PAGE_LIMIT
+        } else {
+            limit
+        };
+        let start = (cursor.expires_at, cursor.project_id);
+        let buckets = Self::get_buckets(env);
+        let (Ok(first) | Err(first)) = buckets.binary_search(start.0 / EXPIRY_BUCKET_SECONDS);
+
+        let mut entries = Vec::new(env);
+        for bucket in buckets.slice(first..).iter() {
+            if bucket.saturating_mul(EXPIRY_BUCKET_SECONDS) > before_ts {
+                break;
+            }
+            for entry in Self::get_bucket(env, bucket).iter() {
+                if entry.expires_at > before_ts {
+                    break;
+                }
+                if (entry.expires_at, entry.project_id) < start {
+                    continue;
+                }
+                if entries.len() >= limit {
+                    return ExpiringVerificationPage {
+                        entries,
+                        has_more: true,
+                        next_cursor: ExpiryCursor {
+                            expires_at: entry.expires_at,
+                            project_id: entry.project_id,
+                        },
+                    };
+                }
+                if Self::is_live(env, &entry) {
+                    entries.push_back(entry);
+                } else if prune {
+                    Self::prune_entry(env, &entry);
+                }
+            }
+        }
+        ExpiringVerificationPage {
+            entries,
+            has_more: false,
+            next_cursor: ExpiryCursor::default(),
+        }
+    }
+
+    /// Emit a renewal reminder for each indexed verification that expires
+    /// within the reminder window and has not been reminded about for its
+    /// current expiry time, among up to `limit` verifications from `cursor`.
+    /// Lapsed verifications are left to the expiry sweep, so the pass starts
+    /// no earlier than the current time, and it removes entries whose
+    /// verification no longer matches. Returns the project IDs reminded by
+    /// this call.
+    pub fn notify_expiring(env: &Env, cursor: ExpiryCursor, limit: u32) -> Vec<u64> {
+        let mut notified = Vec::new(env);
+        let window = Self::get_reminder_window(env);
+        if window == 0 {
+            return notified;
+        }
+
+        let now = env.ledger().timestamp();
+        let cursor = if cursor.expires_at > now {
+            cursor
+        } else {
+            ExpiryCursor {
+                expires_at: now.saturating_add(1),
+                project_id: 0,
+            }
+        };
+        let page = Self::scan(env, now.saturating_add(window), cursor, limit, true);
+        for entry in page.entries.iter() {
+            let marker = FeatureKey::ExpiryReminderSent(entry.project_id);
+            if env.storage().persistent().get::<_, u64>(&marker) == Some(entry.expires_at) {
+                continue;
+            }
+            let Some(project) = ProjectRegistry::get_project(env, entry.project_id) else {
+                continue;
+            };
+            env.storage().persistent().set(&marker, &entry.expires_at);
+            publish_verification_expiring_soon_event(
+                env,
+                entry.project_id,
+                project.owner,
+                entry.expires_at,
+                window,
+            );
+            notified.push_back(entry.project_id);
+        }
+        notified
+    }
+
+    pub fn get_reminder_window(env: &Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::ExpiryReminderWindow)
+            .unwrap_or(DEFAULT_EXPIRY_REMINDER_WINDOW)
+    }
+
+    /// Admin: set how many seconds before expiry renewal reminders start
+    /// (0 disables reminders).
+    pub fn set_reminder_window(
+        env: &Env,
+        admin: Address,
+        window_seconds: u64,
+    ) -> Result<(), ContractError> {
+        require_admin_auth(env, &admin)?;
+        env.storage()
+            .persistent()
+            .set(&FeatureKey::ExpiryReminderWindow, &window_seconds);
+        AdminActionLog::record_action(
+            env,
+            admin,
+            AdminActionType::VerificationSettingsChanged,
+            None,
+            None,
+            None,
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,322 @@
+//! Verification and renewal history: paginated listing, retention and
+//! pruned-record summaries.
+//!
+//! An admin-configured retention policy is applied to a project's
+//! verification history on each new verification request and to its renewal
+//! history on each new renewal request, pruning a bounded number of the
+//! oldest records per request. Every pruned record, whether removed by the
+//! policy or by `clear_verification_history` / `clear_renewal_history`,
+//! extends the history's `HistorySummary` hash chain so the full history can
+//! still be proven against archived records.
+
+use crate::admin_action_log::AdminActionLog;
+use crate::auth::require_admin_auth;
+use crate::constants::{MAX_HISTORY_PRUNE_PER_REQUEST, MAX_PAGE_LIMIT};
+use crate::errors::ContractError;
+use crate::events::{
+    publish_renewal_history_pruned_event, publish_verification_history_pruned_event,
+};
+use crate::storage_keys::{FeatureKey, StorageKey};
+use crate::storage_migration;
+use crate::types::{
+    AdminActionType, HistoryRetentionPolicy, HistorySummary, RenewalHistoryPage,
+    VerificationHistoryPage, VerificationRecord, VerificationRenewalRecord,
+};
+use crate::verification_registry::storage::VerificationRegistry;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec};
+
+pub struct VerificationHistoryRegistry;
+
+impl VerificationHistoryRegistry {
+    pub fn get_retention_policy(env: &Env) -> HistoryRetentionPolicy {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::HistoryRetentionPolicy)
+            .unwrap_or(HistoryRetentionPolicy {
+                max_records: 0,
+                max_age_seconds: 0,
+            })
+    }
+
+    /// Admin: set the retention policy applied on each new request.
+    pub fn set_retention_policy(
+        env: &Env,
+        admin: Address,
+        policy: HistoryRetentionPolicy,
+    ) -> Result<(), ContractError> {
+        require_admin_auth(env, &admin)?;
+        env.storage()
+            .persistent()
+            .set(&FeatureKey::HistoryRetentionPolicy, &policy);
+        AdminActionLog::record_action(
+            env,
+            admin,
+            AdminActionType::VerificationSettingsChanged,
+            None,
+            None,
+            None,
+        );
+        Ok(())
+    }
+
+    pub fn get_verification_summary(env: &Env, project_id: u64) -> Option<HistorySummary> {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::VerificationHistorySummary(project_id))
+    }
+
+    pub fn get_renewal_summary(env: &Env, project_id: u64) -> Option<HistorySummary> {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::RenewalHistorySummary(project_id))
+    }
+
+    fn load_summary(env: &Env, key: &FeatureKey) -> HistorySummary {
+        env.storage()
+            .persistent()
+            .get(key)
+            .unwrap_or(HistorySummary {
+                pruned_count: 0,
+                last_pruned_at: 0,
+                chain_hash: BytesN::from_array(env, &[0; 32]),
+            })
+    }
+
+    fn fold(env: &Env, summary: &mut HistorySummary, record: Bytes) {
+        let mut input = Bytes::from_array(env, &summary.chain_hash.to_array());
+        input.append(&record);
+        summary.chain_hash = env.crypto().sha256(&input).into();
+        summary.pruned_count = summary.pruned_count.saturating_add(1);
+        summary.last_pruned_at = env.ledger().timestamp();
+    }
+
+    fn page_limit(limit: u32) -> u32 {
+        if limit == 0 || limit > MAX_PAGE_LIMIT {
+            MAX_PAGE_LIMIT
+        } else {
+            limit
+        }
+    }
+
+    fn verification_ids(env: &Env, project_id: u64) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::ProjectVerificationHistory(project_id))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    fn renewal_bounds(env: &Env, project_id: u64) -> (u32, u32) {
+        let start: u32 = env
+            .storage()
+            .persistent()
+            .get(&FeatureKey::RenewalHistoryStart(project_id))
+            .unwrap_or(0);
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::VerificationRenewalCount(project_id))
+            .unwrap_or(0);
+        (start, count)
+    }
+
+    fn get_renewal_record(
+        env: &Env,
+        project_id: u64,
+        index: u32,
+    ) -> Option<VerificationRenewalRecord> {
+        storage_migration::get_renewal_record(
+            env,
+            &StorageKey::VerificationRenewalHistory(project_id, index),
+        )
+    }
+
+    /// Retained verification records with request ID `>= cursor`, in request
+    /// order.
+    pub fn list_verification_history(
+        env: &Env,
+        project_id: u64,
+        cursor: u64,
+        limit: u32,
+    ) -> VerificationHistoryPage {
+        let limit = Self::page_limit(limit);
+        let mut records = Vec::new(env);
+        for request_id in Self::verification_ids(env, project_id).iter() {
+            if request_id < cursor {
+                continue;
+            }
+            if records.len() >= limit {
+                return VerificationHistoryPage {
+                    records,
+                    next_cursor: Some(request_id),
+                };
+            }
+            if let Some(record) = VerificationRegistry::get_verification_record(env, request_id) {
+                records.push_back(record);
+            }
+        }
+        VerificationHistoryPage {
+            records,
+            next_cursor: None,
+        }
+    }
+
+    /// Retained renewal records from history index `cursor`, in approval
+    /// order.
+    pub fn list_renewal_history(
+        env: &Env,
+        project_id: u64,
+        cursor: u32,
+        limit: u32,
+    ) -> RenewalHistoryPage {
+        let (start, count) = Self::renewal_bounds(env, project_id);
+        let first = cursor.max(start);
+        let end = first.saturating_add(Self::page_limit(limit)).min(count);
+        let mut records = Vec::new(env);
+        for index in first..end {
+            if let Some(record) = Self::get_renewal_record(env, project_id, index) {
+                records.push_back(record);
+            }
+        }
+        RenewalHistoryPage {
+            records,
+            next_cursor: if end < count { Some(end) } else { None },
+        }
+    }
+
+    /// Whether a record requested at `timestamp` falls outside the policy
+    /// while `remaining` records, itself included, are retained.
+    fn is_expendable(
+        policy: &HistoryRetentionPolicy,
+        now: u64,
+        remaining: u32,
+        timestamp: u64,
+    ) -> bool {
+        (policy.max_records > 0 && remaining > policy.max_records)
+            || (policy.max_age_seconds > 0
+                && now.saturating_sub(timestamp) > policy.max_age_seconds)
+    }
+
+    /// Remove the oldest `remove_count` verification records, folding each
+    /// into the summary. Returns the updated summary.
+    pub(crate) fn prune_verification_records(
+        env: &Env,
+        project_id: u64,
+        remove_count: u32,
+    ) -> HistorySummary {
+        let key = FeatureKey::VerificationHistorySummary(project_id);
+        let mut summary = Self::load_summary(env, &key);
+        let history = Self::verification_ids(env, project_id);
+        let remove_count = remove_count.min(history.len());
+        if remove_count == 0 {
+            return summary;
+        }
+
+        for request_id in history.slice(0..remove_count).iter() {
+            let record_key = StorageKey::VerificationRecord(request_id);
+            if let Some(record) = storage_migration::get_verification_record(env, &record_key) {
+                Self::fold(env, &mut summary, record.to_xdr(env));
+                env.storage().persistent().remove(&record_key);
+            }
+        }
+
+        let retained = history.slice(remove_count..history.len());
+        let history_key = StorageKey::ProjectVerificationHistory(project_id);
+        if retained.is_empty() {
+            env.storage().persistent().remove(&history_key);
+        } else {
+            env.storage().persistent().set(&history_key, &retained);
+        }
+        env.storage().persistent().set(&key, &summary);
+        summary
+    }
+
+    /// Remove the retained renewal records with history index below `end`,
+    /// folding each into the summary. Returns the updated summary.
+    pub(crate) fn prune_renewal_records(env: &Env, project_id: u64, end: u32) -> HistorySummary {
+        let key = FeatureKey::RenewalHistorySummary(project_id);
+        let mut summary = Self::load_summary(env, &key);
+        let (start, count) = Self::renewal_bounds(env, project_id);
+        let end = end.min(count);
+        if end <= start {
+            return summary;
+        }
+
+        for index in start..end {
+            if let Some(record) = Self::get_renewal_record(env, project_id, index) {
+                Self::fold(env, &mut summary, record.to_xdr(env));
+                env.storage()
+                    .persistent()
+                    .remove(&StorageKey::VerificationRenewalHistory(project_id, index));
+            }
+        }
+        env.storage()
+            .persistent()
+            .set(&FeatureKey::RenewalHistoryStart(project_id), &end);
+        env.storage().persistent().set(&key, &summary);
+        summary
+    }
+
+    /// Apply the retention policy to the project's verification history,
+    /// always keeping the newest record.
+    pub(crate) fn apply_to_verifications(env: &Env, project_id: u64) {
+        let policy = Self::get_retention_policy(env);
+        if policy.max_records == 0 && policy.max_age_seconds == 0 {
+            return;
+        }
+        let history = Self::verification_ids(env, project_id);
+        let total = history.len();
+        let max_remove = total.saturating_sub(1).min(MAX_HISTORY_PRUNE_PER_REQUEST);
+        let now = env.ledger().timestamp();
+
+        let mut remove_count = 0;
+        while remove_count < max_remove {
+            let requested_at = history
+                .get(remove_count)
+                .and_then(|request_id| {
+                    VerificationRegistry::get_verification_record(env, request_id)
+                })
+                .map(|record| record.requested_at)
+                .unwrap_or(0);
+            if !Self::is_expendable(&policy, now, total - remove_count, requested_at) {
+                break;
+            }
+            remove_count += 1;
+        }
+
+        if remove_count > 0 {
+            let summary = Self::prune_verification_records(env, project_id, remove_count);
+            publish_verification_history_pruned_event(env, project_id, remove_count, summary);
+        }
+    }
+
+    /// Apply the retention policy to the project's renewal history, always
+    /// keeping the newest record.
+    pub(crate) fn apply_to_renewals(env: &Env, project_id: u64) {
+        let policy = Self::get_retention_policy(env);
+        if policy.max_records == 0 && policy.max_age_seconds == 0 {
+            return;
+        }
+        let (start, count) = Self::renewal_bounds(env, project_id);
+        let max_end = start
+            .saturating_add(MAX_HISTORY_PRUNE_PER_REQUEST)
+            .min(count.saturating_sub(1));
+        let now = env.ledger().timestamp();
+
+        let mut end = start;
+        while end < max_end {
+            let timestamp = Self::get_renewal_record(env, project_id, end)
+                .map(|record| record.timestamp)
+                .unwrap_or(0);
+            if !Self::is_expendable(&policy, now, count - end, timestamp) {
+                break;
+            }
+            end += 1;
+        }
+
+        if end > start {
+            let summary = Self::prune_renewal_records(env, project_id, end);
+            publish_renewal_history_pruned_event(env, project_id, end - start, summary);
+        }
+    }
+}
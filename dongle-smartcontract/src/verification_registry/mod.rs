@@ -1,9 +1,25 @@
 //! Verification requests with ownership and fee checks, events, and state machine.
 
+mod attestors;
+mod checklist;
+mod evidence;
+mod expiry;
+mod history;
+mod queue;
 mod state_machine;
 mod storage;
+mod tiers;
 mod validation;
+mod verifiers;
 
+pub use attestors::AttestorRegistry;
+pub use checklist::VerificationChecklistRegistry;
+pub use evidence::EvidenceRegistry;
+pub use expiry::VerificationExpiryIndex;
+pub use history::VerificationHistoryRegistry;
+pub use queue::VerificationQueue;
 pub use state_machine::VerificationStateMachine;
 pub use storage::VerificationRegistry;
+pub use tiers::VerificationTierRegistry;
 pub use validation::VerificationValidation;
+pub use verifiers::VerifierRegistry;
@@ -0,0 +1,391 @@
+//! Pending verification queue.
+//!
+//! Pending request IDs are kept in submission order, which is also
+//! `requested_at` order. The queue backs paginated listing, queue positions,
+//! automatic assignment to delegated verifiers and SLA tracking. Requests
+//! leave the queue when they are approved, rejected or reset.
+//!
+//! Each request gets the next sequence number and is stored in pages of
+//! `VERIFICATION_QUEUE_PAGE_SIZE` IDs, with its sequence number kept
+//! alongside. Leaving the queue clears the request's slot and moves the head
+//! past leading cleared slots, so joining and leaving touch a fixed number of
+//! entries however long the queue is.
+
+use crate::admin_action_log::AdminActionLog;
+use crate::auth::require_admin_auth;
+use crate::conflict_registry::ConflictRegistry;
+use crate::constants::{MAX_PAGE_LIMIT, VERIFICATION_QUEUE_PAGE_SIZE};
+use crate::errors::ContractError;
+use crate::events::{
+    publish_verification_assigned_event, publish_verification_queue_configured_event,
+    publish_verification_stale_event,
+};
+use crate::project_registry::ProjectRegistry;
+use crate::storage_keys::{FeatureKey, StorageKey, VerificationKey};
+use crate::types::{
+    AdminActionType, PendingVerification, VerificationAssignmentMode, VerificationRecord,
+    VerificationStatus,
+};
+use crate::verification_registry::storage::VerificationRegistry;
+use crate::verification_registry::verifiers::VerifierRegistry;
+use soroban_sdk::{Address, Env, Vec};
+
+pub struct VerificationQueue;
+
+impl VerificationQueue {
+    fn get_seq(env: &Env, key: &VerificationKey) -> u32 {
+        env.storage().persistent().get(key).unwrap_or(0)
+    }
+
+    fn load_page(env: &Env, page_no: u32) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&VerificationKey::QueuePage(page_no))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    fn store_page(env: &Env, page_no: u32, page: &Vec<u64>) {
+        let key = VerificationKey::QueuePage(page_no);
+        // A full page whose requests have all left is read as empty.
+        if page.len() == VERIFICATION_QUEUE_PAGE_SIZE && page.iter().all(|id| id == 0) {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, page);
+        }
+    }
+
+    fn push(env: &Env, request_id: u64) {
+        let tail = Self::get_seq(env, &VerificationKey::QueueTail);
+        let page_no = tail / VERIFICATION_QUEUE_PAGE_SIZE;
+        let mut page = Self::load_page(env, page_no);
+        page.push_back(request_id);
+        Self::store_page(env, page_no, &page);
+        env.storage()
+            .persistent()
+            .set(&VerificationKey::QueuePos(request_id), &tail);
+        env.storage()
+            .persistent()
+            .set(&VerificationKey::QueueTail, &(tail + 1));
+    }
+
+    /// Clear a request's slot. Returns `false` if the request is not queued.
+    fn remove(env: &Env, request_id: u64) -> bool {
+        let pos_key = VerificationKey::QueuePos(request_id);
+        let Some(seq) = env.storage().persistent().get::<_, u32>(&pos_key) else {
+            return false;
+        };
+        env.storage().persistent().remove(&pos_key);
+        let mut page_no = seq / VERIFICATION_QUEUE_PAGE_SIZE;
+        let mut page = Self::load_page(env, page_no);
+        page.set(seq % VERIFICATION_QUEUE_PAGE_SIZE, 0);
+        Self::store_page(env, page_no, &page);
+
+        let mut head = Self::get_seq(env, &VerificationKey::QueueHead);
+        if seq == head {
+            let tail = Self::get_seq(env, &VerificationKey::QueueTail);
+            while head < tail {
+                if head / VERIFICATION_QUEUE_PAGE_SIZE != page_no {
+                    page_no = head / VERIFICATION_QUEUE_PAGE_SIZE;
+                    page = Self::load_page(env, page_no);
+                }
+                if page.get(head % VERIFICATION_QUEUE_PAGE_SIZE).unwrap_or(0) != 0 {
+                    break;
+                }
+                head += 1;
+            }
+            env.storage()
+                .persistent()
+                .set(&VerificationKey::QueueHead, &head);
+        }
+        true
+    }
+
+    /// Call `visit` with each queued request ID, oldest first, until it
+    /// returns `false`.
+    fn scan(env: &Env, mut visit: impl FnMut(u64) -> bool) {
+        let tail = Self::get_seq(env, &VerificationKey::QueueTail);
+        let mut seq = Self::get_seq(env, &VerificationKey::QueueHead);
+        while seq < tail {
+            let page_no = seq / VERIFICATION_QUEUE_PAGE_SIZE;
+            let page = Self::load_page(env, page_no);
+            let page_end = ((page_no + 1) * VERIFICATION_QUEUE_PAGE_SIZE).min(tail);
+            for slot in seq..page_end {
+                let request_id = page.get(slot % VERIFICATION_QUEUE_PAGE_SIZE).unwrap_or(0);
+                if request_id != 0 && !visit(request_id) {
+                    return;
+                }
+            }
+            seq = page_end;
+        }
+    }
+
+    /// The queued request if it is still pending.
+    fn pending_record(env: &Env, request_id: u64) -> Option<VerificationRecord> {
+        VerificationRegistry::get_verification_record(env, request_id)
+            .filter(|record| record.status == VerificationStatus::Pending)
+    }
+
+    fn to_pending(env: &Env, record: VerificationRecord, sla: u64) -> PendingVerification {
+        let age_seconds = env.ledger().timestamp().saturating_sub(record.requested_at);
+        PendingVerification {
+            request_id: record.request_id,
+            project_id: record.project_id,
+            requester: record.requester,
+            requested_at: record.requested_at,
+            age_seconds,
+            assigned_admin: record.assigned_admin,
+            tier_id: record.tier_id,
+            sla_breached: sla > 0 && age_seconds > sla,
+        }
+    }
+
+    /// Append a new request to the queue and, unless assignment is manual,
+    /// assign it to an eligible verifier without a conflict of interest.
+    pub(crate) fn enqueue(env: &Env, mut record: VerificationRecord) {
+        Self::push(env, record.request_id);
+
+        if let Some(assignee) = Self::pick_assignee(env, &record) {
+            Self::adjust_load(env, &assignee, true);
+            record.assigned_admin = Some(assignee.clone());
+            env.storage()
+                .persistent()
+                .set(&StorageKey::VerificationRecord(record.request_id), &record);
+            publish_verification_assigned_event(
+                env,
+                record.project_id,
+                record.request_id,
+                assignee,
+                env.current_contract_address(),
+            );
+        }
+    }
+
+    /// Remove a request from the queue once it is no longer pending. Does
+    /// nothing if the request is not queued.
+    pub(crate) fn dequeue(env: &Env, record: &VerificationRecord) {
+        if Self::remove(env, record.request_id) {
+            if let Some(assignee) = &record.assigned_admin {
+                Self::adjust_load(env, assignee, false);
+            }
+        }
+    }
+
+    /// Move a queued request's assignment from `previous` to `assignee`.
+    pub(crate) fn reassign(env: &Env, previous: &Option<Address>, assignee: &Address) {
+        if let Some(previous) = previous {
+            Self::adjust_load(env, previous, false);
+        }
+        Self::adjust_load(env, assignee, true);
+    }
+
+    fn adjust_load(env: &Env, assignee: &Address, increase: bool) {
+        let key = FeatureKey::VerifierLoad(assignee.clone());
+        let load = Self::get_load(env, assignee);
+        let load = if increase {
+            load.saturating_add(1)
+        } else {
+            load.saturating_sub(1)
+        };
+        if load == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &load);
+        }
+    }
+
+    /// Number of pending requests assigned to `assignee`.
+    pub fn get_load(env: &Env, assignee: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::VerifierLoad(assignee.clone()))
+            .unwrap_or(0)
+    }
+
+    fn pick_assignee(env: &Env, record: &VerificationRecord) -> Option<Address> {
+        let mode = Self::get_assignment_mode(env);
+        if mode == VerificationAssignmentMode::Manual {
+            return None;
+        }
+        let project = ProjectRegistry::get_project(env, record.project_id)?;
+        let verifiers = VerifierRegistry::list_verifiers(env);
+        let count = verifiers.len();
+        if count == 0 {
+            return None;
+        }
+
+        let cursor: u32 = env
+            .storage()
+            .persistent()
+            .get(&FeatureKey::VerificationAssignmentCursor)
+            .unwrap_or(0);
+        let mut chosen: Option<(u32, Address, u32)> = None;
+        for offset in 0..count {
+            let index = (cursor + offset) % count;
+            let Some(verifier) = verifiers.get(index) else {
+                continue;
+            };
+            let eligible = VerifierRegistry::active_grant(env, &verifier)
+                .map(|grant| VerifierRegistry::covers(&grant, &project.category, record.tier_id))
+                .unwrap_or(false);
+            if !eligible || ConflictRegistry::has_conflict(env, record.project_id, &verifier) {
+                continue;
+            }
+            if mode == VerificationAssignmentMode::RoundRobin {
+                chosen = Some((index, verifier, 0));
+                break;
+            }
+            let load = Self::get_load(env, &verifier);
+            if chosen.as_ref().map_or(true, |(_, _, best)| load < *best) {
+                chosen = Some((index, verifier, load));
+            }
+        }
+
+        let (index, verifier, _) = chosen?;
+        env.storage().persistent().set(
+            &FeatureKey::VerificationAssignmentCursor,
+            &((index + 1) % count),
+        );
+        Some(verifier)
+    }
+
+    /// Pending requests, oldest first, optionally only those assigned to
+    /// `assignee`. `start` counts matching requests.
+    pub fn list_pending(
+        env: &Env,
+        assignee: Option<Address>,
+        start: u32,
+        limit: u32,
+    ) -> Vec<PendingVerification> {
+        let limit = limit.min(MAX_PAGE_LIMIT);
+        let sla = Self::get_sla(env);
+        let mut result = Vec::new(env);
+        let mut matched: u32 = 0;
+        Self::scan(env, |request_id| {
+            if result.len() >= limit {
+                return false;
+            }
+            let Some(record) = Self::pending_record(env, request_id) else {
+                return true;
+            };
+            if assignee.is_some() && record.assigned_admin != assignee {
+                return true;
+            }
+            if matched >= start {
+                result.push_back(Self::to_pending(env, record, sla));
+            }
+            matched += 1;
+            true
+        });
+        result
+    }
+
+    /// 1-based position of the project's pending request in the queue.
+    pub fn get_queue_position(env: &Env, project_id: u64) -> Option<u32> {
+        let record = VerificationRegistry::get_verification(env, project_id)
+            .filter(|record| record.status == VerificationStatus::Pending)?;
+        let mut position: u32 = 0;
+        let mut found = false;
+        Self::scan(env, |request_id| {
+            position += 1;
+            found = request_id == record.request_id;
+            !found
+        });
+        found.then_some(position)
+    }
+
+    /// Emit a stale event for each pending request past the SLA that has not
+    /// been flagged yet, scanning up to `limit` queue entries from `start`.
+    /// Returns the project IDs flagged by this call.
+    pub fn flag_stale(env: &Env, start: u32, limit: u32) -> Vec<u64> {
+        let mut flagged = Vec::new(env);
+        let sla = Self::get_sla(env);
+        if sla == 0 {
+            return flagged;
+        }
+
+        let end = start.saturating_add(limit.min(MAX_PAGE_LIMIT));
+        let mut index: u32 = 0;
+        Self::scan(env, |request_id| {
+            if index >= end {
+                return false;
+            }
+            index += 1;
+            if index <= start {
+                return true;
+            }
+            let Some(record) = Self::pending_record(env, request_id) else {
+                return true;
+            };
+            let pending = Self::to_pending(env, record, sla);
+            // The queue is in request order, so nothing after a fresh
+            // request can be stale.
+            if !pending.sla_breached {
+                return false;
+            }
+            let marker = FeatureKey::VerificationStaleFlagged(request_id);
+            if !env.storage().persistent().has(&marker) {
+                env.storage().persistent().set(&marker, &true);
+                publish_verification_stale_event(env, &pending, sla);
+                flagged.push_back(pending.project_id);
+            }
+            true
+        });
+        flagged
+    }
+
+    pub fn get_assignment_mode(env: &Env) -> VerificationAssignmentMode {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::VerificationAssignmentMode)
+            .unwrap_or(VerificationAssignmentMode::Manual)
+    }
+
+    pub fn get_sla(env: &Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::VerificationSla)
+            .unwrap_or(0)
+    }
+
+    /// Admin: set how new requests are assigned to verifiers.
+    pub fn set_assignment_mode(
+        env: &Env,
+        admin: Address,
+        mode: VerificationAssignmentMode,
+    ) -> Result<(), ContractError> {
+        require_admin_auth(env, &admin)?;
+        env.storage()
+            .persistent()
+            .set(&FeatureKey::VerificationAssignmentMode, &mode);
+        Self::record_config_change(env, admin);
+        Ok(())
+    }
+
+    /// Admin: set the SLA in seconds after which pending requests are stale
+    /// (0 disables stale tracking).
+    pub fn set_sla(env: &Env, admin: Address, sla_seconds: u64) -> Result<(), ContractError> {
+        require_admin_auth(env, &admin)?;
+        env.storage()
+            .persistent()
+            .set(&FeatureKey::VerificationSla, &sla_seconds);
+        Self::record_config_change(env, admin);
+        Ok(())
+    }
+
+    fn record_config_change(env: &Env, admin: Address) {
+        publish_verification_queue_configured_event(
+            env,
+            admin.clone(),
+            Self::get_assignment_mode(env),
+            Self::get_sla(env),
+        );
+        AdminActionLog::record_action(
+            env,
+            admin,
+            AdminActionType::VerificationSettingsChanged,
+            None,
+            None,
+            None,
+        );
+    }
+}
@@ -34,9 +34,22 @@ impl VerificationStateMachine {
             // Pending -> Rejected (admin rejection)
             (VerificationStatus::Pending, VerificationStatus::Rejected) => Ok(()),
 
+            // Pending -> Withdrawn (owner withdrawal)
+            (VerificationStatus::Pending, VerificationStatus::Withdrawn) => Ok(()),
+
+            // Withdrawn -> Pending (re-request verification after withdrawal)
+            (VerificationStatus::Withdrawn, VerificationStatus::Pending) => Ok(()),
+
             // Verified -> Unverified (admin revocation)
             (VerificationStatus::Verified, VerificationStatus::Unverified) => Ok(()),
 
+            // Verified -> Suspended (admin incident response)
+            (VerificationStatus::Verified, VerificationStatus::Suspended) => Ok(()),
+
+            // Suspended -> Verified (resume) or Unverified (revocation)
+            (VerificationStatus::Suspended, VerificationStatus::Verified) => Ok(()),
+            (VerificationStatus::Suspended, VerificationStatus::Unverified) => Ok(()),
+
             // Same state (no change) - this should fail as it's not a valid transition
             (current, target) if current == target => Err(ContractError::InvalidStatus),
 
@@ -45,6 +58,16 @@ impl VerificationStateMachine {
         }
     }
 
+    /// Validates resuming a suspended verification, the `Suspended ->
+    /// Verified` transition. Other statuses that may move to Verified, such
+    /// as Pending, cannot be resumed.
+    pub fn validate_resume(current_status: VerificationStatus) -> Result<(), ContractError> {
+        if current_status != VerificationStatus::Suspended {
+            return Err(ContractError::InvalidStatus);
+        }
+        Self::validate_transition(current_status, VerificationStatus::Verified)
+    }
+
     /// Gets a descriptive error message for invalid transitions
     #[allow(dead_code)]
     fn get_transition_error_message(
@@ -84,7 +107,9 @@ impl VerificationStateMachine {
     pub fn can_request_verification(status: VerificationStatus) -> bool {
         matches!(
             status,
-            VerificationStatus::Unverified | VerificationStatus::Rejected
+            VerificationStatus::Unverified
+                | VerificationStatus::Rejected
+                | VerificationStatus::Withdrawn
         )
     }
 
@@ -116,9 +141,10 @@ impl VerificationStateMachine {
                 let mut v = Vec::new(env);
                 v.push_back(VerificationStatus::Verified);
                 v.push_back(VerificationStatus::Rejected);
+                v.push_back(VerificationStatus::Withdrawn);
                 v
             }
-            VerificationStatus::Rejected => {
+            VerificationStatus::Rejected | VerificationStatus::Withdrawn => {
                 let mut v = Vec::new(env);
                 v.push_back(VerificationStatus::Pending);
                 v
@@ -126,6 +152,13 @@ impl VerificationStateMachine {
             VerificationStatus::Verified => {
                 let mut v = Vec::new(env);
                 v.push_back(VerificationStatus::Unverified); // revocable by admin
+                v.push_back(VerificationStatus::Suspended);
+                v
+            }
+            VerificationStatus::Suspended => {
+                let mut v = Vec::new(env);
+                v.push_back(VerificationStatus::Verified);
+                v.push_back(VerificationStatus::Unverified);
                 v
             }
         }
@@ -165,6 +198,30 @@ mod tests {
             VerificationStatus::Rejected
         )
         .is_ok());
+
+        // Verified -> Suspended -> Verified
+        assert!(VerificationStateMachine::validate_transition(
+            VerificationStatus::Verified,
+            VerificationStatus::Suspended
+        )
+        .is_ok());
+        assert!(VerificationStateMachine::validate_transition(
+            VerificationStatus::Suspended,
+            VerificationStatus::Verified
+        )
+        .is_ok());
+
+        // Pending -> Withdrawn -> Pending
+        assert!(VerificationStateMachine::validate_transition(
+            VerificationStatus::Pending,
+            VerificationStatus::Withdrawn
+        )
+        .is_ok());
+        assert!(VerificationStateMachine::validate_transition(
+            VerificationStatus::Withdrawn,
+            VerificationStatus::Pending
+        )
+        .is_ok());
     }
 
     #[test]
@@ -206,6 +263,9 @@ mod tests {
         assert!(VerificationStateMachine::can_request_verification(
             VerificationStatus::Rejected
         ));
+        assert!(VerificationStateMachine::can_request_verification(
+            VerificationStatus::Withdrawn
+        ));
         assert!(!VerificationStateMachine::can_request_verification(
             VerificationStatus::Pending
         ));
@@ -246,6 +306,19 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_validate_resume() {
+        assert!(VerificationStateMachine::validate_resume(VerificationStatus::Suspended).is_ok());
+        assert_eq!(
+            VerificationStateMachine::validate_resume(VerificationStatus::Pending),
+            Err(ContractError::InvalidStatus)
+        );
+        assert_eq!(
+            VerificationStateMachine::validate_resume(VerificationStatus::Verified),
+            Err(ContractError::InvalidStatus)
+        );
+    }
+
     #[test]
     fn test_get_possible_next_states() {
         let env = Env::default();
@@ -262,9 +335,13 @@ mod tests {
 
         let pending_states =
             VerificationStateMachine::get_possible_next_states(&env, VerificationStatus::Pending);
-        assert_eq!(pending_states.len(), 2);
+        assert_eq!(pending_states.len(), 3);
         assert_eq!(pending_states.get(0).unwrap(), VerificationStatus::Verified);
         assert_eq!(pending_states.get(1).unwrap(), VerificationStatus::Rejected);
+        assert_eq!(
+            pending_states.get(2).unwrap(),
+            VerificationStatus::Withdrawn
+        );
 
         let rejected_states =
             VerificationStateMachine::get_possible_next_states(&env, VerificationStatus::Rejected);
@@ -273,7 +350,7 @@ mod tests {
 
         let verified_states =
             VerificationStateMachine::get_possible_next_states(&env, VerificationStatus::Verified);
-        assert_eq!(verified_states.len(), 1);
+        assert_eq!(verified_states.len(), 2);
         assert_eq!(
             verified_states.get(0).unwrap(),
             VerificationStatus::Unverified
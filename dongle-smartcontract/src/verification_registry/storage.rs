@@ -3,23 +3,43 @@
 use crate::admin_action_log::AdminActionLog;
 use crate::admin_manager::AdminManager;
 use crate::auth::{require_admin_auth, require_owner_auth};
-use crate::constants::MAX_PAGE_LIMIT;
+use crate::conflict_registry::ConflictRegistry;
+use crate::constants::{
+    DEFAULT_VERIFICATION_QUORUM, DEFAULT_WITHDRAWAL_REFUND_BPS, MAX_BPS, MAX_PAGE_LIMIT,
+    MAX_VERIFICATION_QUORUM, RENEWAL_SWEEP_GRACE_SECONDS,
+};
 use crate::errors::ContractError;
 use crate::events::{
-    publish_verification_approved_event, publish_verification_evidence_updated_event,
-    publish_verification_expired_event, publish_verification_rejected_event,
-    publish_verification_renewal_approved_event, publish_verification_renewal_rejected_event,
-    publish_verification_renewal_requested_event, publish_verification_renewed_event,
-    publish_verification_requested_event, publish_verification_revoked_event,
+    publish_fee_refunded_event, publish_verification_approved_event,
+    publish_verification_attested_event, publish_verification_evidence_updated_event,
+    publish_verification_expired_event, publish_verification_quorum_set_event,
+    publish_verification_rejected_event, publish_verification_renewal_approved_event,
+    publish_verification_renewal_rejected_event, publish_verification_renewal_requested_event,
+    publish_verification_renewed_event, publish_verification_requested_event,
+    publish_verification_resumed_event, publish_verification_revoked_event,
+    publish_verification_suspended_event, publish_verification_withdrawn_event,
+    publish_withdrawal_refund_set_event, FeeOperation,
 };
+use crate::fee_escrow::FeeEscrowManager;
 use crate::fee_manager::FeeManager;
+use crate::fee_revenue::FeeRevenueLedger;
 use crate::project_registry::ProjectRegistry;
-use crate::storage_keys::{ExtensionKey, StorageKey};
+use crate::storage_keys::{ExtensionKey, FeatureKey, StorageKey};
+use crate::storage_migration;
 use crate::types::{
-    AdminActionType, VerificationRecord, VerificationRenewalRecord, VerificationStatus,
+    AdminActionType, FeePaymentStatus, VerificationApproval, VerificationRecord,
+    VerificationRenewalRecord, VerificationStatus, VerificationSuspension, VerificationSweepResult,
+    VerificationTier,
 };
+use crate::verification_registry::checklist::VerificationChecklistRegistry;
+use crate::verification_registry::evidence::EvidenceRegistry;
+use crate::verification_registry::expiry::VerificationExpiryIndex;
+use crate::verification_registry::history::VerificationHistoryRegistry;
+use crate::verification_registry::queue::VerificationQueue;
 use crate::verification_registry::state_machine::VerificationStateMachine;
+use crate::verification_registry::tiers::VerificationTierRegistry;
 use crate::verification_registry::validation::VerificationValidation;
+use crate::verification_registry::verifiers::VerifierRegistry;
 use soroban_sdk::{Address, Env, String, Vec};
 
 pub struct VerificationRegistry;
@@ -30,6 +50,74 @@ impl VerificationRegistry {
         project_id: u64,
         requester: Address,
         evidence_cid: String,
+    ) -> Result<(), ContractError> {
+        Self::submit_request(env, project_id, requester, evidence_cid, None)
+    }
+
+    /// Request verification at a specific tier. The tier's fee replaces the
+    /// global verification fee, and approval grants the tier to the project.
+    pub fn request_tier_verification(
+        env: &Env,
+        project_id: u64,
+        requester: Address,
+        tier_id: u32,
+        evidence_cid: String,
+    ) -> Result<(), ContractError> {
+        let tier = VerificationTierRegistry::require_active_tier(env, tier_id)?;
+        Self::submit_request(env, project_id, requester, evidence_cid, Some(tier))
+    }
+
+    /// Consume the fee for a request: the tier's fee for tiered requests,
+    /// otherwise the verification fee paid in whichever accepted token the
+    /// owner chose. Returns the amount charged.
+    fn charge_request_fee(
+        env: &Env,
+        project_id: u64,
+        requester: &Address,
+        tier: &Option<VerificationTier>,
+    ) -> Result<u128, ContractError> {
+        if let Some(tier) = tier {
+            if tier.fee > 0 {
+                let payment = FeeManager::get_fee_payment_details(env, project_id)
+                    .filter(|_| FeeManager::is_fee_paid(env, project_id))
+                    .ok_or(ContractError::InsufficientFee)?;
+                // Tier fees are only priced in the default fee token.
+                if payment.token != FeeManager::get_fee_config(env)?.token {
+                    return Err(ContractError::InvalidProjectData);
+                }
+                if payment.amount < tier.fee {
+                    return Err(ContractError::InsufficientFee);
+                }
+                FeeManager::consume_fee_payment(env, project_id, requester.clone(), tier.fee)?;
+            }
+            return Ok(tier.fee);
+        }
+
+        if !FeeManager::is_fee_required(env, FeeOperation::Verification) {
+            return Ok(0);
+        }
+        let amount = FeeManager::get_fee_payment_details(env, project_id)
+            .map(|payment| payment.amount)
+            .unwrap_or(0);
+        // A payment priced below the current fee must be re-priced first.
+        if let Some(payment) = FeeManager::get_fee_payment_status(env, project_id)
+            .filter(|payment| payment.status == FeePaymentStatus::Valid)
+        {
+            let quote = FeeManager::get_fee_quote(env, payment.token, FeeOperation::Verification);
+            if quote.is_ok_and(|quote| payment.amount < quote) {
+                return Err(ContractError::InsufficientFee);
+            }
+        }
+        FeeManager::consume_fee_payment(env, project_id, requester.clone(), amount)?;
+        Ok(amount)
+    }
+
+    fn submit_request(
+        env: &Env,
+        project_id: u64,
+        requester: Address,
+        evidence_cid: String,
+        tier: Option<VerificationTier>,
     ) -> Result<(), ContractError> {
         // 1. Validate project existence and ownership
         let mut project =
@@ -59,19 +147,7 @@ impl VerificationRegistry {
         VerificationValidation::validate_evidence_cid(&evidence_cid)?;
 
         // 6. Consume fee payment when configured
-        let fee_amount = match FeeManager::get_fee_config(env) {
-            Ok(config) if config.verification_fee > 0 => {
-                FeeManager::consume_fee_payment(
-                    env,
-                    project_id,
-                    requester.clone(),
-                    config.verification_fee,
-                )?;
-                config.verification_fee
-            }
-            Ok(config) => config.verification_fee,
-            Err(_) => 0,
-        };
+        let fee_amount = Self::charge_request_fee(env, project_id, &requester, &tier)?;
 
         // 7. Generate a unique request ID
         let mut request_id = env
@@ -99,6 +175,10 @@ impl VerificationRegistry {
             expires_at: 0,
             last_renewed_at: 0,
             assigned_admin: None,
+            tier_id: tier.map(|tier| tier.tier_id),
+            required_approvals: Self::get_verification_quorum(env),
+            approvals: Vec::new(env),
+            checklist: Vec::new(env),
         };
 
         // 8. Save to historical record
@@ -122,6 +202,7 @@ impl VerificationRegistry {
             &StorageKey::ProjectVerificationHistory(project_id),
             &history,
         );
+        VerificationHistoryRegistry::apply_to_verifications(env, project_id);
 
         // 11. Update project status to Pending
         project.verification_status = VerificationStatus::Pending;
@@ -132,6 +213,7 @@ impl VerificationRegistry {
             .set(&StorageKey::Project(project_id), &project);
 
         publish_verification_requested_event(env, project_id, requester, evidence_cid);
+        VerificationQueue::enqueue(env, record);
         Ok(())
     }
 
@@ -165,9 +247,13 @@ impl VerificationRegistry {
         // 4. Validate CID before state mutation
         VerificationValidation::validate_evidence_cid(&new_evidence_cid)?;
 
-        // 5. Update CID and persist
+        // 5. Update CID and persist. Sign-offs and checklist ticks covered the
+        // old evidence, so verifiers have to review the new evidence again.
         let old_evidence_cid = record.evidence_cid;
         record.evidence_cid = new_evidence_cid.clone();
+        record.approvals = Vec::new(env);
+        record.checklist = Vec::new(env);
+        EvidenceRegistry::clear_commitment(env, record.request_id);
 
         env.storage()
             .persistent()
@@ -185,17 +271,46 @@ impl VerificationRegistry {
         Ok(())
     }
 
+    /// Sign off on a pending verification request as an admin or verifier.
+    /// With the default quorum of one this approves the request outright.
     pub fn approve_verification(
         env: &Env,
         project_id: u64,
         admin: Address,
     ) -> Result<(), ContractError> {
-        require_admin_auth(env, &admin)?;
+        VerifierRegistry::require_can_decide(env, &admin, project_id)?;
 
         if crate::admin_manager::AdminManager::get_admin_approval_threshold(env) > 1 {
             return Err(ContractError::Unauthorized);
         }
 
+        Self::sign_off(env, project_id, admin, None)
+    }
+
+    /// Record a verifier's sign-off on a pending request, with optional review
+    /// notes. The request moves to Verified once `required_approvals` distinct
+    /// verifiers have signed off. Not affected by the governance approval
+    /// threshold.
+    pub fn attest_verification(
+        env: &Env,
+        project_id: u64,
+        verifier: Address,
+        notes_cid: Option<String>,
+    ) -> Result<(), ContractError> {
+        VerifierRegistry::require_can_decide(env, &verifier, project_id)?;
+        if let Some(cid) = &notes_cid {
+            VerificationValidation::validate_evidence_cid(cid)?;
+        }
+
+        Self::sign_off(env, project_id, verifier, notes_cid)
+    }
+
+    fn sign_off(
+        env: &Env,
+        project_id: u64,
+        verifier: Address,
+        notes_cid: Option<String>,
+    ) -> Result<(), ContractError> {
         // Get project
         let mut project =
             ProjectRegistry::get_project(env, project_id).ok_or(ContractError::ProjectNotFound)?;
@@ -209,11 +324,58 @@ impl VerificationRegistry {
             project.verification_status,
             VerificationStatus::Verified,
         )?;
+        VerificationChecklistRegistry::require_complete(env, &project.category, &record)?;
+        ConflictRegistry::require_no_conflict(env, project_id, &verifier)?;
+
+        if record
+            .approvals
+            .iter()
+            .any(|approval| approval.verifier == verifier)
+        {
+            return Err(ContractError::AlreadyAttested);
+        }
 
         let now = env.ledger().timestamp();
+        record.approvals.push_back(VerificationApproval {
+            verifier: verifier.clone(),
+            notes_cid: notes_cid.clone(),
+            approved_at: now,
+        });
+        let approvals = record.approvals.len();
+        publish_verification_attested_event(
+            env,
+            project_id,
+            record.request_id,
+            verifier.clone(),
+            notes_cid,
+            approvals,
+            record.required_approvals,
+        );
+
+        // Below quorum: keep the request pending with the new sign-off.
+        if approvals < record.required_approvals {
+            env.storage()
+                .persistent()
+                .set(&StorageKey::VerificationRecord(record.request_id), &record);
+            AdminActionLog::record_action(
+                env,
+                verifier,
+                AdminActionType::VerificationAttested,
+                Some(project_id),
+                None,
+                None,
+            );
+            return Ok(());
+        }
+
+        VerificationQueue::dequeue(env, &record);
 
         // Update record – stamp the expiry timestamp
-        let duration = AdminManager::get_verification_duration(env);
+        let duration = VerificationTierRegistry::duration_for(
+            env,
+            record.tier_id,
+            AdminManager::get_verification_duration(env),
+        );
         record.status = VerificationStatus::Verified;
         record.expires_at = now.saturating_add(duration);
         record.decided_at = now;
@@ -223,6 +385,8 @@ impl VerificationRegistry {
         env.storage()
             .persistent()
             .set(&StorageKey::VerificationRecord(record.request_id), &record);
+        Self::grant_record_tier(env, &record);
+        VerificationExpiryIndex::index(env, project_id, record.expires_at);
 
         // Update project
         project.verification_status = VerificationStatus::Verified;
@@ -232,11 +396,12 @@ impl VerificationRegistry {
             .persistent()
             .set(&StorageKey::Project(project_id), &project);
 
-        publish_verification_approved_event(env, project_id, admin.clone(), now);
+        publish_verification_approved_event(env, project_id, verifier.clone(), now);
+        FeeEscrowManager::settle_decision(env, project_id, true, FeeOperation::Verification)?;
 
         AdminActionLog::record_action(
             env,
-            admin,
+            verifier,
             AdminActionType::VerificationApproved,
             Some(project_id),
             None,
@@ -251,7 +416,7 @@ impl VerificationRegistry {
         project_id: u64,
         admin: Address,
     ) -> Result<(), ContractError> {
-        require_admin_auth(env, &admin)?;
+        VerifierRegistry::require_can_decide(env, &admin, project_id)?;
 
         if crate::admin_manager::AdminManager::get_admin_approval_threshold(env) > 1 {
             return Err(ContractError::Unauthorized);
@@ -272,6 +437,7 @@ impl VerificationRegistry {
         )?;
 
         let now = env.ledger().timestamp();
+        VerificationQueue::dequeue(env, &record);
 
         // Update record
         record.status = VerificationStatus::Rejected;
@@ -292,6 +458,7 @@ impl VerificationRegistry {
             .set(&StorageKey::Project(project_id), &project);
 
         publish_verification_rejected_event(env, project_id, admin.clone(), now);
+        FeeEscrowManager::settle_decision(env, project_id, false, FeeOperation::Verification)?;
 
         AdminActionLog::record_action(
             env,
@@ -305,42 +472,127 @@ impl VerificationRegistry {
         Ok(())
     }
 
+    /// Owner: withdraw a pending request that has not been assigned or
+    /// signed off yet. Refunds the configured share of the consumed fee from
+    /// escrow, or from the treasury, which must then authorize the transfer.
+    /// The record stays in the project's history as `Withdrawn`.
+    pub fn withdraw_verification_request(
+        env: &Env,
+        project_id: u64,
+        owner: Address,
+    ) -> Result<u128, ContractError> {
+        let mut project =
+            ProjectRegistry::get_project(env, project_id).ok_or(ContractError::ProjectNotFound)?;
+        require_owner_auth(&owner, &project.owner)?;
+
+        let mut record =
+            Self::get_verification(env, project_id).ok_or(ContractError::VerificationNotFound)?;
+        VerificationStateMachine::validate_transition(
+            project.verification_status,
+            VerificationStatus::Withdrawn,
+        )?;
+        if record.assigned_admin.is_some() || !record.approvals.is_empty() {
+            return Err(ContractError::VerificationInReview);
+        }
+
+        let refund_share = record
+            .fee_amount
+            .saturating_mul(Self::get_withdrawal_refund_bps(env) as u128)
+            / MAX_BPS as u128;
+        // The contract refunds an escrowed fee and releases the rest; a fee
+        // already paid out is refunded by the treasury.
+        let escrowed =
+            FeeEscrowManager::settle(env, project_id, refund_share, FeeOperation::Verification)?;
+        let refund_amount = refund_share;
+        if refund_amount > 0 {
+            if !escrowed {
+                let payment = FeeManager::get_fee_payment_details(env, project_id)
+                    .ok_or(ContractError::FeeConfigNotSet)?;
+                let token = payment.token.ok_or(ContractError::FeeConfigNotSet)?;
+                let treasury = FeeManager::get_treasury(env)?;
+                treasury.require_auth();
+                soroban_sdk::token::Client::new(env, &token).transfer(
+                    &treasury,
+                    &record.requester,
+                    &(refund_amount as i128),
+                );
+                FeeRevenueLedger::record_refunded(
+                    env,
+                    &token,
+                    FeeOperation::Verification,
+                    refund_amount,
+                );
+            }
+            publish_fee_refunded_event(
+                env,
+                project_id,
+                record.request_id,
+                record.requester.clone(),
+                refund_amount,
+            );
+        }
+
+        let now = env.ledger().timestamp();
+        VerificationQueue::dequeue(env, &record);
+        record.status = VerificationStatus::Withdrawn;
+        record.decided_at = now;
+        env.storage()
+            .persistent()
+            .set(&StorageKey::VerificationRecord(record.request_id), &record);
+
+        project.verification_status = VerificationStatus::Withdrawn;
+        project.updated_at = now;
+        env.storage()
+            .persistent()
+            .set(&StorageKey::Project(project_id), &project);
+
+        publish_verification_withdrawn_event(
+            env,
+            project_id,
+            record.request_id,
+            owner,
+            refund_amount,
+        );
+        Ok(refund_amount)
+    }
+
     pub fn get_verification(env: &Env, project_id: u64) -> Option<VerificationRecord> {
         let request_id = env
             .storage()
             .persistent()
             .get::<_, u64>(&StorageKey::Verification(project_id))?;
-        env.storage()
-            .persistent()
-            .get::<_, VerificationRecord>(&StorageKey::VerificationRecord(request_id))
+        Self::get_verification_record(env, request_id)
     }
 
+    /// Verification record by request ID, including records stored before
+    /// tiers and quorums were added.
     pub fn get_verification_record(env: &Env, request_id: u64) -> Option<VerificationRecord> {
-        env.storage()
-            .persistent()
-            .get::<_, VerificationRecord>(&StorageKey::VerificationRecord(request_id))
+        storage_migration::get_verification_record(env, &StorageKey::VerificationRecord(request_id))
     }
 
     /// Returns `true` if the project has a Verified record that has **not** yet expired.
     ///
     /// A record is considered active when:
-    ///   1. `status == Verified`, **and**
+    ///   1. `status == Verified` (or `Suspended` with a lapsed suspension), **and**
     ///   2. `expires_at` is either `None` (legacy records without an expiry) **or**
     ///      `Some(t)` where `t > current_ledger_timestamp`.
     ///
     /// If the record is expired this also emits a `VerificationExpiredEvent` so that
     /// indexers can pick it up without needing a dedicated "check expiry" transaction.
     pub fn is_verification_active(env: &Env, project_id: u64) -> bool {
-        let record: VerificationRecord = match env
-            .storage()
-            .persistent()
-            .get(&StorageKey::Verification(project_id))
-        {
+        let record = match Self::get_verification(env, project_id) {
             Some(r) => r,
             None => return false,
         };
 
-        if record.status != VerificationStatus::Verified {
+        // A suspension whose `resume_at` has passed no longer holds the
+        // project back, even before anyone calls `resume_verification`.
+        let verified = match record.status {
+            VerificationStatus::Verified => true,
+            VerificationStatus::Suspended => !Self::is_suspended(env, &record),
+            _ => false,
+        };
+        if !verified {
             return false;
         }
 
@@ -359,6 +611,153 @@ impl VerificationRegistry {
         }
     }
 
+    /// Admin: put a verified project on hold during an incident. The
+    /// verification keeps its `expires_at`; a nonzero `resume_at` lets the
+    /// suspension lapse on its own at that time. A pending renewal is
+    /// dropped, so the owner requests it again after the suspension.
+    pub fn suspend_verification(
+        env: &Env,
+        project_id: u64,
+        admin: Address,
+        reason_cid: String,
+        resume_at: u64,
+    ) -> Result<(), ContractError> {
+        require_admin_auth(env, &admin)?;
+
+        let mut project =
+            ProjectRegistry::get_project(env, project_id).ok_or(ContractError::ProjectNotFound)?;
+        let mut record =
+            Self::get_verification(env, project_id).ok_or(ContractError::VerificationNotFound)?;
+        VerificationStateMachine::validate_transition(
+            project.verification_status,
+            VerificationStatus::Suspended,
+        )?;
+        VerificationValidation::validate_evidence_cid(&reason_cid)?;
+        let now = env.ledger().timestamp();
+        if resume_at != 0 && resume_at <= now {
+            return Err(ContractError::InvalidInput);
+        }
+
+        // A renewal ended without a decision gets its escrowed fee back in
+        // full.
+        let renewal_key = StorageKey::VerificationRenewal(project_id);
+        if env.storage().persistent().has(&renewal_key) {
+            env.storage().persistent().remove(&renewal_key);
+            FeeEscrowManager::refund_undecided(env, project_id, FeeOperation::Renewal);
+        }
+
+        let suspension = VerificationSuspension {
+            request_id: record.request_id,
+            reason_cid: reason_cid.clone(),
+            suspended_by: admin.clone(),
+            suspended_at: now,
+            resume_at,
+        };
+        env.storage().persistent().set(
+            &FeatureKey::VerificationSuspension(record.request_id),
+            &suspension,
+        );
+
+        record.status = VerificationStatus::Suspended;
+        env.storage()
+            .persistent()
+            .set(&StorageKey::VerificationRecord(record.request_id), &record);
+        project.verification_status = VerificationStatus::Suspended;
+        project.updated_at = now;
+        env.storage()
+            .persistent()
+            .set(&StorageKey::Project(project_id), &project);
+
+        publish_verification_suspended_event(env, project_id, suspension);
+        AdminActionLog::record_action(
+            env,
+            admin,
+            AdminActionType::VerificationSuspended,
+            Some(project_id),
+            None,
+            Some(reason_cid),
+        );
+        Ok(())
+    }
+
+    /// Resume a suspended verification with its original `expires_at`.
+    /// Admins can resume at any time; anyone can once `resume_at` has passed.
+    pub fn resume_verification(
+        env: &Env,
+        project_id: u64,
+        caller: Address,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        let mut project =
+            ProjectRegistry::get_project(env, project_id).ok_or(ContractError::ProjectNotFound)?;
+        VerificationStateMachine::validate_resume(project.verification_status)?;
+        let mut record =
+            Self::get_verification(env, project_id).ok_or(ContractError::VerificationNotFound)?;
+        let is_admin = AdminManager::is_admin(env, &caller);
+        if !is_admin && Self::is_suspended(env, &record) {
+            return Err(ContractError::AdminOnly);
+        }
+
+        let now = env.ledger().timestamp();
+        record.status = VerificationStatus::Verified;
+        env.storage()
+            .persistent()
+            .set(&StorageKey::VerificationRecord(record.request_id), &record);
+        project.verification_status = VerificationStatus::Verified;
+        project.updated_at = now;
+        env.storage()
+            .persistent()
+            .set(&StorageKey::Project(project_id), &project);
+
+        publish_verification_resumed_event(
+            env,
+            project_id,
+            record.request_id,
+            caller.clone(),
+            record.expires_at,
+        );
+        if is_admin {
+            AdminActionLog::record_action(
+                env,
+                caller,
+                AdminActionType::VerificationResumed,
+                Some(project_id),
+                None,
+                None,
+            );
+        }
+        Ok(())
+    }
+
+    /// The suspension recorded for the project's current verification, if any.
+    pub fn get_verification_suspension(
+        env: &Env,
+        project_id: u64,
+    ) -> Option<VerificationSuspension> {
+        let record = Self::get_verification(env, project_id)?;
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::VerificationSuspension(record.request_id))
+    }
+
+    /// Whether the record is suspended and the suspension has not lapsed.
+    pub fn is_suspended(env: &Env, record: &VerificationRecord) -> bool {
+        if record.status != VerificationStatus::Suspended {
+            return false;
+        }
+        let suspension: Option<VerificationSuspension> = env
+            .storage()
+            .persistent()
+            .get(&FeatureKey::VerificationSuspension(record.request_id));
+        match suspension {
+            Some(suspension) => {
+                suspension.resume_at == 0 || env.ledger().timestamp() < suspension.resume_at
+            }
+            None => true,
+        }
+    }
+
     /// Batch-fetch verification records for multiple project IDs.
     /// Silently skips IDs with no record. Clamped to 100 entries.
     pub fn get_verifications_batch(env: &Env, ids: Vec<u64>) -> Vec<(u64, VerificationRecord)> {
@@ -389,11 +788,7 @@ impl VerificationRegistry {
         {
             for i in 0..history.len() {
                 if let Some(req_id) = history.get(i) {
-                    if let Some(record) = env
-                        .storage()
-                        .persistent()
-                        .get::<_, VerificationRecord>(&StorageKey::VerificationRecord(req_id))
-                    {
+                    if let Some(record) = Self::get_verification_record(env, req_id) {
                         out.push_back(record);
                     }
                 }
@@ -411,9 +806,12 @@ impl VerificationRegistry {
     ) -> Result<(), ContractError> {
         require_admin_auth(env, &admin)?;
 
-        // Assignee must also be an admin
+        // Assignee must be an admin or a verifier whose role covers the request
         if !crate::admin_manager::AdminManager::is_admin(env, &assignee) {
-            return Err(ContractError::AdminNotFound);
+            if VerifierRegistry::active_grant(env, &assignee).is_none() {
+                return Err(ContractError::AdminNotFound);
+            }
+            VerifierRegistry::require_in_scope(env, &assignee, project_id)?;
         }
 
         let mut record =
@@ -422,6 +820,7 @@ impl VerificationRegistry {
             return Err(ContractError::InvalidStatus);
         }
 
+        VerificationQueue::reassign(env, &record.assigned_admin, &assignee);
         record.assigned_admin = Some(assignee.clone());
         env.storage()
             .persistent()
@@ -475,7 +874,10 @@ impl VerificationRegistry {
         let mut project =
             ProjectRegistry::get_project(env, project_id).ok_or(ContractError::ProjectNotFound)?;
 
-        if project.verification_status != VerificationStatus::Verified {
+        if !matches!(
+            project.verification_status,
+            VerificationStatus::Verified | VerificationStatus::Suspended
+        ) {
             return Err(ContractError::InvalidStatus);
         }
 
@@ -500,6 +902,8 @@ impl VerificationRegistry {
         env.storage()
             .persistent()
             .set(&StorageKey::Project(project_id), &project);
+        VerificationTierRegistry::clear_grants(env, project_id);
+        VerificationExpiryIndex::unindex(env, project_id);
 
         publish_verification_revoked_event(env, project_id, admin.clone(), reason.clone());
 
@@ -515,6 +919,93 @@ impl VerificationRegistry {
         Ok(())
     }
 
+    /// Grant the tier named on a verified record, valid until the record expires.
+    pub(crate) fn grant_record_tier(env: &Env, record: &VerificationRecord) {
+        if let Some(tier_id) = record.tier_id {
+            VerificationTierRegistry::grant(
+                env,
+                record.project_id,
+                tier_id,
+                record.request_id,
+                record.expires_at,
+            );
+        }
+    }
+
+    /// Verifier sign-offs required for new verification requests.
+    pub fn get_verification_quorum(env: &Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::VerificationQuorum)
+            .unwrap_or(DEFAULT_VERIFICATION_QUORUM)
+    }
+
+    /// Set the verifier quorum. Applies to requests submitted afterwards;
+    /// pending requests keep the quorum they were created with.
+    pub fn set_verification_quorum(
+        env: &Env,
+        admin: Address,
+        quorum: u32,
+    ) -> Result<(), ContractError> {
+        require_admin_auth(env, &admin)?;
+        if quorum == 0 || quorum > MAX_VERIFICATION_QUORUM {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let previous_quorum = Self::get_verification_quorum(env);
+        env.storage()
+            .persistent()
+            .set(&FeatureKey::VerificationQuorum, &quorum);
+
+        publish_verification_quorum_set_event(env, admin.clone(), previous_quorum, quorum);
+        AdminActionLog::record_action(
+            env,
+            admin,
+            AdminActionType::VerificationQuorumSet,
+            None,
+            None,
+            None,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_withdrawal_refund_bps(env: &Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::VerificationWithdrawalRefundBps)
+            .unwrap_or(DEFAULT_WITHDRAWAL_REFUND_BPS)
+    }
+
+    /// Set the share of the consumed fee, in basis points, refunded when an
+    /// owner withdraws a request.
+    pub fn set_withdrawal_refund_bps(
+        env: &Env,
+        admin: Address,
+        refund_bps: u32,
+    ) -> Result<(), ContractError> {
+        require_admin_auth(env, &admin)?;
+        if refund_bps > MAX_BPS {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let previous_bps = Self::get_withdrawal_refund_bps(env);
+        env.storage()
+            .persistent()
+            .set(&FeatureKey::VerificationWithdrawalRefundBps, &refund_bps);
+
+        publish_withdrawal_refund_set_event(env, admin.clone(), previous_bps, refund_bps);
+        AdminActionLog::record_action(
+            env,
+            admin,
+            AdminActionType::VerificationSettingsChanged,
+            None,
+            None,
+            None,
+        );
+        Ok(())
+    }
+
     /// Get minimum project age configuration
     pub fn get_min_project_age(env: &Env) -> u64 {
         env.storage()
@@ -598,6 +1089,32 @@ impl VerificationRegistry {
         project_id: u64,
         requester: Address,
         evidence_cid: String,
+    ) -> Result<(), ContractError> {
+        let tier = Self::get_verification(env, project_id)
+            .and_then(|record| record.tier_id)
+            .and_then(|tier_id| VerificationTierRegistry::get_tier(env, tier_id));
+        Self::submit_renewal(env, project_id, requester, evidence_cid, tier)
+    }
+
+    /// Request a renewal at a specific tier, e.g. to move a verified project
+    /// to a higher tier. Charged and timed like a tiered request.
+    pub fn request_tier_renewal(
+        env: &Env,
+        project_id: u64,
+        requester: Address,
+        tier_id: u32,
+        evidence_cid: String,
+    ) -> Result<(), ContractError> {
+        let tier = VerificationTierRegistry::require_active_tier(env, tier_id)?;
+        Self::submit_renewal(env, project_id, requester, evidence_cid, Some(tier))
+    }
+
+    fn submit_renewal(
+        env: &Env,
+        project_id: u64,
+        requester: Address,
+        evidence_cid: String,
+        tier: Option<VerificationTier>,
     ) -> Result<(), ContractError> {
         let project =
             ProjectRegistry::get_project(env, project_id).ok_or(ContractError::ProjectNotFound)?;
@@ -616,21 +1133,26 @@ impl VerificationRegistry {
 
         VerificationValidation::validate_evidence_cid(&evidence_cid)?;
 
-        let fee_amount = match FeeManager::get_fee_config(env) {
-            Ok(config) if config.verification_fee > 0 => {
-                FeeManager::consume_fee_payment(
+        let fee_amount = Self::charge_request_fee(env, project_id, &requester, &tier)?;
+        // The consumed verification payment is renewal revenue.
+        if let Some(payment) = FeeManager::get_fee_payment_details(env, project_id) {
+            if let Some(token) = payment.token {
+                FeeRevenueLedger::reattribute(
                     env,
-                    project_id,
-                    requester.clone(),
-                    config.verification_fee,
-                )?;
-                config.verification_fee
+                    &token,
+                    FeeOperation::Verification,
+                    FeeOperation::Renewal,
+                    fee_amount,
+                    payment.paid_at,
+                );
             }
-            Ok(config) => config.verification_fee,
-            Err(_) => 0,
-        };
+        }
 
         let now = env.ledger().timestamp();
+        let duration = tier
+            .as_ref()
+            .map(|tier| tier.duration_seconds)
+            .unwrap_or_else(|| Self::get_verification_duration(env));
         let renewal = VerificationRenewalRecord {
             project_id,
             requester: requester.clone(),
@@ -638,12 +1160,14 @@ impl VerificationRegistry {
             evidence_cid: evidence_cid.clone(),
             timestamp: now,
             fee_amount,
-            expires_at: now.saturating_add(Self::get_verification_duration(env)),
+            expires_at: now.saturating_add(duration),
+            tier_id: tier.map(|tier| tier.tier_id),
         };
 
         env.storage()
             .persistent()
             .set(&StorageKey::VerificationRenewal(project_id), &renewal);
+        VerificationHistoryRegistry::apply_to_renewals(env, project_id);
 
         publish_verification_renewal_requested_event(
             env,
@@ -661,20 +1185,30 @@ impl VerificationRegistry {
         admin: Address,
     ) -> Result<(), ContractError> {
         require_admin_auth(env, &admin)?;
+        ConflictRegistry::require_no_conflict(env, project_id, &admin)?;
 
         let renewal = Self::get_renewal_request(env, project_id)
             .ok_or(ContractError::VerificationNotFound)?;
         let mut verification =
             Self::get_verification(env, project_id).ok_or(ContractError::VerificationNotFound)?;
+        if verification.status != VerificationStatus::Verified {
+            return Err(ContractError::InvalidStatus);
+        }
         let mut project =
             ProjectRegistry::get_project(env, project_id).ok_or(ContractError::ProjectNotFound)?;
 
         let now = env.ledger().timestamp();
-        let expires_at = now.saturating_add(Self::get_verification_duration(env));
+        let duration = VerificationTierRegistry::duration_for(
+            env,
+            renewal.tier_id,
+            Self::get_verification_duration(env),
+        );
+        let expires_at = now.saturating_add(duration);
 
         verification.status = VerificationStatus::Verified;
         verification.expires_at = expires_at;
         verification.last_renewed_at = now;
+        verification.tier_id = renewal.tier_id;
         env.storage().persistent().set(
             &StorageKey::Verification(project_id),
             &verification.request_id,
@@ -683,6 +1217,8 @@ impl VerificationRegistry {
             &StorageKey::VerificationRecord(verification.request_id),
             &verification,
         );
+        Self::grant_record_tier(env, &verification);
+        VerificationExpiryIndex::index(env, project_id, verification.expires_at);
 
         project.updated_at = now;
         project.current_verification_id = Some(verification.request_id);
@@ -713,6 +1249,7 @@ impl VerificationRegistry {
             .remove(&StorageKey::VerificationRenewal(project_id));
 
         publish_verification_renewal_approved_event(env, project_id, admin.clone(), expires_at);
+        FeeEscrowManager::settle_decision(env, project_id, true, FeeOperation::Renewal)?;
 
         AdminActionLog::record_action(
             env,
@@ -749,7 +1286,11 @@ impl VerificationRegistry {
         }
 
         let now = env.ledger().timestamp();
-        let duration = AdminManager::get_verification_duration(env);
+        let duration = VerificationTierRegistry::duration_for(
+            env,
+            record.tier_id,
+            AdminManager::get_verification_duration(env),
+        );
         let new_expires_at = now.saturating_add(duration);
 
         record.expires_at = new_expires_at;
@@ -760,6 +1301,8 @@ impl VerificationRegistry {
         env.storage()
             .persistent()
             .set(&StorageKey::VerificationRecord(record.request_id), &record);
+        Self::grant_record_tier(env, &record);
+        VerificationExpiryIndex::index(env, project_id, record.expires_at);
 
         publish_verification_renewed_event(env, project_id, admin, new_expires_at);
         Ok(())
@@ -773,6 +1316,7 @@ impl VerificationRegistry {
             .persistent()
             .remove(&StorageKey::VerificationRenewal(project_id));
         publish_verification_renewal_rejected_event(env, project_id, admin.clone());
+        FeeEscrowManager::settle_decision(env, project_id, false, FeeOperation::Renewal)?;
 
         AdminActionLog::record_action(
             env,
@@ -787,9 +1331,7 @@ impl VerificationRegistry {
     }
 
     pub fn get_renewal_request(env: &Env, project_id: u64) -> Option<VerificationRenewalRecord> {
-        env.storage()
-            .persistent()
-            .get(&StorageKey::VerificationRenewal(project_id))
+        storage_migration::get_renewal_record(env, &StorageKey::VerificationRenewal(project_id))
     }
 
     pub fn get_renewal_history(
@@ -813,11 +1355,10 @@ impl VerificationRegistry {
         let mut history = Vec::new(env);
         let end = core::cmp::min(start_index.saturating_add(effective_limit), count);
         for index in start_index..end {
-            if let Some(record) = env
-                .storage()
-                .persistent()
-                .get(&StorageKey::VerificationRenewalHistory(project_id, index))
-            {
+            if let Some(record) = storage_migration::get_renewal_record(
+                env,
+                &StorageKey::VerificationRenewalHistory(project_id, index),
+            ) {
                 history.push_back(record);
             }
         }
@@ -830,6 +1371,127 @@ impl VerificationRegistry {
         Ok(verification.expires_at != 0 && env.ledger().timestamp() > verification.expires_at)
     }
 
+    /// Returns true when the current verification has not expired yet but
+    /// will expire within `threshold_seconds` of the current ledger timestamp.
+    pub fn is_verification_expiring_soon(
+        env: &Env,
+        project_id: u64,
+        threshold_seconds: u64,
+    ) -> Result<bool, ContractError> {
+        let verification =
+            Self::get_verification(env, project_id).ok_or(ContractError::VerificationNotFound)?;
+        if verification.status != VerificationStatus::Verified || verification.expires_at == 0 {
+            return Ok(false);
+        }
+        let now = env.ledger().timestamp();
+        if now > verification.expires_at {
+            return Ok(false);
+        }
+        Ok(verification.expires_at - now <= threshold_seconds)
+    }
+
+    /// Move lapsed verifications to Unverified in bounded batches.
+    ///
+    /// Permissionless. Checks up to `limit` project IDs (clamped to
+    /// `MAX_PAGE_LIMIT`) starting at `cursor` (0 = first project). A project
+    /// is expired once `is_verification_expired` reports it, whether it is
+    /// verified or suspended. A pending renewal request holds off expiry for
+    /// `RENEWAL_SWEEP_GRACE_SECONDS`; after that the renewal is dropped, its
+    /// escrowed fee refunded, and the project expires. Each expired project
+    /// loses its tiers and emits `VerificationExpiredEvent`. Unexpired
+    /// verifications missing from the expiry index are added to it.
+    pub fn sweep_expired_verifications(
+        env: &Env,
+        cursor: u64,
+        limit: u32,
+    ) -> VerificationSweepResult {
+        let effective_limit = if limit == 0 || limit > MAX_PAGE_LIMIT {
+            MAX_PAGE_LIMIT
+        } else {
+            limit
+        };
+        let count: u64 = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::ProjectCount)
+            .unwrap_or(0);
+
+        let mut expired = Vec::new(env);
+        let first = if cursor == 0 { 1 } else { cursor };
+        if first > count {
+            return VerificationSweepResult {
+                expired,
+                next_cursor: None,
+            };
+        }
+        let last = core::cmp::min(count, first.saturating_add(effective_limit as u64 - 1));
+        let now = env.ledger().timestamp();
+
+        for project_id in first..=last {
+            let mut project = match ProjectRegistry::get_project(env, project_id) {
+                Some(project)
+                    if matches!(
+                        project.verification_status,
+                        VerificationStatus::Verified | VerificationStatus::Suspended
+                    ) =>
+                {
+                    project
+                }
+                _ => continue,
+            };
+            let mut record = match Self::get_verification(env, project_id) {
+                Some(record) if record.expires_at != 0 => record,
+                _ => continue,
+            };
+            if now <= record.expires_at {
+                // Backfills the expiry index for verifications approved
+                // before it existed.
+                VerificationExpiryIndex::index(env, project_id, record.expires_at);
+                continue;
+            }
+            if VerificationStateMachine::validate_transition(
+                project.verification_status,
+                VerificationStatus::Unverified,
+            )
+            .is_err()
+            {
+                continue;
+            }
+            let renewal_key = StorageKey::VerificationRenewal(project_id);
+            if env.storage().persistent().has(&renewal_key) {
+                let grace_ends_at = record
+                    .expires_at
+                    .saturating_add(RENEWAL_SWEEP_GRACE_SECONDS);
+                if now <= grace_ends_at {
+                    continue;
+                }
+                env.storage().persistent().remove(&renewal_key);
+                FeeEscrowManager::refund_undecided(env, project_id, FeeOperation::Renewal);
+            }
+
+            record.status = VerificationStatus::Unverified;
+            env.storage()
+                .persistent()
+                .set(&StorageKey::VerificationRecord(record.request_id), &record);
+
+            project.verification_status = VerificationStatus::Unverified;
+            project.updated_at = now;
+            env.storage()
+                .persistent()
+                .set(&StorageKey::Project(project_id), &project);
+            VerificationTierRegistry::clear_grants(env, project_id);
+            VerificationExpiryIndex::unindex(env, project_id);
+
+            publish_verification_expired_event(env, project_id, record.expires_at);
+            expired.push_back(project_id);
+        }
+
+        VerificationSweepResult {
+            expired,
+            next_cursor: if last < count { Some(last + 1) } else { None },
+        }
+    }
+
     /// Admin-only: prune verification history for a project, retaining only the
     /// most recent `keep_count` records. Pass `keep_count = 0` to remove all
     /// historical records (the live `Verification(project_id)` record is never removed).
@@ -872,28 +1534,8 @@ impl VerificationRegistry {
             return Ok(0);
         }
 
-        // Remove individual VerificationRecord entries for pruned request IDs
-        for i in 0..remove_count {
-            if let Some(req_id) = history.get(i) {
-                env.storage()
-                    .persistent()
-                    .remove(&StorageKey::VerificationRecord(req_id));
-            }
-        }
-
-        // Build the retained history (most recent `keep` entries)
-        let mut retained = Vec::new(env);
-        for i in remove_count..total {
-            if let Some(req_id) = history.get(i) {
-                retained.push_back(req_id);
-            }
-        }
-
-        if retained.is_empty() {
-            env.storage().persistent().remove(&history_key);
-        } else {
-            env.storage().persistent().set(&history_key, &retained);
-        }
+        // Remove the oldest records, folding them into the history summary
+        VerificationHistoryRegistry::prune_verification_records(env, project_id, remove_count);
 
         crate::events::publish_verification_history_cleared_event(
             env,
@@ -937,22 +1579,28 @@ impl VerificationRegistry {
             .persistent()
             .get(&StorageKey::VerificationRenewalCount(project_id))
             .unwrap_or(0);
+        let start: u32 = env
+            .storage()
+            .persistent()
+            .get(&FeatureKey::RenewalHistoryStart(project_id))
+            .unwrap_or(0);
 
         if count == 0 {
             return Ok(0);
         }
 
-        // Remove every individual renewal record
-        for index in 0..count {
-            env.storage()
-                .persistent()
-                .remove(&StorageKey::VerificationRenewalHistory(project_id, index));
-        }
+        // Remove every retained renewal record, folding them into the
+        // history summary
+        VerificationHistoryRegistry::prune_renewal_records(env, project_id, count);
+        let count = count - start;
 
         // Reset the counter
         env.storage()
             .persistent()
             .remove(&StorageKey::VerificationRenewalCount(project_id));
+        env.storage()
+            .persistent()
+            .remove(&FeatureKey::RenewalHistoryStart(project_id));
 
         crate::events::publish_renewal_history_cleared_event(env, project_id, admin.clone(), count);
 
@@ -0,0 +1,258 @@
+//! Admin-defined verification tiers and the tiers granted to each project.
+//!
+//! A verification request may name a tier; approving it grants that tier to
+//! the project until the tier's duration runs out. Renewals can move a project
+//! to another tier, and each tier keeps its own grant, so a project may hold
+//! several tiers at once. Its tier is the active grant with the highest level.
+
+use crate::admin_action_log::AdminActionLog;
+use crate::auth::require_admin_auth;
+use crate::constants::{MAX_NAME_LEN, MAX_VERIFICATION_TIERS};
+use crate::errors::ContractError;
+use crate::events::{publish_verification_tier_granted_event, publish_verification_tier_set_event};
+use crate::storage_keys::FeatureKey;
+use crate::types::{
+    AdminActionType, VerificationTier, VerificationTierGrant, VerificationTierParams,
+};
+use crate::verification_registry::storage::VerificationRegistry;
+use crate::verification_registry::validation::VerificationValidation;
+use soroban_sdk::{Address, Env, Vec};
+
+pub struct VerificationTierRegistry;
+
+impl VerificationTierRegistry {
+    fn validate_params(params: &VerificationTierParams) -> Result<(), ContractError> {
+        if params.name.is_empty() || params.name.len() as usize > MAX_NAME_LEN {
+            return Err(ContractError::InvalidInput);
+        }
+        if params.duration_seconds == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+        if params.fee > i128::MAX as u128 {
+            return Err(ContractError::InvalidInput);
+        }
+        VerificationValidation::validate_evidence_cid(&params.requirements_cid)?;
+        VerificationValidation::validate_evidence_cid(&params.badge_cid)?;
+        Ok(())
+    }
+
+    fn save_tier(env: &Env, tier: &VerificationTier) {
+        env.storage()
+            .persistent()
+            .set(&FeatureKey::VerificationTier(tier.tier_id), tier);
+    }
+
+    /// Admin: define a new tier. Tiers start active. Returns the new tier ID.
+    pub fn create_tier(
+        env: &Env,
+        admin: Address,
+        params: VerificationTierParams,
+    ) -> Result<u32, ContractError> {
+        require_admin_auth(env, &admin)?;
+        Self::validate_params(&params)?;
+
+        let count = Self::tier_count(env);
+        if count >= MAX_VERIFICATION_TIERS {
+            return Err(ContractError::InvalidInput);
+        }
+        let tier_id = count + 1;
+        let tier = VerificationTier {
+            tier_id,
+            name: params.name,
+            level: params.level,
+            fee: params.fee,
+            duration_seconds: params.duration_seconds,
+            requirements_cid: params.requirements_cid,
+            badge_cid: params.badge_cid,
+            active: true,
+        };
+        Self::save_tier(env, &tier);
+        env.storage()
+            .persistent()
+            .set(&FeatureKey::VerificationTierCount, &tier_id);
+
+        publish_verification_tier_set_event(env, tier, admin.clone());
+        AdminActionLog::record_action(
+            env,
+            admin,
+            AdminActionType::VerificationTierCreated,
+            Some(tier_id as u64),
+            None,
+            None,
+        );
+        Ok(tier_id)
+    }
+
+    /// Admin: replace a tier's definition. Existing grants keep their expiry;
+    /// the new fee and duration apply to later requests and renewals.
+    pub fn update_tier(
+        env: &Env,
+        admin: Address,
+        tier_id: u32,
+        params: VerificationTierParams,
+    ) -> Result<(), ContractError> {
+        require_admin_auth(env, &admin)?;
+        Self::validate_params(&params)?;
+
+        let mut tier =
+            Self::get_tier(env, tier_id).ok_or(ContractError::VerificationTierNotFound)?;
+        tier.name = params.name;
+        tier.level = params.level;
+        tier.fee = params.fee;
+        tier.duration_seconds = params.duration_seconds;
+        tier.requirements_cid = params.requirements_cid;
+        tier.badge_cid = params.badge_cid;
+        Self::save_tier(env, &tier);
+
+        publish_verification_tier_set_event(env, tier, admin.clone());
+        AdminActionLog::record_action(
+            env,
+            admin,
+            AdminActionType::VerificationTierUpdated,
+            Some(tier_id as u64),
+            None,
+            None,
+        );
+        Ok(())
+    }
+
+    /// Admin: activate or deactivate a tier. Deactivated tiers reject new
+    /// requests and are ignored when resolving a project's tier.
+    pub fn set_tier_active(
+        env: &Env,
+        admin: Address,
+        tier_id: u32,
+        active: bool,
+    ) -> Result<(), ContractError> {
+        require_admin_auth(env, &admin)?;
+
+        let mut tier =
+            Self::get_tier(env, tier_id).ok_or(ContractError::VerificationTierNotFound)?;
+        tier.active = active;
+        Self::save_tier(env, &tier);
+
+        publish_verification_tier_set_event(env, tier, admin.clone());
+        AdminActionLog::record_action(
+            env,
+            admin,
+            AdminActionType::VerificationTierUpdated,
+            Some(tier_id as u64),
+            None,
+            None,
+        );
+        Ok(())
+    }
+
+    pub fn tier_count(env: &Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::VerificationTierCount)
+            .unwrap_or(0)
+    }
+
+    pub fn get_tier(env: &Env, tier_id: u32) -> Option<VerificationTier> {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::VerificationTier(tier_id))
+    }
+
+    /// All tiers, active or not, in ID order.
+    pub fn list_tiers(env: &Env) -> Vec<VerificationTier> {
+        let mut out = Vec::new(env);
+        for tier_id in 1..=Self::tier_count(env) {
+            if let Some(tier) = Self::get_tier(env, tier_id) {
+                out.push_back(tier);
+            }
+        }
+        out
+    }
+
+    /// Resolve a requested tier, rejecting unknown and inactive tiers.
+    pub fn require_active_tier(env: &Env, tier_id: u32) -> Result<VerificationTier, ContractError> {
+        let tier = Self::get_tier(env, tier_id).ok_or(ContractError::VerificationTierNotFound)?;
+        if !tier.active {
+            return Err(ContractError::VerificationTierInactive);
+        }
+        Ok(tier)
+    }
+
+    /// Validity period for a verification at `tier_id`, or `untiered` when the
+    /// verification has no tier (or its tier no longer exists).
+    pub fn duration_for(env: &Env, tier_id: Option<u32>, untiered: u64) -> u64 {
+        tier_id
+            .and_then(|id| Self::get_tier(env, id))
+            .map(|tier| tier.duration_seconds)
+            .unwrap_or(untiered)
+    }
+
+    // ── Project grants ──────────────────────────────────────────────────
+
+    pub fn get_grants(env: &Env, project_id: u64) -> Vec<VerificationTierGrant> {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::ProjectTierGrants(project_id))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Grant `tier_id` to a project, replacing any earlier grant of the same tier.
+    pub fn grant(env: &Env, project_id: u64, tier_id: u32, request_id: u64, expires_at: u64) {
+        let grants = Self::get_grants(env, project_id);
+        let mut updated = Vec::new(env);
+        for existing in grants.iter() {
+            if existing.tier_id != tier_id {
+                updated.push_back(existing);
+            }
+        }
+        updated.push_back(VerificationTierGrant {
+            tier_id,
+            request_id,
+            granted_at: env.ledger().timestamp(),
+            expires_at,
+        });
+        env.storage()
+            .persistent()
+            .set(&FeatureKey::ProjectTierGrants(project_id), &updated);
+
+        publish_verification_tier_granted_event(env, project_id, tier_id, request_id, expires_at);
+    }
+
+    /// Drop every tier held by a project (used when its verification is revoked).
+    pub fn clear_grants(env: &Env, project_id: u64) {
+        env.storage()
+            .persistent()
+            .remove(&FeatureKey::ProjectTierGrants(project_id));
+    }
+
+    /// The project's tier: the unexpired grant of an active tier with the
+    /// highest level. Ties go to the lower tier ID. None while the project's
+    /// verification is suspended.
+    pub fn get_project_tier(env: &Env, project_id: u64) -> Option<VerificationTier> {
+        if let Some(record) = VerificationRegistry::get_verification(env, project_id) {
+            if VerificationRegistry::is_suspended(env, &record) {
+                return None;
+            }
+        }
+        let now = env.ledger().timestamp();
+        let mut best: Option<VerificationTier> = None;
+        for grant in Self::get_grants(env, project_id).iter() {
+            if grant.expires_at != 0 && grant.expires_at <= now {
+                continue;
+            }
+            let tier = match Self::get_tier(env, grant.tier_id) {
+                Some(tier) if tier.active => tier,
+                _ => continue,
+            };
+            let better = match &best {
+                Some(current) => {
+                    tier.level > current.level
+                        || (tier.level == current.level && tier.tier_id < current.tier_id)
+                }
+                None => true,
+            };
+            if better {
+                best = Some(tier);
+            }
+        }
+        best
+    }
+}
@@ -0,0 +1,252 @@
+//! Delegated verifier role.
+//!
+//! Admins grant the role to outside reviewers such as audit firms, optionally
+//! limited to some project categories or verification tiers and with an
+//! expiry. Verifiers can be assigned requests, approve, reject and attest
+//! them, and attach reports, but hold no other admin power. Their decisions
+//! are logged in the admin action log under their own address.
+
+use crate::admin_action_log::AdminActionLog;
+use crate::admin_manager::AdminManager;
+use crate::auth::require_admin_auth;
+use crate::constants::{
+    MAX_CATEGORY_LEN, MAX_VERIFICATION_REPORTS, MAX_VERIFIERS, MAX_VERIFIER_SCOPE_ENTRIES,
+};
+use crate::errors::ContractError;
+use crate::events::{
+    publish_verification_report_attached_event, publish_verifier_granted_event,
+    publish_verifier_revoked_event,
+};
+use crate::project_registry::ProjectRegistry;
+use crate::storage_keys::FeatureKey;
+use crate::types::{AdminActionType, VerificationReport, VerifierGrant};
+use crate::verification_registry::storage::VerificationRegistry;
+use crate::verification_registry::tiers::VerificationTierRegistry;
+use crate::verification_registry::validation::VerificationValidation;
+use soroban_sdk::{Address, Env, String, Vec};
+
+pub struct VerifierRegistry;
+
+impl VerifierRegistry {
+    /// Admin: grant or update the verifier role for `verifier`.
+    pub fn grant_verifier(
+        env: &Env,
+        admin: Address,
+        verifier: Address,
+        categories: Vec<String>,
+        tier_ids: Vec<u32>,
+        expires_at: u64,
+    ) -> Result<(), ContractError> {
+        require_admin_auth(env, &admin)?;
+
+        if categories.len() > MAX_VERIFIER_SCOPE_ENTRIES
+            || tier_ids.len() > MAX_VERIFIER_SCOPE_ENTRIES
+        {
+            return Err(ContractError::InvalidInput);
+        }
+        for category in categories.iter() {
+            if category.is_empty() || category.len() as usize > MAX_CATEGORY_LEN {
+                return Err(ContractError::InvalidInput);
+            }
+        }
+        for tier_id in tier_ids.iter() {
+            VerificationTierRegistry::get_tier(env, tier_id)
+                .ok_or(ContractError::VerificationTierNotFound)?;
+        }
+        let now = env.ledger().timestamp();
+        if expires_at != 0 && expires_at <= now {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let mut verifiers = Self::list_verifiers(env);
+        if !verifiers.contains(&verifier) {
+            if verifiers.len() >= MAX_VERIFIERS {
+                return Err(ContractError::InvalidInput);
+            }
+            verifiers.push_back(verifier.clone());
+            env.storage()
+                .persistent()
+                .set(&FeatureKey::VerifierList, &verifiers);
+        }
+
+        let grant = VerifierGrant {
+            verifier: verifier.clone(),
+            categories,
+            tier_ids,
+            expires_at,
+            granted_by: admin.clone(),
+            granted_at: now,
+        };
+        env.storage()
+            .persistent()
+            .set(&FeatureKey::Verifier(verifier.clone()), &grant);
+
+        publish_verifier_granted_event(env, grant);
+        AdminActionLog::record_action(
+            env,
+            admin,
+            AdminActionType::VerifierRoleChanged,
+            None,
+            Some(verifier),
+            None,
+        );
+        Ok(())
+    }
+
+    /// Admin: remove the verifier role. Assignments to the address stay on
+    /// their records but can no longer be acted on by it.
+    pub fn revoke_verifier(
+        env: &Env,
+        admin: Address,
+        verifier: Address,
+    ) -> Result<(), ContractError> {
+        require_admin_auth(env, &admin)?;
+
+        let key = FeatureKey::Verifier(verifier.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(ContractError::VerifierNotFound);
+        }
+        env.storage().persistent().remove(&key);
+
+        let mut verifiers = Self::list_verifiers(env);
+        if let Some(index) = verifiers.first_index_of(&verifier) {
+            verifiers.remove(index);
+            env.storage()
+                .persistent()
+                .set(&FeatureKey::VerifierList, &verifiers);
+        }
+
+        publish_verifier_revoked_event(env, verifier.clone(), admin.clone());
+        AdminActionLog::record_action(
+            env,
+            admin,
+            AdminActionType::VerifierRoleChanged,
+            None,
+            Some(verifier),
+            None,
+        );
+        Ok(())
+    }
+
+    /// The verifier's grant, including lapsed ones.
+    pub fn get_verifier(env: &Env, verifier: &Address) -> Option<VerifierGrant> {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::Verifier(verifier.clone()))
+    }
+
+    pub fn list_verifiers(env: &Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::VerifierList)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// The verifier's grant if it has not lapsed.
+    pub fn active_grant(env: &Env, verifier: &Address) -> Option<VerifierGrant> {
+        let now = env.ledger().timestamp();
+        Self::get_verifier(env, verifier)
+            .filter(|grant| grant.expires_at == 0 || now < grant.expires_at)
+    }
+
+    /// Whether a grant covers a project in `category` requesting `tier_id`.
+    pub fn covers(grant: &VerifierGrant, category: &String, tier_id: Option<u32>) -> bool {
+        let category_ok = grant.categories.is_empty() || grant.categories.contains(category);
+        let tier_ok = grant.tier_ids.is_empty()
+            || matches!(tier_id, Some(tier_id) if grant.tier_ids.contains(tier_id));
+        category_ok && tier_ok
+    }
+
+    /// Check that `verifier` holds an active grant covering the project's
+    /// current verification request.
+    pub fn require_in_scope(
+        env: &Env,
+        verifier: &Address,
+        project_id: u64,
+    ) -> Result<(), ContractError> {
+        let grant = Self::active_grant(env, verifier).ok_or(ContractError::AdminOnly)?;
+        let project =
+            ProjectRegistry::get_project(env, project_id).ok_or(ContractError::ProjectNotFound)?;
+        let record = VerificationRegistry::get_verification(env, project_id)
+            .ok_or(ContractError::VerificationNotFound)?;
+        if !Self::covers(&grant, &project.category, record.tier_id) {
+            return Err(ContractError::VerifierOutOfScope);
+        }
+        Ok(())
+    }
+
+    /// Authorize a decision on a project's verification request. Admins may
+    /// always decide; verifiers need an active grant covering the request and,
+    /// when the request is assigned, must be the assignee.
+    pub fn require_can_decide(
+        env: &Env,
+        caller: &Address,
+        project_id: u64,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        if AdminManager::is_admin(env, caller) {
+            return Ok(());
+        }
+
+        Self::require_in_scope(env, caller, project_id)?;
+        let record = VerificationRegistry::get_verification(env, project_id)
+            .ok_or(ContractError::VerificationNotFound)?;
+        match record.assigned_admin {
+            Some(assignee) if assignee != *caller => Err(ContractError::VerifierOutOfScope),
+            _ => Ok(()),
+        }
+    }
+
+    // ── Reports ─────────────────────────────────────────────────────────
+
+    /// Attach a report CID to the project's current verification request.
+    pub fn attach_report(
+        env: &Env,
+        project_id: u64,
+        author: Address,
+        report_cid: String,
+    ) -> Result<(), ContractError> {
+        Self::require_can_decide(env, &author, project_id)?;
+        VerificationValidation::validate_evidence_cid(&report_cid)?;
+
+        let record = VerificationRegistry::get_verification(env, project_id)
+            .ok_or(ContractError::VerificationNotFound)?;
+        let mut reports = Self::get_reports(env, record.request_id);
+        if reports.len() >= MAX_VERIFICATION_REPORTS {
+            return Err(ContractError::InvalidInput);
+        }
+        reports.push_back(VerificationReport {
+            author: author.clone(),
+            report_cid: report_cid.clone(),
+            attached_at: env.ledger().timestamp(),
+        });
+        env.storage().persistent().set(
+            &FeatureKey::VerificationReports(record.request_id),
+            &reports,
+        );
+
+        publish_verification_report_attached_event(
+            env,
+            project_id,
+            record.request_id,
+            author.clone(),
+            report_cid.clone(),
+        );
+        AdminActionLog::record_action(
+            env,
+            author,
+            AdminActionType::VerificationReportAttached,
+            Some(project_id),
+            None,
+            Some(report_cid),
+        );
+        Ok(())
+    }
+
+    pub fn get_reports(env: &Env, request_id: u64) -> Vec<VerificationReport> {
+        env.storage()
+            .persistent()
+            .get(&FeatureKey::VerificationReports(request_id))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+}